@@ -54,6 +54,13 @@ impl SteamCloudApp {
 
         let mut app = Self::default();
         app.scan_cloud_games();
+        // 启动时检查所有软链接，有问题才弹出面板
+        app.show_symlink_health(true);
+
+        // 上次退出时有未完成的上传，提示续传
+        if let Some(session) = crate::upload_session::list_sessions().into_iter().next() {
+            app.dialogs.upload_resume = Some(crate::ui::UploadResumeDialog::new(session));
+        }
         app
     }
 
@@ -106,23 +113,90 @@ impl SteamCloudApp {
     }
 
     fn download(&mut self) {
-        if let Some((tasks, _base_dir)) =
-            self.handlers
-                .prepare_download(&self.file_list, &mut self.dialogs, &self.misc.i18n)
-        {
-            self.handlers
-                .start_download(tasks, &mut self.dialogs, &mut self.async_handlers);
+        if let Some((tasks, base_dir)) = self.handlers.prepare_download(
+            &self.file_list,
+            self.connection.profile.as_ref(),
+            &mut self.dialogs,
+            &self.misc.i18n,
+        ) {
+            let app_id = self
+                .connection
+                .app_id_input
+                .trim()
+                .parse::<u32>()
+                .unwrap_or(0);
+            self.handlers.start_download(
+                tasks,
+                base_dir,
+                app_id,
+                &mut self.dialogs,
+                &mut self.async_handlers,
+            );
         }
     }
 
     fn upload(&mut self) {
-        self.handlers
-            .upload_files(&self.connection, &mut self.dialogs, &self.misc.i18n);
+        self.handlers.upload_files(
+            &self.connection,
+            &self.file_list,
+            &mut self.dialogs,
+            &self.misc.i18n,
+        );
     }
 
-    fn upload_start(&mut self, queue: crate::file_manager::UploadQueue) {
-        self.handlers
-            .start_upload(queue, &mut self.dialogs, &mut self.async_handlers);
+    fn upload_start(
+        &mut self,
+        queue: crate::file_manager::UploadQueue,
+        limits: crate::file_manager::UploadLimits,
+    ) {
+        let app_id = self.connection.app_id_input.parse::<u32>().unwrap_or(0);
+        self.handlers.start_upload(
+            queue,
+            limits,
+            app_id,
+            false,
+            &mut self.dialogs,
+            &mut self.async_handlers,
+        );
+    }
+
+    // 连接成功后检查该 app 是否有未完成的上传会话
+    fn check_upload_session(&mut self) {
+        let app_id = self.connection.app_id_input.parse::<u32>().unwrap_or(0);
+        if self.connection.pending_upload_resume.take() == Some(app_id) {
+            self.resume_upload(app_id);
+        } else if self.dialogs.upload_resume.is_none()
+            && let Some(session) = crate::upload_session::load_session(app_id)
+        {
+            self.dialogs.upload_resume = Some(crate::ui::UploadResumeDialog::new(session));
+        }
+    }
+
+    // 续传未完成的任务，云端内容一致的文件会被跳过
+    fn resume_upload(&mut self, app_id: u32) {
+        let queue = match crate::upload_session::load_session(app_id) {
+            Some(session) => session.to_queue(),
+            None => return,
+        };
+
+        if queue.tasks.is_empty() {
+            // 本地文件均已不存在，会话无法继续
+            if let Err(e) = crate::upload_session::remove_session(app_id) {
+                tracing::warn!("删除上传会话日志失败: {}", e);
+            }
+            self.show_error(self.misc.i18n.upload_resume_nothing_left());
+            return;
+        }
+
+        let limits = self.handlers.upload_limits(app_id);
+        self.handlers.start_upload(
+            queue,
+            limits,
+            app_id,
+            true,
+            &mut self.dialogs,
+            &mut self.async_handlers,
+        );
     }
 
     fn forget(&mut self) {
@@ -178,8 +252,9 @@ impl SteamCloudApp {
                         Some(crate::ui::AppInfoDialog::new(app_id, config));
                 }
                 Err(e) => {
-                    self.dialogs
-                        .show_error(&self.misc.i18n.error_get_appinfo(&e.to_string()));
+                    let message = unsupported_appinfo_text(&self.misc.i18n, &e)
+                        .unwrap_or_else(|| self.misc.i18n.error_get_appinfo(&e.to_string()));
+                    self.dialogs.show_error(&message);
                 }
             },
             Err(e) => {
@@ -189,24 +264,50 @@ impl SteamCloudApp {
         }
     }
 
+    fn game_name(&self, app_id: u32) -> String {
+        self.game_library
+            .cloud_games
+            .iter()
+            .find(|g| g.app_id == app_id)
+            .and_then(|g| g.game_name.clone())
+            .unwrap_or_else(|| format!("Game {}", app_id))
+    }
+
+    // 当前连接的游戏用已加载的配置档，其他游戏从磁盘读取
+    fn game_profile(&self, app_id: u32) -> Option<crate::config::GameProfile> {
+        match &self.connection.profile {
+            Some(profile) if profile.app_id == app_id => Some(profile.clone()),
+            _ => crate::config::get_game_profile(app_id),
+        }
+    }
+
+    // 保存配置档，属于当前连接的游戏时立即生效
+    fn save_game_profile(&mut self, profile: crate::config::GameProfile) {
+        if let Err(e) = crate::config::save_game_profile(&profile) {
+            self.show_error(&self.misc.i18n.game_profile_save_failed(&e.to_string()));
+            return;
+        }
+        let current = self.connection.app_id_input.trim().parse::<u32>().ok();
+        if self.connection.is_connected && current == Some(profile.app_id) {
+            self.connection.profile = (!profile.is_empty()).then_some(profile);
+        }
+        self.misc.status_message = self.misc.i18n.game_profile_saved().to_string();
+    }
+
     fn show_symlink_manager(&mut self, app_id: u32) {
         match crate::vdf_parser::VdfParser::new() {
             Ok(parser) => {
-                let game_name = self
-                    .game_library
-                    .cloud_games
-                    .iter()
-                    .find(|g| g.app_id == app_id)
-                    .and_then(|g| g.game_name.clone())
-                    .unwrap_or_else(|| format!("Game {}", app_id));
-
-                self.dialogs.symlink_dialog = Some(crate::ui::SymlinkDialog::new(
-                    app_id,
-                    game_name,
-                    parser.get_steam_path().clone(),
-                    parser.get_user_id().to_string(),
-                    Some(self.steam_manager.clone()),
-                ));
+                let profile = self.game_profile(app_id);
+                self.dialogs.symlink_dialog = Some(
+                    crate::ui::SymlinkDialog::new(
+                        app_id,
+                        self.game_name(app_id),
+                        parser.get_steam_path().clone(),
+                        parser.get_user_id().to_string(),
+                        Some(self.steam_manager.clone()),
+                    )
+                    .with_profile(profile.as_ref()),
+                );
             }
             Err(e) => {
                 self.dialogs
@@ -215,9 +316,78 @@ impl SteamCloudApp {
         }
     }
 
+    // 游戏配置档的备份计划到期时，文件列表加载完成后自动备份
+    fn run_scheduled_backup(&mut self) {
+        if !self.connection.remote_ready
+            || self.file_list.files.is_empty()
+            || self.async_handlers.backup_rx.is_some()
+        {
+            return;
+        }
+        let now = chrono::Utc::now().timestamp();
+        let Some(profile) = self
+            .connection
+            .profile
+            .as_mut()
+            .filter(|p| p.backup_due(now))
+        else {
+            return;
+        };
+
+        // 按开始时间记录，失败时也等到下个周期再试，避免每次刷新都重复备份
+        profile.last_backup_at = Some(now);
+        if let Err(e) = crate::config::save_game_profile(profile) {
+            tracing::warn!("保存游戏配置档失败: {}", e);
+        }
+        let app_id = profile.app_id;
+        tracing::info!("App ID {} 的备份计划已到期，开始自动备份", app_id);
+
+        let files = self.file_list.files.clone();
+        self.handlers.start_backup(
+            app_id,
+            self.game_name(app_id),
+            files,
+            &mut self.dialogs,
+            &mut self.async_handlers,
+        );
+    }
+
+    fn show_symlink_health(&mut self, only_if_issues: bool) {
+        let parser = match crate::vdf_parser::VdfParser::new() {
+            Ok(parser) => parser,
+            Err(e) => {
+                if !only_if_issues {
+                    self.dialogs
+                        .show_error(&self.misc.i18n.error_vdf_parser_init(&e.to_string()));
+                }
+                return;
+            }
+        };
+        let game_names = self
+            .game_library
+            .cloud_games
+            .iter()
+            .filter_map(|g| g.game_name.clone().map(|name| (g.app_id, name)))
+            .collect();
+        match crate::ui::SymlinkHealthPanel::new(
+            parser.get_steam_path().clone(),
+            parser.get_user_id().to_string(),
+            game_names,
+        ) {
+            Ok(panel) => {
+                if !only_if_issues || panel.issue_count() > 0 {
+                    self.dialogs.symlink_health = Some(panel);
+                }
+            }
+            Err(e) => tracing::warn!("软链接健康检查失败: {}", e),
+        }
+    }
+
     fn refresh_appinfo_config(&mut self) {
         if let Some(ref mut dialog) = self.dialogs.appinfo_dialog {
             let app_id = dialog.app_id;
+            // 完整 AppInfo 在下次打开对应标签页时重新读取
+            dialog.set_full_info(None);
             match crate::vdf_parser::VdfParser::new() {
                 Ok(parser) => match parser.get_ufs_config(app_id) {
                     Ok(config) => {
@@ -252,6 +422,15 @@ impl SteamCloudApp {
                 return;
             }
 
+            let report = crate::ufs_validator::validate_game_config(&game_config);
+            if report.has_errors() {
+                tracing::warn!("UFS 配置校验失败: {}", report.error_summary());
+                dialog.inject_status =
+                    Some(self.misc.i18n.ufs_validation_blocked(report.error_count()));
+                dialog.inject_status_is_success = false;
+                return;
+            }
+
             match crate::appinfo_writer::AppInfoWriter::new() {
                 Ok(writer) => match writer.inject_full_ufs_config(app_id, &game_config) {
                     Ok(_) => {
@@ -264,8 +443,9 @@ impl SteamCloudApp {
                         self.refresh_appinfo_config();
                     }
                     Err(e) => {
-                        dialog.inject_status =
-                            Some(self.misc.i18n.ufs_inject_error(&e.to_string()));
+                        let message = unsupported_appinfo_text(&self.misc.i18n, &e)
+                            .unwrap_or_else(|| self.misc.i18n.ufs_inject_error(&e.to_string()));
+                        dialog.inject_status = Some(message);
                         dialog.inject_status_is_success = false;
                     }
                 },
@@ -320,6 +500,75 @@ impl SteamCloudApp {
         }
     }
 
+    // 导出 UFS 配置分享包
+    fn export_game_configs(&mut self, path: std::path::PathBuf, all: bool) {
+        if let Some(ref mut dialog) = self.dialogs.appinfo_dialog {
+            let current = dialog.build_game_config();
+            let configs = if all {
+                // 已保存的配置 + 当前正在编辑的配置（覆盖同 app_id 的已保存版本）
+                let mut configs: Vec<_> = crate::config::list_ufs_game_configs()
+                    .into_iter()
+                    .filter(|c| c.app_id != current.app_id)
+                    .collect();
+                if !current.savefiles.is_empty() || !current.root_overrides.is_empty() {
+                    configs.push(current);
+                }
+                configs.sort_by_key(|c| c.app_id);
+                configs
+            } else {
+                vec![current]
+            };
+
+            let cloud_games = &self.game_library.cloud_games;
+            let bundle = crate::ufs_bundle::UfsConfigBundle::from_configs(configs, |app_id| {
+                cloud_games
+                    .iter()
+                    .find(|g| g.app_id == app_id)
+                    .and_then(|g| g.game_name.clone())
+            });
+
+            match crate::ufs_bundle::export_to_file(&bundle, &path) {
+                Ok(_) => {
+                    dialog.inject_status = Some(
+                        self.misc
+                            .i18n
+                            .ufs_export_success(bundle.configs.len(), &path.display().to_string()),
+                    );
+                    dialog.inject_status_is_success = true;
+                }
+                Err(e) => {
+                    dialog.inject_status = Some(self.misc.i18n.ufs_export_error(&e.to_string()));
+                    dialog.inject_status_is_success = false;
+                }
+            }
+        }
+    }
+
+    // 导入 UFS 配置分享包
+    fn import_game_configs(&mut self, path: std::path::PathBuf) {
+        if let Some(ref mut dialog) = self.dialogs.appinfo_dialog {
+            let result = crate::ufs_bundle::read_from_file(&path)
+                .and_then(|bundle| crate::ufs_bundle::import_bundle(&bundle, dialog.import_policy));
+
+            match result {
+                Ok(summary) => {
+                    dialog.inject_status = Some(self.misc.i18n.ufs_import_success(
+                        summary.added,
+                        summary.overwritten,
+                        summary.merged,
+                        summary.skipped,
+                    ));
+                    dialog.inject_status_is_success = true;
+                    dialog.refresh_saved_configs();
+                }
+                Err(e) => {
+                    dialog.inject_status = Some(self.misc.i18n.ufs_import_error(&e.to_string()));
+                    dialog.inject_status_is_success = false;
+                }
+            }
+        }
+    }
+
     fn scan_cloud_games(&mut self) {
         self.handlers.scan_cloud_games(
             &mut self.game_library,
@@ -361,7 +610,8 @@ impl SteamCloudApp {
                 return;
             }
 
-            let mut queue = crate::file_manager::UploadQueue::new();
+            let mut queue = crate::file_manager::UploadQueue::new()
+                .with_profile(self.connection.profile.as_ref());
             let mut added_count = 0;
 
             for file in dropped_files {
@@ -384,22 +634,30 @@ impl SteamCloudApp {
 
             if added_count > 0 {
                 // 显示预览对话框，而不是直接上传
-                self.dialogs.upload_preview = Some(crate::ui::UploadPreviewDialog::new(queue));
+                self.handlers.open_upload_preview(
+                    queue,
+                    &self.connection,
+                    &self.file_list,
+                    &mut self.dialogs,
+                );
             }
         }
     }
 
     fn poll_async_results(&mut self) {
         // 连接结果
-        if let Some(result) = self.async_handlers.poll_connect()
-            && self.handlers.handle_connect_result(
+        if let Some(result) = self.async_handlers.poll_connect() {
+            if self.handlers.handle_connect_result(
                 result,
                 &mut self.connection,
                 &mut self.misc,
                 &mut self.dialogs,
-            )
-        {
-            self.refresh_files();
+            ) {
+                self.refresh_files();
+                self.check_upload_session();
+            } else {
+                self.connection.pending_upload_resume = None;
+            }
         }
 
         // 文件加载结果
@@ -412,6 +670,7 @@ impl SteamCloudApp {
                 &mut self.dialogs,
                 &mut self.async_handlers,
             );
+            self.run_scheduled_backup();
         }
 
         // 本地文件扫描结果
@@ -620,6 +879,13 @@ impl eframe::App for SteamCloudApp {
             crate::ui::BottomPanelEvent::ShowSymlinkManager(app_id) => {
                 self.show_symlink_manager(app_id);
             }
+            crate::ui::BottomPanelEvent::ShowGameProfile(app_id) => {
+                self.dialogs.game_profile = Some(crate::ui::GameProfileDialog::new(
+                    app_id,
+                    self.game_name(app_id),
+                    self.game_profile(app_id),
+                ));
+            }
             crate::ui::BottomPanelEvent::None => {}
         }
 
@@ -676,6 +942,12 @@ impl eframe::App for SteamCloudApp {
                 crate::ui::AppInfoDialogAction::ClearGameConfig => {
                     self.clear_game_config();
                 }
+                crate::ui::AppInfoDialogAction::ExportConfigs { path, all } => {
+                    self.export_game_configs(path, all);
+                }
+                crate::ui::AppInfoDialogAction::ImportConfigs(path) => {
+                    self.import_game_configs(path);
+                }
                 crate::ui::AppInfoDialogAction::RestartSteam => {
                     if self.connection.is_connected {
                         self.disconnect_from_steam();
@@ -687,14 +959,29 @@ impl eframe::App for SteamCloudApp {
                 crate::ui::AppInfoDialogAction::RefreshConfig => {
                     self.refresh_appinfo_config();
                 }
+                crate::ui::AppInfoDialogAction::LoadFullAppInfo => {
+                    if let Some(ref mut dialog) = self.dialogs.appinfo_dialog {
+                        let result = crate::appinfo_inspector::load_app_info(dialog.app_id)
+                            .map_err(|e| {
+                                tracing::warn!("读取完整 appinfo 失败: {}", e);
+                                unsupported_appinfo_text(&self.misc.i18n, &e)
+                                    .unwrap_or_else(|| e.to_string())
+                            });
+                        dialog.set_full_info(Some(result));
+                    }
+                }
                 crate::ui::AppInfoDialogAction::None => {}
             }
         }
 
         // Symlink 管理对话框
         let mut close_symlink_dialog = false;
+        let mut open_symlink_health = false;
         if let Some(ref mut dialog) = self.dialogs.symlink_dialog {
-            let _action = dialog.draw(ui.ctx(), &self.misc.i18n);
+            let action = dialog.draw(ui.ctx(), &self.misc.i18n);
+            if action == crate::ui::SymlinkAction::OpenHealthCheck {
+                open_symlink_health = true;
+            }
             if !dialog.show {
                 close_symlink_dialog = true;
             }
@@ -702,12 +989,43 @@ impl eframe::App for SteamCloudApp {
         if close_symlink_dialog {
             self.dialogs.symlink_dialog = None;
         }
+        if open_symlink_health {
+            self.show_symlink_health(false);
+        }
+
+        // 软链接健康检查面板
+        let mut close_symlink_health = false;
+        if let Some(ref mut panel) = self.dialogs.symlink_health {
+            panel.draw(ui.ctx(), &self.misc.i18n);
+            if !panel.show {
+                close_symlink_health = true;
+            }
+        }
+        if close_symlink_health {
+            self.dialogs.symlink_health = None;
+        }
+
+        // 游戏配置档对话框
+        if let Some(dialog) = &mut self.dialogs.game_profile {
+            match dialog.draw(ui.ctx(), &self.misc.i18n) {
+                crate::ui::GameProfileAction::Save(profile) => {
+                    self.dialogs.game_profile = None;
+                    self.save_game_profile(profile);
+                }
+                crate::ui::GameProfileAction::Cancel => {
+                    self.dialogs.game_profile = None;
+                }
+                crate::ui::GameProfileAction::None => {}
+            }
+        }
 
         if self.game_library.show_game_selector {
             let (selected_app_id, refresh_clicked) = crate::ui::draw_game_selector_window(
                 ui.ctx(),
                 &mut self.game_library.show_game_selector,
                 &self.game_library.cloud_games,
+                &mut self.game_library.collection_filter,
+                &self.game_library.dynamic_collections,
                 self.game_library.is_scanning_games,
                 self.game_library.vdf_count,
                 self.game_library.cdp_count,
@@ -755,10 +1073,11 @@ impl eframe::App for SteamCloudApp {
                 .unwrap_or_default();
             }
 
-            let selected_user_id = crate::ui::draw_user_selector_window(
+            let (selected_user_id, compare_clicked) = crate::ui::draw_user_selector_window(
                 ui.ctx(),
                 &mut self.game_library.show_user_selector,
                 &self.game_library.all_users,
+                &mut self.game_library.avatar_textures,
                 &self.misc.i18n,
             );
             if let Some(user_id) = selected_user_id {
@@ -775,6 +1094,50 @@ impl eframe::App for SteamCloudApp {
                 }
                 self.game_library.show_user_selector = false;
             }
+            if compare_clicked {
+                self.dialogs.user_compare = Some(crate::ui::UserCompareDialog::new(
+                    self.game_library.all_users.clone(),
+                    self.connection.app_id_input.trim().to_string(),
+                    self.handlers.worker_session(self.connection.is_connected),
+                ));
+                self.game_library.show_user_selector = false;
+            }
+        }
+
+        // 跨账号存档对比对话框
+        let compare_action = match &mut self.dialogs.user_compare {
+            Some(dialog) => dialog.draw(ui.ctx(), &self.misc.i18n),
+            None => crate::ui::UserCompareAction::None,
+        };
+        match compare_action {
+            crate::ui::UserCompareAction::Compare => {
+                if let Some(dialog) = &mut self.dialogs.user_compare {
+                    self.handlers.compare_users(dialog);
+                }
+            }
+            crate::ui::UserCompareAction::Copy => {
+                if let Some(dialog) = &mut self.dialogs.user_compare {
+                    let report = self.handlers.copy_between_users(dialog);
+                    self.handlers.compare_users(dialog);
+
+                    let mut message = self
+                        .misc
+                        .i18n
+                        .user_compare_copy_result(report.copied.len(), report.failed.len());
+                    for (name, err) in &report.failed {
+                        message.push_str(&format!("\n{}: {}", name, err));
+                    }
+                    if report.failed.is_empty() {
+                        self.dialogs.show_result(&message);
+                    } else {
+                        self.dialogs.show_error(&message);
+                    }
+                }
+            }
+            crate::ui::UserCompareAction::None => {}
+        }
+        if self.dialogs.user_compare.as_ref().is_some_and(|d| !d.show) {
+            self.dialogs.user_compare = None;
         }
 
         // 上传预览对话框
@@ -783,16 +1146,53 @@ impl eframe::App for SteamCloudApp {
                 crate::ui::UploadAction::Confirm => {
                     // 开始上传
                     if let Some(preview) = self.dialogs.upload_preview.take() {
-                        self.upload_start(preview.queue);
+                        // 过滤规则可能已写入游戏配置档，同步回当前连接
+                        if preview.profile.is_some() {
+                            self.connection.profile = preview.profile.clone();
+                        }
+                        self.upload_start(preview.queue, preview.limits);
                     }
                 }
                 crate::ui::UploadAction::Cancel => {
-                    self.dialogs.upload_preview = None;
+                    if let Some(preview) = self.dialogs.upload_preview.take()
+                        && preview.profile.is_some()
+                    {
+                        self.connection.profile = preview.profile;
+                    }
                 }
                 crate::ui::UploadAction::None => {}
             }
         }
 
+        // 续传提示对话框
+        if let Some(dialog) = &mut self.dialogs.upload_resume {
+            match dialog.draw(ui.ctx(), &self.misc.i18n) {
+                crate::ui::UploadResumeAction::Resume => {
+                    let app_id = dialog.session.app_id;
+                    self.dialogs.upload_resume = None;
+                    let current = self.connection.app_id_input.parse::<u32>().ok();
+                    if self.connection.is_connected && current == Some(app_id) {
+                        self.resume_upload(app_id);
+                    } else {
+                        // 先连接到对应 app，连接成功后自动续传
+                        self.connection.app_id_input = app_id.to_string();
+                        self.connection.pending_upload_resume = Some(app_id);
+                        self.connect_to_steam();
+                    }
+                }
+                crate::ui::UploadResumeAction::Discard => {
+                    if let Err(e) = crate::upload_session::remove_session(dialog.session.app_id) {
+                        tracing::warn!("删除上传会话日志失败: {}", e);
+                    }
+                    self.dialogs.upload_resume = None;
+                }
+                crate::ui::UploadResumeAction::Later => {
+                    self.dialogs.upload_resume = None;
+                }
+                crate::ui::UploadResumeAction::None => {}
+            }
+        }
+
         // 上传进度对话框
         if let Some(progress) = &mut self.dialogs.upload_progress {
             progress.draw(ui.ctx(), &self.misc.i18n);
@@ -856,17 +1256,9 @@ impl eframe::App for SteamCloudApp {
         if self.dialogs.show_backup && self.dialogs.backup_preview.is_none() {
             // 创建备份预览对话框
             let app_id = self.connection.app_id_input.parse::<u32>().unwrap_or(0);
-            let game_name = self
-                .game_library
-                .cloud_games
-                .iter()
-                .find(|g| g.app_id == app_id)
-                .and_then(|g| g.game_name.clone())
-                .unwrap_or_else(|| format!("Game {}", app_id));
-
             self.dialogs.backup_preview = Some(crate::ui::BackupPreviewDialog::new(
                 app_id,
-                game_name,
+                self.game_name(app_id),
                 self.file_list.files.clone(),
             ));
             self.dialogs.show_backup = false;
@@ -938,3 +1330,13 @@ impl eframe::App for SteamCloudApp {
             .request_repaint_after(std::time::Duration::from_millis(100));
     }
 }
+
+// 未知 appinfo.vdf 版本的提示文本，其他错误返回 None
+fn unsupported_appinfo_text(i18n: &crate::i18n::I18n, e: &anyhow::Error) -> Option<String> {
+    crate::appinfo_format::UnsupportedAppInfoVersion::from_error(e).map(|unsupported| {
+        i18n.error_appinfo_unsupported_version(
+            &unsupported.version_label(),
+            &crate::appinfo_format::supported_versions(),
+        )
+    })
+}
@@ -47,6 +47,12 @@ impl AppHandlers {
         connection.is_connecting = true;
         misc.status_message = misc.i18n.connecting_to_steam(app_id);
 
+        // 加载该游戏的配置档，后续下载/上传/备份/软链接都会读取
+        connection.profile = crate::config::get_game_profile(app_id);
+        if connection.profile.is_some() {
+            tracing::info!("已加载 App ID {} 的游戏配置档", app_id);
+        }
+
         // 系统级 App ID 不需要通过 Steam API 连接
         // 直接模拟连接成功，后续通过 CDP 获取文件列表
         if is_system_app_id(app_id) {
@@ -170,6 +176,19 @@ impl AppHandlers {
                 let available = total.saturating_sub(used);
                 misc.quota_info = Some((total, available));
             }
+            Err(e) => {
+                // 未知 appinfo.vdf 版本需要明确告知用户，而不是静默隐藏配额
+                if let Some(unsupported) =
+                    crate::appinfo_format::UnsupportedAppInfoVersion::from_error(&e)
+                {
+                    tracing::warn!("{}", unsupported);
+                    misc.status_message = misc.i18n.error_appinfo_unsupported_version(
+                        &unsupported.version_label(),
+                        &crate::appinfo_format::supported_versions(),
+                    );
+                }
+                misc.quota_info = None;
+            }
             _ => {
                 // appinfo.vdf 无配额数据时，不显示配额信息
                 misc.quota_info = None;
@@ -208,6 +227,7 @@ impl AppHandlers {
     pub fn prepare_download(
         &self,
         file_list: &FileListState,
+        profile: Option<&crate::config::GameProfile>,
         dialogs: &mut DialogState,
         i18n: &crate::i18n::I18n,
     ) -> Option<(Vec<crate::downloader::DownloadTask>, std::path::PathBuf)> {
@@ -216,16 +236,32 @@ impl AppHandlers {
             return None;
         }
 
-        // 选择下载目录
-        let base_dir = crate::file_manager::FileOperations::pick_download_folder()?;
+        // 游戏配置档指定了下载目录则直接使用，否则弹出选择
+        let preferred = profile
+            .and_then(|p| p.download_dir.clone())
+            .filter(|dir| std::fs::create_dir_all(dir).is_ok());
+        let base_dir = match preferred {
+            Some(dir) => {
+                tracing::info!("使用游戏配置档的下载目录: {}", dir.display());
+                dir
+            }
+            None => crate::file_manager::FileOperations::pick_download_folder()?,
+        };
 
         // 准备下载任务
-        let tasks = crate::file_manager::FileOperations::prepare_download_tasks(
+        let tasks = match crate::file_manager::FileOperations::prepare_download_tasks(
             &file_list.files,
             &file_list.selected_files,
             &base_dir,
             &file_list.local_save_paths,
-        );
+            crate::config::get_config().download.mirror_root_layout,
+        ) {
+            Ok(tasks) => tasks,
+            Err(collisions) => {
+                dialogs.show_error(&i18n.error_download_target_collision(&collisions.join(", ")));
+                return None;
+            }
+        };
 
         if tasks.is_empty() {
             dialogs.show_error(i18n.error_no_files_selected());
@@ -238,6 +274,7 @@ impl AppHandlers {
     pub fn upload_files(
         &self,
         connection: &ConnectionState,
+        file_list: &FileListState,
         dialogs: &mut DialogState,
         i18n: &crate::i18n::I18n,
     ) {
@@ -247,17 +284,63 @@ impl AppHandlers {
         }
 
         // 直接打开空的上传准备对话框，用户在对话框中添加文件
-        let queue = crate::file_manager::UploadQueue::new();
-        dialogs.upload_preview = Some(crate::ui::UploadPreviewDialog::new(queue));
+        let queue =
+            crate::file_manager::UploadQueue::new().with_profile(connection.profile.as_ref());
+        self.open_upload_preview(queue, connection, file_list, dialogs);
+    }
+
+    // 打开上传预览对话框，附带云端文件和配额限制用于预测
+    pub fn open_upload_preview(
+        &self,
+        queue: crate::file_manager::UploadQueue,
+        connection: &ConnectionState,
+        file_list: &FileListState,
+        dialogs: &mut DialogState,
+    ) {
+        let app_id = connection.app_id_input.parse::<u32>().unwrap_or(0);
+        let existing_files = file_list
+            .files
+            .iter()
+            .filter(|f| f.exists)
+            .map(|f| (f.name.clone(), f.size))
+            .collect();
+        dialogs.upload_preview = Some(
+            crate::ui::UploadPreviewDialog::new(queue)
+                .with_profile(connection.profile.as_ref())
+                .with_quota(existing_files, self.upload_limits(app_id)),
+        );
+    }
+
+    // 收集上传限制：appinfo.vdf 中的 quota/maxnumfiles 和 Steam 实时可用配额
+    pub fn upload_limits(&self, app_id: u32) -> crate::file_manager::UploadLimits {
+        let mut limits = crate::vdf_parser::VdfParser::new()
+            .and_then(|parser| parser.get_ufs_config(app_id))
+            .map(|config| {
+                crate::file_manager::UploadLimits::from_ufs(config.quota, config.maxnumfiles)
+            })
+            .unwrap_or_default();
+
+        if let Ok(mut manager) = self.steam_manager.lock() {
+            match manager.get_quota() {
+                Ok((_total, available)) => limits.available = Some(available),
+                Err(e) => tracing::debug!("获取实时配额失败: {}", e),
+            }
+        }
+
+        limits
     }
 
     pub fn start_upload(
         &self,
         queue: crate::file_manager::UploadQueue,
+        limits: crate::file_manager::UploadLimits,
+        app_id: u32,
+        resume: bool,
         dialogs: &mut DialogState,
         async_handlers: &mut AsyncHandlers,
     ) {
         let total_files = queue.total_files();
+        let verify_after_write = crate::config::get_config().upload.verify_after_write;
         dialogs.upload_progress = Some(crate::ui::UploadProgressDialog::new(total_files));
 
         let steam_manager = self.steam_manager.clone();
@@ -269,6 +352,10 @@ impl AppHandlers {
         std::thread::spawn(move || {
             let mut queue = queue;
             let executor = crate::file_manager::UploadExecutor::new(steam_manager)
+                .with_limits(limits)
+                .with_journal(crate::upload_session::UploadJournal::new(app_id))
+                .with_skip_matching(resume)
+                .with_verify_after_write(verify_after_write)
                 .with_progress_callback(move |current, total, filename| {
                     let _ = progress_tx.send((current, total, filename.to_string()));
                 });
@@ -277,10 +364,12 @@ impl AppHandlers {
                 Ok(result) => {
                     let result_json = serde_json::json!({
                         "success_count": result.success_count,
+                        "skipped_count": result.skipped_count,
                         "failed_count": result.failed_count,
                         "total_size": result.total_size,
                         "elapsed_secs": result.elapsed_secs,
                         "failed_files": result.failed_files,
+                        "quota_warning": result.quota_warning,
                     });
                     let _ = tx.send(Ok(result_json.to_string()));
                 }
@@ -403,6 +492,11 @@ impl AppHandlers {
                 .arg(steam_url)
                 .spawn();
 
+            // 先用上次的索引填充游戏列表，后台扫描完成后再替换
+            if game_library.cloud_games.is_empty() {
+                game_library.cloud_games = crate::game_index::load_cached_games(&user_id);
+            }
+
             game_library.is_scanning_games = true;
             misc.status_message = misc.i18n.scanning_game_library().to_string();
             let (tx, rx) = std::sync::mpsc::channel();
@@ -418,6 +512,99 @@ impl AppHandlers {
         }
     }
 
+    // Steam 当前登录的账号和工作进程连接的 app，未连接时为 None
+    pub fn worker_session(&mut self, is_connected: bool) -> Option<(String, u32)> {
+        if !is_connected {
+            return None;
+        }
+        let steam_path = self.ensure_vdf_parser()?.get_steam_path().clone();
+        let (user_id, _) = crate::user_manager::find_user_id_from_loginusers(&steam_path)?;
+        let app_id = self.steam_manager.lock().ok()?.get_app_id();
+        Some((user_id, app_id))
+    }
+
+    pub fn compare_users(&mut self, dialog: &mut crate::ui::UserCompareDialog) {
+        let (steam_path, app_id) = match (self.ensure_vdf_parser(), dialog.app_id()) {
+            (Some(parser), Some(app_id)) => (parser.get_steam_path().clone(), app_id),
+            _ => return,
+        };
+        if dialog.source_user == dialog.target_user {
+            return;
+        }
+
+        let source = crate::user_compare::load_user_saves(&steam_path, &dialog.source_user, app_id);
+        let target = crate::user_compare::load_user_saves(&steam_path, &dialog.target_user, app_id);
+        tracing::info!(
+            "跨账号对比 App {}: {} ({} 个文件) -> {} ({} 个文件)",
+            app_id,
+            dialog.source_user,
+            source.len(),
+            dialog.target_user,
+            target.len()
+        );
+        dialog.set_entries(crate::user_compare::compare_saves(&source, &target));
+    }
+
+    pub fn copy_between_users(
+        &mut self,
+        dialog: &crate::ui::UserCompareDialog,
+    ) -> crate::user_compare::CopyReport {
+        use crate::user_compare::{CopyReport, CopyTarget};
+
+        let mut report = CopyReport::default();
+        let (steam_path, app_id) = match (self.ensure_vdf_parser(), dialog.app_id()) {
+            (Some(parser), Some(app_id)) => (parser.get_steam_path().clone(), app_id),
+            _ => return report,
+        };
+        let use_api = dialog.copy_target == CopyTarget::SteamApi && dialog.can_use_steam_api();
+
+        for entry in dialog.selected_entries() {
+            // rootoverride 目录中的文件不能通过 API 写入，仍复制到本地目录
+            let result = if use_api && entry.root == 0 {
+                crate::user_compare::read_source(&entry).and_then(|data| {
+                    let mut manager = self
+                        .steam_manager
+                        .lock()
+                        .map_err(|e| anyhow::anyhow!("锁定 Steam 管理器失败: {}", e))?;
+                    match manager.write_file(&entry.name, &data)? {
+                        true => Ok(()),
+                        false => Err(anyhow::anyhow!("Steam 拒绝写入")),
+                    }
+                })
+            } else {
+                crate::user_compare::copy_to_user_folder(
+                    &steam_path,
+                    app_id,
+                    &dialog.target_user,
+                    &entry,
+                )
+                .map(|_| ())
+            };
+
+            match result {
+                Ok(()) => report.copied.push(entry.name),
+                Err(e) => {
+                    tracing::warn!(
+                        "复制 {} 到账号 {} 失败: {}",
+                        entry.name,
+                        dialog.target_user,
+                        e
+                    );
+                    report.failed.push((entry.name, e.to_string()));
+                }
+            }
+        }
+
+        tracing::info!(
+            "跨账号复制完成: {} -> {}, 成功 {} 个, 失败 {} 个",
+            dialog.source_user,
+            dialog.target_user,
+            report.copied.len(),
+            report.failed.len()
+        );
+        report
+    }
+
     fn ensure_vdf_parser(&mut self) -> Option<&VdfParser> {
         if self.vdf_parser.is_none() {
             self.vdf_parser = VdfParser::new().ok();
@@ -631,14 +818,22 @@ impl AppHandlers {
         match result {
             Ok(result) => {
                 game_library.cloud_games = result.games;
+                game_library.dynamic_collections = result.dynamic_collections;
                 game_library.vdf_count = result.vdf_count;
                 game_library.cdp_count = result.cdp_count;
 
                 // 状态消息将在 UI 渲染时动态生成，以支持语言切换
                 misc.status_message = String::new();
 
-                if result.cdp_count == 0 && crate::cdp_client::CdpClient::is_cdp_running() {
-                    dialogs.show_error(misc.i18n.cdp_no_data_error());
+                if result.cdp_count == 0 && crate::web_remotestorage::is_available() {
+                    match crate::config::get_config().remote_storage.provider {
+                        crate::config::RemoteStorageProvider::Cdp => {
+                            dialogs.show_error(misc.i18n.cdp_no_data_error())
+                        }
+                        crate::config::RemoteStorageProvider::Web => {
+                            dialogs.show_error(misc.i18n.web_no_data_error())
+                        }
+                    }
                 }
 
                 game_library.is_scanning_games = false;
@@ -678,6 +873,7 @@ impl AppHandlers {
             Ok(msg) => {
                 if let Ok(result) = serde_json::from_str::<serde_json::Value>(&msg) {
                     let success_count = result["success_count"].as_u64().unwrap_or(0) as usize;
+                    let skipped_count = result["skipped_count"].as_u64().unwrap_or(0) as usize;
                     let failed_count = result["failed_count"].as_u64().unwrap_or(0) as usize;
                     let total_size = result["total_size"].as_u64().unwrap_or(0);
                     let elapsed_secs = result["elapsed_secs"].as_u64().unwrap_or(0);
@@ -694,14 +890,17 @@ impl AppHandlers {
                                 .collect()
                         })
                         .unwrap_or_default();
+                    let quota_warning = result["quota_warning"].as_str().map(str::to_string);
 
                     dialogs.upload_progress = None;
                     dialogs.upload_complete = Some(crate::ui::UploadCompleteDialog::new(
                         success_count,
+                        skipped_count,
                         failed_count,
                         total_size,
                         elapsed_secs,
                         failed_files,
+                        quota_warning,
                     ));
                 }
             }
@@ -871,6 +1070,8 @@ impl AppHandlers {
     pub fn start_download(
         &self,
         tasks: Vec<crate::downloader::DownloadTask>,
+        base_dir: std::path::PathBuf,
+        app_id: u32,
         dialogs: &mut DialogState,
         async_handlers: &mut AsyncHandlers,
     ) {
@@ -887,10 +1088,15 @@ impl AppHandlers {
         async_handlers.download_cancel = Some(cancel_flag.clone());
 
         std::thread::spawn(move || {
-            let downloader = crate::downloader::BatchDownloader::new(tasks)
+            let config = crate::config::get_config().download;
+            let mut downloader = crate::downloader::BatchDownloader::new(tasks)
                 .with_cancel_flag(cancel_flag)
                 .with_progress_sender(progress_tx)
-                .with_steam_manager(steam_manager);
+                .with_steam_manager(steam_manager)
+                .with_config(&config);
+            if config.write_manifest {
+                downloader = downloader.with_manifest(base_dir, app_id);
+            }
 
             let result = downloader.execute();
             let _ = result_tx.send(result);
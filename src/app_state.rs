@@ -15,6 +15,10 @@ pub struct ConnectionState {
     pub is_connecting: bool,
     pub remote_ready: bool,
     pub since_connected: Option<Instant>,
+    // 连接成功后需要续传的 app（来自启动时的续传提示）
+    pub pending_upload_resume: Option<u32>,
+    // 当前游戏的配置档（连接时加载）
+    pub profile: Option<crate::config::GameProfile>,
 }
 
 impl ConnectionState {
@@ -23,6 +27,7 @@ impl ConnectionState {
         self.is_connecting = false;
         self.remote_ready = false;
         self.since_connected = None;
+        self.profile = None;
     }
 }
 
@@ -94,7 +99,11 @@ pub struct GameLibraryState {
     pub cloud_games: Vec<CloudGameInfo>,
     pub show_game_selector: bool,
     pub is_scanning_games: bool,
+    pub collection_filter: crate::game_scanner::CollectionFilter,
+    pub dynamic_collections: Vec<String>,
     pub all_users: Vec<UserInfo>,
+    // 用户头像纹理，加载失败的记为 None 避免每帧重试
+    pub avatar_textures: HashMap<u64, Option<eframe::egui::TextureHandle>>,
     pub show_user_selector: bool,
     pub vdf_count: usize,
     pub cdp_count: usize,
@@ -113,6 +122,7 @@ pub struct DialogState {
     pub upload_preview: Option<crate::ui::UploadPreviewDialog>,
     pub upload_progress: Option<crate::ui::UploadProgressDialog>,
     pub upload_complete: Option<crate::ui::UploadCompleteDialog>,
+    pub upload_resume: Option<crate::ui::UploadResumeDialog>,
     pub conflict_dialog: crate::ui::ConflictDialog,
     pub show_backup: bool,
     pub backup_preview: Option<crate::ui::BackupPreviewDialog>,
@@ -120,6 +130,9 @@ pub struct DialogState {
     pub download_progress: Option<crate::ui::DownloadProgressDialog>,
     pub appinfo_dialog: Option<crate::ui::AppInfoDialog>,
     pub symlink_dialog: Option<crate::ui::SymlinkDialog>,
+    pub symlink_health: Option<crate::ui::SymlinkHealthPanel>,
+    pub game_profile: Option<crate::ui::GameProfileDialog>,
+    pub user_compare: Option<crate::ui::UserCompareDialog>,
 }
 
 impl Default for DialogState {
@@ -131,11 +144,12 @@ impl Default for DialogState {
             result_message: String::new(),
             show_settings: false,
             settings_state: crate::ui::SettingsWindowState::default(),
-            show_debug_warning: !crate::cdp_client::CdpClient::is_cdp_running(),
+            show_debug_warning: !crate::web_remotestorage::is_available(),
             guide_dialog: None,
             upload_preview: None,
             upload_progress: None,
             upload_complete: None,
+            upload_resume: None,
             conflict_dialog: crate::ui::ConflictDialog::new(),
             show_backup: false,
             backup_preview: None,
@@ -143,6 +157,9 @@ impl Default for DialogState {
             download_progress: None,
             appinfo_dialog: None,
             symlink_dialog: None,
+            symlink_health: None,
+            game_profile: None,
+            user_compare: None,
         }
     }
 }
@@ -0,0 +1,225 @@
+// appinfo.vdf 版本格式描述
+// 文件头布局、条目头字段和字符串表位置都由格式表决定，支持新版本只需在 APPINFO_FORMATS 中添加一项
+
+use anyhow::Result;
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use std::io::{Read, Write};
+
+// 单个 appinfo.vdf 版本的格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AppInfoFormat {
+    pub version: u32,
+    pub magic: u32,
+    // 文件头包含字符串表偏移 (u64)，VDF 中的键名存储为字符串表索引
+    pub string_table: bool,
+    // 条目头包含二进制 VDF 数据的 SHA-1
+    pub binary_sha: bool,
+}
+
+// 已知的 appinfo.vdf 格式
+pub const APPINFO_FORMATS: &[AppInfoFormat] = &[
+    AppInfoFormat {
+        version: 27,
+        magic: 0x07564427,
+        string_table: false,
+        binary_sha: false,
+    },
+    AppInfoFormat {
+        version: 28,
+        magic: 0x07564428,
+        string_table: false,
+        binary_sha: true,
+    },
+    AppInfoFormat {
+        version: 29,
+        magic: 0x07564429,
+        string_table: true,
+        binary_sha: true,
+    },
+];
+
+// 条目头固定字段: info_state(4) + last_updated(4) + access_token(8) + text_sha(20) + change_number(4)
+const ENTRY_BASE_HEADER_SIZE: usize = 4 + 4 + 8 + 20 + 4;
+
+impl AppInfoFormat {
+    // 根据文件魔数查找格式
+    pub fn from_magic(magic: u32) -> std::result::Result<&'static Self, UnsupportedAppInfoVersion> {
+        APPINFO_FORMATS
+            .iter()
+            .find(|f| f.magic == magic)
+            .ok_or(UnsupportedAppInfoVersion { magic })
+    }
+
+    // 条目头大小（不含 app_id 和 size 字段）
+    pub fn entry_header_size(&self) -> usize {
+        ENTRY_BASE_HEADER_SIZE + if self.binary_sha { 20 } else { 0 }
+    }
+}
+
+// 已支持版本列表，如 "V27, V28, V29"
+pub fn supported_versions() -> String {
+    APPINFO_FORMATS
+        .iter()
+        .map(|f| format!("V{}", f.version))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// 遇到未知 appinfo.vdf 版本时的错误，界面据此显示专门的提示
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedAppInfoVersion {
+    pub magic: u32,
+}
+
+impl UnsupportedAppInfoVersion {
+    // 从错误链中识别未知版本错误
+    pub fn from_error(e: &anyhow::Error) -> Option<Self> {
+        e.downcast_ref::<Self>().copied()
+    }
+
+    // 用于界面展示的版本标签，如 "V30"，无法识别时显示原始魔数
+    pub fn version_label(&self) -> String {
+        match self.version_hint() {
+            Some(v) => format!("V{}", v),
+            None => format!("0x{:08X}", self.magic),
+        }
+    }
+
+    // 魔数低字节即版本号（0x075644xx）
+    pub fn version_hint(&self) -> Option<u32> {
+        if self.magic >> 8 == 0x075644 {
+            Some(self.magic & 0xFF)
+        } else {
+            None
+        }
+    }
+}
+
+impl std::fmt::Display for UnsupportedAppInfoVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "不支持的 appinfo.vdf 版本: 0x{:08X} (当前支持: {})",
+            self.magic,
+            supported_versions()
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedAppInfoVersion {}
+
+// appinfo.vdf 文件头
+#[derive(Debug, Clone, Copy)]
+pub struct AppInfoHeader {
+    pub format: &'static AppInfoFormat,
+    pub universe: u32,
+    pub string_table_offset: Option<u64>,
+}
+
+// 读取文件头
+pub fn read_header<R: Read>(reader: &mut R) -> Result<AppInfoHeader> {
+    let magic = reader.read_u32::<LittleEndian>()?;
+    let format = AppInfoFormat::from_magic(magic)?;
+    let universe = reader.read_u32::<LittleEndian>()?;
+    let string_table_offset = if format.string_table {
+        Some(reader.read_u64::<LittleEndian>()?)
+    } else {
+        None
+    };
+    Ok(AppInfoHeader {
+        format,
+        universe,
+        string_table_offset,
+    })
+}
+
+// 条目头（app_id 和 size 之后的字段）
+#[derive(Debug, Clone, Copy)]
+pub struct AppInfoEntryHeader {
+    pub info_state: u32,
+    pub last_updated: u32,
+    pub access_token: u64,
+    pub text_sha: [u8; 20],
+    pub change_number: u32,
+    pub binary_sha: Option<[u8; 20]>,
+}
+
+// 读取条目头
+pub fn read_entry_header<R: Read>(
+    reader: &mut R,
+    format: &AppInfoFormat,
+) -> Result<AppInfoEntryHeader> {
+    let info_state = reader.read_u32::<LittleEndian>()?;
+    let last_updated = reader.read_u32::<LittleEndian>()?;
+    let access_token = reader.read_u64::<LittleEndian>()?;
+    let mut text_sha = [0u8; 20];
+    reader.read_exact(&mut text_sha)?;
+    let change_number = reader.read_u32::<LittleEndian>()?;
+    let binary_sha = if format.binary_sha {
+        let mut sha = [0u8; 20];
+        reader.read_exact(&mut sha)?;
+        Some(sha)
+    } else {
+        None
+    };
+    Ok(AppInfoEntryHeader {
+        info_state,
+        last_updated,
+        access_token,
+        text_sha,
+        change_number,
+        binary_sha,
+    })
+}
+
+// 写入条目头
+pub fn write_entry_header<W: Write>(
+    writer: &mut W,
+    format: &AppInfoFormat,
+    header: &AppInfoEntryHeader,
+) -> Result<()> {
+    writer.write_u32::<LittleEndian>(header.info_state)?;
+    writer.write_u32::<LittleEndian>(header.last_updated)?;
+    writer.write_u64::<LittleEndian>(header.access_token)?;
+    writer.write_all(&header.text_sha)?;
+    writer.write_u32::<LittleEndian>(header.change_number)?;
+    if format.binary_sha {
+        writer.write_all(&header.binary_sha.unwrap_or([0u8; 20]))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn header_layout_follows_format_table() {
+        let v29 = AppInfoFormat::from_magic(0x07564429).unwrap();
+        assert_eq!(v29.entry_header_size(), 60);
+
+        let v27 = AppInfoFormat::from_magic(0x07564427).unwrap();
+        assert_eq!(v27.entry_header_size(), 40);
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&0x07564429u32.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&1234u64.to_le_bytes());
+        let header = read_header(&mut Cursor::new(&data)).unwrap();
+        assert_eq!(header.format.version, 29);
+        assert_eq!(header.string_table_offset, Some(1234));
+    }
+
+    #[test]
+    fn unknown_version_is_reported() {
+        let mut data = Vec::new();
+        data.extend_from_slice(&0x0756442Au32.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+
+        let err = read_header(&mut Cursor::new(&data)).unwrap_err();
+        let unsupported = UnsupportedAppInfoVersion::from_error(&err).unwrap();
+        assert_eq!(unsupported.version_label(), "V42");
+        assert!(err.to_string().contains("V27, V28, V29"));
+    }
+}
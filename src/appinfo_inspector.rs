@@ -0,0 +1,414 @@
+// appinfo.vdf 完整检查器
+// 将单个 app 的整棵 KeyValues 树（common/config/depots/ufs/extended 等）导出为文本或 JSON
+// cargo run -- --appinfo <appid> [--json]
+
+use anyhow::{Result, anyhow};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::Serialize;
+use serde::ser::{SerializeMap, Serializer};
+use std::io::Cursor;
+
+use crate::appinfo_format::{self, AppInfoFormat};
+use crate::vdf_parser::VdfParser;
+
+// 二进制 KeyValues 类型字节
+const KV_TYPE_SECTION: u8 = 0x00;
+const KV_TYPE_STRING: u8 = 0x01;
+const KV_TYPE_INT32: u8 = 0x02;
+const KV_TYPE_FLOAT32: u8 = 0x03;
+const KV_TYPE_UINT64: u8 = 0x07;
+const KV_TYPE_SECTION_END: u8 = 0x08;
+
+// 最大嵌套深度，防止损坏数据导致栈溢出
+const MAX_DEPTH: usize = 64;
+
+// KeyValues 值
+#[derive(Debug, Clone, PartialEq)]
+pub enum KvValue {
+    String(String),
+    Int32(i32),
+    Float32(f32),
+    UInt64(u64),
+    Section(Vec<KvNode>),
+}
+
+// KeyValues 节点（保持原始顺序，允许重复键）
+#[derive(Debug, Clone, PartialEq)]
+pub struct KvNode {
+    pub key: String,
+    pub value: KvValue,
+}
+
+// 单个 app 的完整 appinfo 条目
+#[derive(Debug, Clone, Serialize)]
+pub struct AppInfoDump {
+    pub app_id: u32,
+    pub version: u32,
+    pub info_state: u32,
+    pub last_updated: u32,
+    pub change_number: u32,
+    #[serde(serialize_with = "serialize_nodes")]
+    pub nodes: Vec<KvNode>,
+}
+
+impl Serialize for KvValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            KvValue::String(s) => serializer.serialize_str(s),
+            KvValue::Int32(v) => serializer.serialize_i32(*v),
+            KvValue::Float32(v) => serializer.serialize_f32(*v),
+            KvValue::UInt64(v) => serializer.serialize_u64(*v),
+            KvValue::Section(nodes) => serialize_nodes(nodes, serializer),
+        }
+    }
+}
+
+// 节点列表按键首次出现的顺序序列化为对象；KeyValues 允许重复键，
+// 重复的键合并为数组，避免 JSON 解析器只保留最后一个值
+fn serialize_nodes<S: Serializer>(
+    nodes: &[KvNode],
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    let mut groups: Vec<(&str, Vec<&KvValue>)> = Vec::new();
+    for node in nodes {
+        match groups.iter_mut().find(|(key, _)| *key == node.key) {
+            Some((_, values)) => values.push(&node.value),
+            None => groups.push((&node.key, vec![&node.value])),
+        }
+    }
+
+    let mut map = serializer.serialize_map(Some(groups.len()))?;
+    for (key, values) in &groups {
+        match values.as_slice() {
+            [value] => map.serialize_entry(key, value)?,
+            values => map.serialize_entry(key, values)?,
+        }
+    }
+    map.end()
+}
+
+impl AppInfoDump {
+    // 输出为 VDF 文本
+    pub fn to_text(&self) -> String {
+        nodes_to_text(&self.nodes)
+    }
+
+    // 输出为 JSON 文本
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+// 节点列表转 VDF 文本
+pub fn nodes_to_text(nodes: &[KvNode]) -> String {
+    let mut lines = Vec::new();
+    push_nodes(&mut lines, nodes, 0);
+    lines.join("\n")
+}
+
+fn push_nodes(lines: &mut Vec<String>, nodes: &[KvNode], indent: usize) {
+    let indent_str = "    ".repeat(indent);
+    for node in nodes {
+        match &node.value {
+            KvValue::Section(children) => {
+                lines.push(format!("{}\"{}\"", indent_str, node.key));
+                lines.push(format!("{}{{", indent_str));
+                push_nodes(lines, children, indent + 1);
+                lines.push(format!("{}}}", indent_str));
+            }
+            value => {
+                lines.push(format!(
+                    "{}\"{}\" \"{}\"",
+                    indent_str,
+                    node.key,
+                    scalar_to_string(value)
+                ));
+            }
+        }
+    }
+}
+
+fn scalar_to_string(value: &KvValue) -> String {
+    match value {
+        KvValue::String(s) => s.clone(),
+        KvValue::Int32(v) => v.to_string(),
+        KvValue::Float32(v) => v.to_string(),
+        KvValue::UInt64(v) => v.to_string(),
+        KvValue::Section(_) => String::new(),
+    }
+}
+
+// 按关键字过滤（不区分大小写）
+// 键或值匹配的节点保留；节名匹配时保留整个子树；否则只保留包含匹配项的子节
+pub fn filter_nodes(nodes: &[KvNode], query: &str) -> Vec<KvNode> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return nodes.to_vec();
+    }
+    filter_nodes_inner(nodes, &query)
+}
+
+fn filter_nodes_inner(nodes: &[KvNode], query: &str) -> Vec<KvNode> {
+    let mut result = Vec::new();
+    for node in nodes {
+        let key_matches = node.key.to_lowercase().contains(query);
+        match &node.value {
+            KvValue::Section(children) => {
+                if key_matches {
+                    result.push(node.clone());
+                } else {
+                    let filtered = filter_nodes_inner(children, query);
+                    if !filtered.is_empty() {
+                        result.push(KvNode {
+                            key: node.key.clone(),
+                            value: KvValue::Section(filtered),
+                        });
+                    }
+                }
+            }
+            value => {
+                if key_matches || scalar_to_string(value).to_lowercase().contains(query) {
+                    result.push(node.clone());
+                }
+            }
+        }
+    }
+    result
+}
+
+// 从当前 Steam 安装读取指定 app 的完整条目
+pub fn load_app_info(app_id: u32) -> Result<AppInfoDump> {
+    let steam_path = VdfParser::find_steam_path()?;
+    let appinfo_path = steam_path.join("appcache").join("appinfo.vdf");
+    if !appinfo_path.exists() {
+        return Err(anyhow!("appinfo.vdf 不存在: {:?}", appinfo_path));
+    }
+    let data = std::fs::read(&appinfo_path)?;
+    parse_app_info(&data, app_id)
+}
+
+// 从 appinfo.vdf 数据中解析指定 app 的完整条目
+pub fn parse_app_info(data: &[u8], target_app_id: u32) -> Result<AppInfoDump> {
+    let mut cursor = Cursor::new(data);
+
+    let header = appinfo_format::read_header(&mut cursor)?;
+    let format = header.format;
+
+    let string_table = match header.string_table_offset {
+        Some(offset) => VdfParser::parse_string_table(data, offset as usize)?,
+        None => Vec::new(),
+    };
+
+    loop {
+        let entry_start = cursor.position();
+        let app_id = cursor.read_u32::<LittleEndian>()?;
+        if app_id == 0 {
+            break;
+        }
+        let size = cursor.read_u32::<LittleEndian>()?;
+        let entry_end = entry_start + 8 + size as u64;
+
+        if app_id != target_app_id {
+            cursor.set_position(entry_end);
+            continue;
+        }
+
+        let entry_header = appinfo_format::read_entry_header(&mut cursor, format)?;
+
+        let vdf_start = cursor.position() as usize;
+        let vdf_end = (entry_end as usize).min(data.len());
+        if vdf_start > vdf_end {
+            return Err(anyhow!("app_id {} 的条目大小无效", app_id));
+        }
+
+        let mut vdf_cursor = Cursor::new(&data[vdf_start..vdf_end]);
+        let nodes = parse_kv_nodes(&mut vdf_cursor, &string_table, format, 0)?;
+
+        return Ok(AppInfoDump {
+            app_id,
+            version: format.version,
+            info_state: entry_header.info_state,
+            last_updated: entry_header.last_updated,
+            change_number: entry_header.change_number,
+            nodes,
+        });
+    }
+
+    Err(anyhow!("未找到 app_id {} 的配置", target_app_id))
+}
+
+// 递归解析二进制 KeyValues，直到节结束标记或数据结束
+fn parse_kv_nodes(
+    cursor: &mut Cursor<&[u8]>,
+    string_table: &[String],
+    format: &AppInfoFormat,
+    depth: usize,
+) -> Result<Vec<KvNode>> {
+    if depth > MAX_DEPTH {
+        return Err(anyhow!("KeyValues 嵌套过深"));
+    }
+
+    let mut nodes = Vec::new();
+    while let Ok(type_byte) = cursor.read_u8() {
+        if type_byte == KV_TYPE_SECTION_END {
+            break;
+        }
+
+        let key = if format.string_table {
+            let idx = cursor.read_u32::<LittleEndian>()? as usize;
+            string_table
+                .get(idx)
+                .cloned()
+                .unwrap_or_else(|| format!("#{}", idx))
+        } else {
+            read_null_string(cursor)
+        };
+
+        let value = match type_byte {
+            KV_TYPE_SECTION => {
+                KvValue::Section(parse_kv_nodes(cursor, string_table, format, depth + 1)?)
+            }
+            KV_TYPE_STRING => KvValue::String(read_null_string(cursor)),
+            KV_TYPE_INT32 => KvValue::Int32(cursor.read_i32::<LittleEndian>()?),
+            KV_TYPE_FLOAT32 => KvValue::Float32(cursor.read_f32::<LittleEndian>()?),
+            KV_TYPE_UINT64 => KvValue::UInt64(cursor.read_u64::<LittleEndian>()?),
+            other => {
+                return Err(anyhow!(
+                    "未知 KeyValues 类型 0x{:02x} (键: {}, 位置: {})",
+                    other,
+                    key,
+                    cursor.position()
+                ));
+            }
+        };
+        nodes.push(KvNode { key, value });
+    }
+    Ok(nodes)
+}
+
+fn read_null_string(cursor: &mut Cursor<&[u8]>) -> String {
+    let mut bytes = Vec::new();
+    while let Ok(b) = cursor.read_u8() {
+        if b == 0 {
+            break;
+        }
+        bytes.push(b);
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+// 输出指定 AppID 的完整 appinfo 到标准输出
+pub fn dump(app_id_str: &str, as_json: bool) -> Result<()> {
+    let app_id: u32 = app_id_str
+        .parse()
+        .map_err(|_| anyhow!("无效的 AppID: {}", app_id_str))?;
+
+    let info = load_app_info(app_id).map_err(|e| anyhow!("读取 appinfo 失败: {}", e))?;
+    if as_json {
+        let json = info
+            .to_json()
+            .map_err(|e| anyhow!("JSON 序列化失败: {}", e))?;
+        println!("{}", json);
+    } else {
+        println!("{}", info.to_text());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 构造只含一个 app 的 V28 appinfo.vdf
+    fn sample_v28() -> Vec<u8> {
+        let mut vdf = Vec::new();
+        vdf.extend_from_slice(b"\x00appinfo\0");
+        vdf.extend_from_slice(b"\x02appid\0");
+        vdf.extend_from_slice(&620i32.to_le_bytes());
+        vdf.extend_from_slice(b"\x00common\0\x01name\0Portal 2\0\x08");
+        vdf.extend_from_slice(b"\x00ufs\0\x02quota\0");
+        vdf.extend_from_slice(&1024i32.to_le_bytes());
+        vdf.extend_from_slice(b"\x08\x08");
+
+        let mut entry = Vec::new();
+        entry.extend_from_slice(&2u32.to_le_bytes()); // info_state
+        entry.extend_from_slice(&1_700_000_000u32.to_le_bytes()); // last_updated
+        entry.extend_from_slice(&0u64.to_le_bytes()); // access_token
+        entry.extend_from_slice(&[0u8; 20]); // sha
+        entry.extend_from_slice(&42u32.to_le_bytes()); // change_number
+        entry.extend_from_slice(&[0u8; 20]); // binary sha
+        entry.extend_from_slice(&vdf);
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&0x07564428u32.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&620u32.to_le_bytes());
+        data.extend_from_slice(&(entry.len() as u32).to_le_bytes());
+        data.extend_from_slice(&entry);
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn parses_full_tree_as_text_and_json() {
+        let info = parse_app_info(&sample_v28(), 620).unwrap();
+        assert_eq!(info.version, 28);
+        assert_eq!(info.change_number, 42);
+
+        let text = info.to_text();
+        assert!(text.contains("\"name\" \"Portal 2\""));
+        assert!(text.contains("\"quota\" \"1024\""));
+
+        let json: serde_json::Value = serde_json::from_str(&info.to_json().unwrap()).unwrap();
+        assert_eq!(json["nodes"]["appinfo"]["common"]["name"], "Portal 2");
+        assert_eq!(json["nodes"]["appinfo"]["ufs"]["quota"], 1024);
+
+        assert!(parse_app_info(&sample_v28(), 1).is_err());
+    }
+
+    #[test]
+    fn duplicate_keys_become_json_arrays() {
+        let dump = AppInfoDump {
+            app_id: 1,
+            version: 28,
+            info_state: 0,
+            last_updated: 0,
+            change_number: 0,
+            nodes: vec![
+                KvNode {
+                    key: "launch".to_string(),
+                    value: KvValue::String("a".to_string()),
+                },
+                KvNode {
+                    key: "name".to_string(),
+                    value: KvValue::String("Game".to_string()),
+                },
+                KvNode {
+                    key: "launch".to_string(),
+                    value: KvValue::Int32(2),
+                },
+            ],
+        };
+
+        let json = dump.to_json().unwrap();
+        assert_eq!(json.matches("\"launch\"").count(), 1);
+        let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(json["nodes"]["launch"], serde_json::json!(["a", 2]));
+        assert_eq!(json["nodes"]["name"], "Game");
+    }
+
+    #[test]
+    fn filter_keeps_matching_paths() {
+        let info = parse_app_info(&sample_v28(), 620).unwrap();
+
+        let filtered = nodes_to_text(&filter_nodes(&info.nodes, "QUOTA"));
+        assert!(filtered.contains("\"ufs\""));
+        assert!(filtered.contains("\"quota\" \"1024\""));
+        assert!(!filtered.contains("common"));
+
+        // 节名匹配时保留整个子树
+        let filtered = nodes_to_text(&filter_nodes(&info.nodes, "common"));
+        assert!(filtered.contains("Portal 2"));
+        assert!(!filtered.contains("quota"));
+    }
+}
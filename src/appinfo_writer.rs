@@ -6,23 +6,22 @@ use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use sha1::{Digest, Sha1};
 use std::collections::HashMap;
 use std::fs;
-use std::io::{Cursor, Read};
+use std::io::Cursor;
 use std::path::PathBuf;
 
-use crate::path_resolver::SaveFileConfig;
+use crate::appinfo_format::{self, AppInfoFormat};
+use crate::config::{
+    RootOverrideEntry, SaveFileEntry, UfsExtraKey, UfsExtraValue, UfsGameConfig, UfsSettings,
+};
 use crate::vdf_parser::VdfParser;
 
 // VDF 二进制类型字节常量
 const VDF_TYPE_SECTION: u8 = 0x00;
 const VDF_TYPE_STRING: u8 = 0x01;
 const VDF_TYPE_INT32: u8 = 0x02;
+const VDF_TYPE_UINT64: u8 = 0x07;
 const VDF_TYPE_SECTION_END: u8 = 0x08;
 
-// appinfo.vdf 版本魔数
-const APPINFO_V27: u32 = 0x07564427;
-const APPINFO_V28: u32 = 0x07564428;
-const APPINFO_V29: u32 = 0x07564429;
-
 // UFS 节位置信息（用于减少函数参数数量）
 #[derive(Default)]
 struct UfsSectionInfo {
@@ -35,6 +34,8 @@ struct UfsSectionInfo {
     rootoverrides_end_pos: Option<usize>,
     last_rootoverride_index: u32,
     root_end_pos: Option<usize>,
+    // ufs 节的直接子项: (键名, 起始位置, 结束位置(排他))
+    ufs_children: Vec<(String, usize, usize)>,
 }
 
 pub struct AppInfoWriter {
@@ -54,11 +55,13 @@ impl AppInfoWriter {
 
     // 完整注入 UFS 配置（savefiles + rootoverrides）
     // 使用新版配置格式
-    pub fn inject_full_ufs_config(
-        &self,
-        app_id: u32,
-        config: &crate::config::UfsGameConfig,
-    ) -> Result<()> {
+    pub fn inject_full_ufs_config(&self, app_id: u32, config: &UfsGameConfig) -> Result<()> {
+        // 配置有错误时拒绝写入，避免生成 Steam 无法识别的 ufs 节
+        let report = crate::ufs_validator::validate_game_config(config);
+        if report.has_errors() {
+            return Err(anyhow!("UFS 配置校验失败: {}", report.error_summary()));
+        }
+
         let appinfo_path = self.appinfo_path();
         if !appinfo_path.exists() {
             return Err(anyhow!("appinfo.vdf 不存在: {:?}", appinfo_path));
@@ -73,22 +76,7 @@ impl AppInfoWriter {
 
         let data = fs::read(&appinfo_path)?;
 
-        // 转换 SaveFileEntry 为 SaveFileConfig
-        let savefiles: Vec<SaveFileConfig> = config
-            .savefiles
-            .iter()
-            .map(|s| SaveFileConfig {
-                root: s.root.clone(),
-                root_type: crate::path_resolver::RootType::from_name(&s.root),
-                path: s.path.clone(),
-                pattern: s.pattern.clone(),
-                platforms: s.platforms.clone(),
-                recursive: s.recursive,
-            })
-            .collect();
-
-        let modified_data =
-            self.modify_app_ufs_full(&data, app_id, savefiles, &config.root_overrides)?;
+        let modified_data = self.modify_app_ufs_full(&data, app_id, config)?;
 
         fs::write(&appinfo_path, &modified_data)?;
         tracing::info!(
@@ -103,36 +91,22 @@ impl AppInfoWriter {
         Ok(())
     }
 
-    // 修改指定 app 的完整 ufs 配置（savefiles + rootoverrides）
+    // 修改指定 app 的完整 ufs 配置（顶层设置 + savefiles + rootoverrides）
     fn modify_app_ufs_full(
         &self,
         data: &[u8],
         target_app_id: u32,
-        custom_savefiles: Vec<SaveFileConfig>,
-        custom_overrides: &[crate::config::RootOverrideEntry],
+        config: &UfsGameConfig,
     ) -> Result<Vec<u8>> {
         let mut cursor = Cursor::new(data);
 
         // 读取文件头
-        let magic = cursor.read_u32::<LittleEndian>()?;
-        let version = match magic {
-            APPINFO_V27 => 27,
-            APPINFO_V28 => 28,
-            APPINFO_V29 => 29,
-            _ => return Err(anyhow!("不支持的 appinfo.vdf 版本: 0x{:X}", magic)),
-        };
+        let header = appinfo_format::read_header(&mut cursor)?;
+        let format = header.format;
+        let string_table_offset = header.string_table_offset.unwrap_or(0);
 
-        let universe = cursor.read_u32::<LittleEndian>()?;
-
-        // V29+ 读取字符串表偏移
-        let string_table_offset = if version >= 29 {
-            cursor.read_u64::<LittleEndian>()?
-        } else {
-            0
-        };
-
-        // 解析字符串表 (V29+)
-        let mut string_table = if version >= 29 && string_table_offset > 0 {
+        // 解析字符串表
+        let mut string_table = if string_table_offset > 0 {
             self.parse_string_table(data, string_table_offset as usize)?
         } else {
             Vec::new()
@@ -176,12 +150,11 @@ impl AppInfoWriter {
         // 读取原始条目
         let original_entry = &data[start..end];
 
-        // 修改条目（包含 savefiles 和 rootoverrides）
+        // 修改条目（包含顶层设置、savefiles 和 rootoverrides）
         let modified_entry = self.modify_entry_full(
             original_entry,
-            &custom_savefiles,
-            custom_overrides,
-            version,
+            config,
+            format,
             &mut string_table,
             &mut string_to_idx,
         )?;
@@ -190,11 +163,11 @@ impl AppInfoWriter {
         let mut result = Vec::new();
 
         // 写入文件头
-        result.write_u32::<LittleEndian>(magic)?;
-        result.write_u32::<LittleEndian>(universe)?;
+        result.write_u32::<LittleEndian>(format.magic)?;
+        result.write_u32::<LittleEndian>(header.universe)?;
 
-        // V29+ 写入字符串表偏移占位符（稍后更新）
-        let string_table_offset_pos = if version >= 29 {
+        // 写入字符串表偏移占位符（稍后更新）
+        let string_table_offset_pos = if format.string_table {
             let pos = result.len();
             result.write_u64::<LittleEndian>(0)?;
             Some(pos)
@@ -209,7 +182,7 @@ impl AppInfoWriter {
         result.extend_from_slice(&modified_entry);
 
         // 写入目标条目之后的所有条目（不包括字符串表）
-        let entries_end = if version >= 29 {
+        let entries_end = if format.string_table {
             string_table_offset as usize
         } else {
             data.len()
@@ -219,8 +192,8 @@ impl AppInfoWriter {
             result.extend_from_slice(&data[end..entries_end]);
         }
 
-        // V29+ 更新字符串表
-        if version >= 29 {
+        // 更新字符串表
+        if format.string_table {
             // 更新字符串表偏移
             let new_string_table_offset = result.len() as u64;
             if let Some(pos) = string_table_offset_pos {
@@ -245,9 +218,8 @@ impl AppInfoWriter {
     fn modify_entry_full(
         &self,
         entry: &[u8],
-        custom_savefiles: &[SaveFileConfig],
-        custom_overrides: &[crate::config::RootOverrideEntry],
-        version: u32,
+        config: &UfsGameConfig,
+        format: &AppInfoFormat,
         string_table: &mut Vec<String>,
         string_to_idx: &mut HashMap<String, usize>,
     ) -> Result<Vec<u8>> {
@@ -256,71 +228,48 @@ impl AppInfoWriter {
         // 读取头部
         let app_id = cursor.read_u32::<LittleEndian>()?;
         let _size = cursor.read_u32::<LittleEndian>()?;
-        let info_state = cursor.read_u32::<LittleEndian>()?;
-        let last_updated = cursor.read_u32::<LittleEndian>()?;
-        let access_token = cursor.read_u64::<LittleEndian>()?;
-
-        let mut checksum_text = [0u8; 20];
-        cursor.read_exact(&mut checksum_text)?;
-
-        let change_number = cursor.read_u32::<LittleEndian>()?;
-
-        let mut checksum_binary = [0u8; 20];
-        if version >= 28 {
-            cursor.read_exact(&mut checksum_binary)?;
-        }
+        let mut header = appinfo_format::read_entry_header(&mut cursor, format)?;
 
         // 读取 VDF 数据
         let vdf_start = cursor.position() as usize;
         let vdf_data = &entry[vdf_start..];
 
-        // 修改 VDF 数据，添加自定义 savefiles 和 rootoverrides
-        let modified_vdf = self.inject_full_ufs_to_vdf(
-            vdf_data,
-            custom_savefiles,
-            custom_overrides,
-            version,
-            string_table,
-            string_to_idx,
-        )?;
+        // 修改 VDF 数据，写入自定义 ufs 配置
+        let modified_vdf =
+            self.inject_full_ufs_to_vdf(vdf_data, config, format, string_table, string_to_idx)?;
 
         // 计算新的校验和
-        let new_checksum_binary = self.calculate_binary_checksum(&modified_vdf);
         let new_checksum_text =
-            self.calculate_text_checksum(&modified_vdf, string_table, version)?;
+            self.calculate_text_checksum(&modified_vdf, string_table, format)?;
 
         // 构建新的条目
-        let header_size: usize = 4 + 4 + 8 + 20 + 4 + if version >= 28 { 20 } else { 0 };
-        let new_size = (header_size + modified_vdf.len()) as u32;
+        let new_size = (format.entry_header_size() + modified_vdf.len()) as u32;
+        header.text_sha = new_checksum_text;
+        header.binary_sha = format
+            .binary_sha
+            .then(|| self.calculate_binary_checksum(&modified_vdf));
 
         let mut result = Vec::new();
         result.write_u32::<LittleEndian>(app_id)?;
         result.write_u32::<LittleEndian>(new_size)?;
-        result.write_u32::<LittleEndian>(info_state)?;
-        result.write_u32::<LittleEndian>(last_updated)?;
-        result.write_u64::<LittleEndian>(access_token)?;
-        result.extend_from_slice(&new_checksum_text);
-        result.write_u32::<LittleEndian>(change_number)?;
-        if version >= 28 {
-            result.extend_from_slice(&new_checksum_binary);
-        }
+        appinfo_format::write_entry_header(&mut result, format, &header)?;
         result.extend_from_slice(&modified_vdf);
 
         Ok(result)
     }
 
-    // 将完整 UFS 配置（savefiles + rootoverrides）注入到 VDF 数据中
+    // 将完整 UFS 配置（顶层设置 + savefiles + rootoverrides）注入到 VDF 数据中
     //
     // 设计原则：
     // 1. 完整解析 VDF 获取所有关键位置
     // 2. 使用 "复制-跳过-插入" 模式避免数据重复
     // 3. 支持三种模式：创建新节、替换现有节、追加到现有节
+    // 4. 配置中未涉及的 ufs 顶层键保持原样
     fn inject_full_ufs_to_vdf(
         &self,
         vdf_data: &[u8],
-        custom_savefiles: &[SaveFileConfig],
-        custom_overrides: &[crate::config::RootOverrideEntry],
-        version: u32,
+        config: &UfsGameConfig,
+        format: &AppInfoFormat,
         string_table: &mut Vec<String>,
         string_to_idx: &mut HashMap<String, usize>,
     ) -> Result<Vec<u8>> {
@@ -328,7 +277,7 @@ impl AppInfoWriter {
 
         // 第一阶段：解析 VDF 结构，收集所有关键位置
         let mut info = UfsSectionInfo::default();
-        if version >= 29 {
+        if format.string_table {
             self.find_ufs_section_v29_improved(&mut cursor, string_table, &mut info)?;
         } else {
             self.find_ufs_section_v28_improved(&mut cursor, &mut info)?;
@@ -371,6 +320,14 @@ impl AppInfoWriter {
                 skip_ranges.push((ro_start, ro_end + 1));
             }
 
+            // 跳过将被配置覆盖的顶层键（quota / maxnumfiles 等）
+            let replaced_keys = Self::replaced_ufs_keys(&config.settings);
+            for (key, start, end) in &info.ufs_children {
+                if replaced_keys.contains(&key.as_str()) {
+                    skip_ranges.push((*start, *end));
+                }
+            }
+
             // 按起始位置排序
             skip_ranges.sort_by_key(|r| r.0);
 
@@ -388,27 +345,23 @@ impl AppInfoWriter {
                 result.extend_from_slice(&vdf_data[pos..ufs_end]);
             }
 
-            // 插入新的 savefiles 节（如果有内容）
-            if !custom_savefiles.is_empty() {
-                self.write_savefiles_section(
-                    &mut result,
-                    custom_savefiles,
-                    version,
-                    string_table,
-                    string_to_idx,
-                );
-            }
-
-            // 插入新的 rootoverrides 节（如果有内容）
-            if !custom_overrides.is_empty() {
-                self.write_rootoverrides_section(
-                    &mut result,
-                    custom_overrides,
-                    version,
-                    string_table,
-                    string_to_idx,
-                );
-            }
+            // 文本编辑会把数值变成字符串，按原 ufs 节中同名键的类型还原
+            let original: Vec<UfsExtraKey> = info
+                .ufs_children
+                .iter()
+                .filter_map(|(_, start, end)| {
+                    self.read_typed_key(
+                        &mut Cursor::new(&vdf_data[*start..*end]),
+                        format,
+                        string_table,
+                    )
+                })
+                .collect();
+            let mut typed = config.clone();
+            restore_config_types(&mut typed, &original);
+
+            // 插入新的 ufs 内容
+            self.write_ufs_contents(&mut result, &typed, format, string_table, string_to_idx);
 
             // 复制 ufs_end（0x08）及之后的所有内容
             result.extend_from_slice(&vdf_data[ufs_end..]);
@@ -420,7 +373,7 @@ impl AppInfoWriter {
             result.extend_from_slice(&vdf_data[..root_end]);
 
             // 创建 ufs 节
-            if version >= 29 {
+            if format.string_table {
                 let ufs_idx = self.get_or_create_string_index("ufs", string_table, string_to_idx);
                 result.push(VDF_TYPE_SECTION);
                 result.extend_from_slice(&(ufs_idx as u32).to_le_bytes());
@@ -429,27 +382,8 @@ impl AppInfoWriter {
                 result.extend_from_slice(b"ufs\0");
             }
 
-            // 写入 savefiles
-            if !custom_savefiles.is_empty() {
-                self.write_savefiles_section(
-                    &mut result,
-                    custom_savefiles,
-                    version,
-                    string_table,
-                    string_to_idx,
-                );
-            }
-
-            // 写入 rootoverrides
-            if !custom_overrides.is_empty() {
-                self.write_rootoverrides_section(
-                    &mut result,
-                    custom_overrides,
-                    version,
-                    string_table,
-                    string_to_idx,
-                );
-            }
+            // 写入 ufs 内容
+            self.write_ufs_contents(&mut result, config, format, string_table, string_to_idx);
 
             // ufs 节结束
             result.push(VDF_TYPE_SECTION_END);
@@ -462,8 +396,8 @@ impl AppInfoWriter {
 
         tracing::info!(
             "已注入完整 UFS 配置: {} savefiles, {} rootoverrides, 大小变化: {} -> {}",
-            custom_savefiles.len(),
-            custom_overrides.len(),
+            config.savefiles.len(),
+            config.root_overrides.len(),
             vdf_data.len(),
             result.len()
         );
@@ -471,17 +405,203 @@ impl AppInfoWriter {
         Ok(result)
     }
 
+    // 配置中会重新写入或明确删除的 ufs 顶层键，其余原有键原样保留
+    fn replaced_ufs_keys(settings: &UfsSettings) -> Vec<&str> {
+        let mut keys = settings.keys();
+        keys.extend(settings.removed.iter().map(String::as_str));
+        keys
+    }
+
+    // 写入 ufs 节内容：顶层设置、savefiles、rootoverrides、未识别的键
+    fn write_ufs_contents(
+        &self,
+        result: &mut Vec<u8>,
+        config: &UfsGameConfig,
+        format: &AppInfoFormat,
+        string_table: &mut Vec<String>,
+        string_to_idx: &mut HashMap<String, usize>,
+    ) {
+        let settings = &config.settings;
+
+        // quota 超出 INT32 范围时使用 UINT64
+        if let Some(quota) = settings.quota {
+            if let Ok(value) = i32::try_from(quota) {
+                self.write_key(
+                    result,
+                    VDF_TYPE_INT32,
+                    "quota",
+                    format,
+                    string_table,
+                    string_to_idx,
+                );
+                result.extend_from_slice(&value.to_le_bytes());
+            } else {
+                self.write_key(
+                    result,
+                    VDF_TYPE_UINT64,
+                    "quota",
+                    format,
+                    string_table,
+                    string_to_idx,
+                );
+                result.extend_from_slice(&quota.to_le_bytes());
+            }
+        }
+        if let Some(maxnumfiles) = settings.maxnumfiles {
+            self.write_key(
+                result,
+                VDF_TYPE_INT32,
+                "maxnumfiles",
+                format,
+                string_table,
+                string_to_idx,
+            );
+            result.extend_from_slice(&maxnumfiles.to_le_bytes());
+        }
+        if let Some(hidecloudui) = settings.hidecloudui {
+            self.write_key(
+                result,
+                VDF_TYPE_INT32,
+                "hidecloudui",
+                format,
+                string_table,
+                string_to_idx,
+            );
+            result.extend_from_slice(&(hidecloudui as u32).to_le_bytes());
+        }
+
+        if !config.savefiles.is_empty() {
+            self.write_savefiles_section(
+                result,
+                &config.savefiles,
+                format,
+                string_table,
+                string_to_idx,
+            );
+        }
+
+        if !config.root_overrides.is_empty() {
+            self.write_rootoverrides_section(
+                result,
+                &config.root_overrides,
+                format,
+                string_table,
+                string_to_idx,
+            );
+        }
+
+        self.write_extra_keys(result, &settings.extra, format, string_table, string_to_idx);
+    }
+
+    // 写入键头：类型字节 + 键名（V29 为字符串表索引，V28 为 null 结尾字符串）
+    fn write_key(
+        &self,
+        result: &mut Vec<u8>,
+        type_byte: u8,
+        key: &str,
+        format: &AppInfoFormat,
+        string_table: &mut Vec<String>,
+        string_to_idx: &mut HashMap<String, usize>,
+    ) {
+        result.push(type_byte);
+        if format.string_table {
+            let idx = self.get_or_create_string_index(key, string_table, string_to_idx);
+            result.extend_from_slice(&(idx as u32).to_le_bytes());
+        } else {
+            result.extend_from_slice(key.as_bytes());
+            result.push(0);
+        }
+    }
+
+    // 写入未识别的键，数值按原始类型写回
+    fn write_extra_keys(
+        &self,
+        result: &mut Vec<u8>,
+        extra: &[UfsExtraKey],
+        format: &AppInfoFormat,
+        string_table: &mut Vec<String>,
+        string_to_idx: &mut HashMap<String, usize>,
+    ) {
+        for item in extra {
+            match &item.value {
+                UfsExtraValue::Value(value) => {
+                    self.write_key(
+                        result,
+                        VDF_TYPE_STRING,
+                        &item.key,
+                        format,
+                        string_table,
+                        string_to_idx,
+                    );
+                    result.extend_from_slice(value.as_bytes());
+                    result.push(0);
+                }
+                UfsExtraValue::Int32(value) => {
+                    self.write_key(
+                        result,
+                        VDF_TYPE_INT32,
+                        &item.key,
+                        format,
+                        string_table,
+                        string_to_idx,
+                    );
+                    result.extend_from_slice(&value.to_le_bytes());
+                }
+                UfsExtraValue::UInt64(value) => {
+                    self.write_key(
+                        result,
+                        VDF_TYPE_UINT64,
+                        &item.key,
+                        format,
+                        string_table,
+                        string_to_idx,
+                    );
+                    result.extend_from_slice(&value.to_le_bytes());
+                }
+                UfsExtraValue::Section(children) => {
+                    self.write_key(
+                        result,
+                        VDF_TYPE_SECTION,
+                        &item.key,
+                        format,
+                        string_table,
+                        string_to_idx,
+                    );
+                    self.write_extra_keys(result, children, format, string_table, string_to_idx);
+                    result.push(VDF_TYPE_SECTION_END);
+                }
+            }
+        }
+    }
+
+    // 在已编码条目的结束标记前追加未识别的键
+    fn append_entry_extras(
+        &self,
+        encoded: &mut Vec<u8>,
+        extra: &[UfsExtraKey],
+        format: &AppInfoFormat,
+        string_table: &mut Vec<String>,
+        string_to_idx: &mut HashMap<String, usize>,
+    ) {
+        if extra.is_empty() || encoded.last() != Some(&VDF_TYPE_SECTION_END) {
+            return;
+        }
+        encoded.pop();
+        self.write_extra_keys(encoded, extra, format, string_table, string_to_idx);
+        encoded.push(VDF_TYPE_SECTION_END);
+    }
+
     // 写入 savefiles 节（helper 函数）
     fn write_savefiles_section(
         &self,
         result: &mut Vec<u8>,
-        savefiles: &[SaveFileConfig],
-        version: u32,
+        savefiles: &[SaveFileEntry],
+        format: &AppInfoFormat,
         string_table: &mut Vec<String>,
         string_to_idx: &mut HashMap<String, usize>,
     ) {
         // 节头
-        if version >= 29 {
+        if format.string_table {
             let idx = self.get_or_create_string_index("savefiles", string_table, string_to_idx);
             result.push(VDF_TYPE_SECTION);
             result.extend_from_slice(&(idx as u32).to_le_bytes());
@@ -492,14 +612,19 @@ impl AppInfoWriter {
 
         // 条目
         for (i, savefile) in savefiles.iter().enumerate() {
-            if version >= 29 {
-                let encoded =
-                    self.encode_savefile_v29(savefile, i as u32, string_table, string_to_idx);
-                result.extend_from_slice(&encoded);
+            let mut encoded = if format.string_table {
+                self.encode_savefile_v29(savefile, i as u32, string_table, string_to_idx)
             } else {
-                let encoded = self.encode_savefile_v28(savefile, i as u32);
-                result.extend_from_slice(&encoded);
-            }
+                self.encode_savefile_v28(savefile, i as u32)
+            };
+            self.append_entry_extras(
+                &mut encoded,
+                &savefile.extra,
+                format,
+                string_table,
+                string_to_idx,
+            );
+            result.extend_from_slice(&encoded);
         }
 
         // 节结束
@@ -510,13 +635,13 @@ impl AppInfoWriter {
     fn write_rootoverrides_section(
         &self,
         result: &mut Vec<u8>,
-        overrides: &[crate::config::RootOverrideEntry],
-        version: u32,
+        overrides: &[RootOverrideEntry],
+        format: &AppInfoFormat,
         string_table: &mut Vec<String>,
         string_to_idx: &mut HashMap<String, usize>,
     ) {
         // 节头
-        if version >= 29 {
+        if format.string_table {
             let idx = self.get_or_create_string_index("rootoverrides", string_table, string_to_idx);
             result.push(VDF_TYPE_SECTION);
             result.extend_from_slice(&(idx as u32).to_le_bytes());
@@ -528,14 +653,19 @@ impl AppInfoWriter {
         // 条目 - rootoverrides 索引从 0 开始（与 savefiles 相同）
         for (i, override_entry) in overrides.iter().enumerate() {
             let idx = i as u32;
-            if version >= 29 {
-                let encoded =
-                    self.encode_rootoverride_v29(override_entry, idx, string_table, string_to_idx);
-                result.extend_from_slice(&encoded);
+            let mut encoded = if format.string_table {
+                self.encode_rootoverride_v29(override_entry, idx, string_table, string_to_idx)
             } else {
-                let encoded = self.encode_rootoverride_v28(override_entry, idx);
-                result.extend_from_slice(&encoded);
-            }
+                self.encode_rootoverride_v28(override_entry, idx)
+            };
+            self.append_entry_extras(
+                &mut encoded,
+                &override_entry.extra,
+                format,
+                string_table,
+                string_to_idx,
+            );
+            result.extend_from_slice(&encoded);
         }
 
         // 节结束
@@ -557,6 +687,8 @@ impl AppInfoWriter {
         let mut ufs_depth = 0;
         let mut savefiles_depth = 0;
         let mut rootoverrides_depth = 0;
+        // 当前正在读取的 ufs 直接子节 (键名, 起始位置)
+        let mut ufs_child_start: Option<(String, usize)> = None;
 
         while let Ok(type_byte) = cursor.read_u8() {
             let pos = cursor.position() as usize - 1;
@@ -565,6 +697,14 @@ impl AppInfoWriter {
                 // 先减少深度
                 depth -= 1;
 
+                // ufs 直接子节结束
+                if in_ufs
+                    && depth == ufs_depth
+                    && let Some((key, start)) = ufs_child_start.take()
+                {
+                    info.ufs_children.push((key, start, pos + 1));
+                }
+
                 // 检查是否是各节的真正结束
                 if in_savefiles && depth < savefiles_depth {
                     info.savefiles_end_pos = Some(pos);
@@ -590,6 +730,9 @@ impl AppInfoWriter {
             match type_byte {
                 VDF_TYPE_SECTION => {
                     depth += 1;
+                    if in_ufs && depth == ufs_depth + 1 {
+                        ufs_child_start = Some((key.clone(), pos));
+                    }
                     if depth == 2 && key == "ufs" {
                         info.ufs_section_pos = Some(pos);
                         in_ufs = true;
@@ -620,11 +763,23 @@ impl AppInfoWriter {
                 VDF_TYPE_INT32 => {
                     cursor.read_i32::<LittleEndian>()?;
                 }
-                0x07 => {
+                VDF_TYPE_UINT64 => {
                     cursor.read_u64::<LittleEndian>()?;
                 }
                 _ => {}
             }
+
+            // ufs 直接子键（标量值）
+            if in_ufs
+                && depth == ufs_depth
+                && matches!(
+                    type_byte,
+                    VDF_TYPE_STRING | VDF_TYPE_INT32 | VDF_TYPE_UINT64
+                )
+            {
+                info.ufs_children
+                    .push((key, pos, cursor.position() as usize));
+            }
         }
 
         Ok(())
@@ -642,6 +797,8 @@ impl AppInfoWriter {
         let mut ufs_depth = 0;
         let mut savefiles_depth = 0;
         let mut rootoverrides_depth = 0;
+        // 当前正在读取的 ufs 直接子节 (键名, 起始位置)
+        let mut ufs_child_start: Option<(String, usize)> = None;
 
         while let Ok(type_byte) = cursor.read_u8() {
             let pos = cursor.position() as usize - 1;
@@ -650,6 +807,14 @@ impl AppInfoWriter {
                 // 先减少深度
                 depth -= 1;
 
+                // ufs 直接子节结束
+                if in_ufs
+                    && depth == ufs_depth
+                    && let Some((key, start)) = ufs_child_start.take()
+                {
+                    info.ufs_children.push((key, start, pos + 1));
+                }
+
                 // 检查是否是各节的真正结束
                 if in_savefiles && depth < savefiles_depth {
                     info.savefiles_end_pos = Some(pos);
@@ -674,6 +839,9 @@ impl AppInfoWriter {
             match type_byte {
                 VDF_TYPE_SECTION => {
                     depth += 1;
+                    if in_ufs && depth == ufs_depth + 1 {
+                        ufs_child_start = Some((key.clone(), pos));
+                    }
                     if depth == 2 && key == "ufs" {
                         info.ufs_section_pos = Some(pos);
                         in_ufs = true;
@@ -704,11 +872,23 @@ impl AppInfoWriter {
                 VDF_TYPE_INT32 => {
                     cursor.read_i32::<LittleEndian>()?;
                 }
-                0x07 => {
+                VDF_TYPE_UINT64 => {
                     cursor.read_u64::<LittleEndian>()?;
                 }
                 _ => {}
             }
+
+            // ufs 直接子键（标量值）
+            if in_ufs
+                && depth == ufs_depth
+                && matches!(
+                    type_byte,
+                    VDF_TYPE_STRING | VDF_TYPE_INT32 | VDF_TYPE_UINT64
+                )
+            {
+                info.ufs_children
+                    .push((key, pos, cursor.position() as usize));
+            }
         }
 
         Ok(())
@@ -727,6 +907,39 @@ impl AppInfoWriter {
         String::from_utf8(bytes).unwrap_or_default()
     }
 
+    // 读取一个键（子节递归读取）及其原始类型，遇到节结束或未知类型返回 None
+    fn read_typed_key(
+        &self,
+        cursor: &mut Cursor<&[u8]>,
+        format: &AppInfoFormat,
+        string_table: &[String],
+    ) -> Option<UfsExtraKey> {
+        let type_byte = cursor.read_u8().ok()?;
+        if type_byte == VDF_TYPE_SECTION_END {
+            return None;
+        }
+        let key = if format.string_table {
+            let idx = cursor.read_u32::<LittleEndian>().ok()? as usize;
+            string_table.get(idx).cloned().unwrap_or_default()
+        } else {
+            self.read_null_string(cursor)
+        };
+        let value = match type_byte {
+            VDF_TYPE_SECTION => {
+                let mut children = Vec::new();
+                while let Some(child) = self.read_typed_key(cursor, format, string_table) {
+                    children.push(child);
+                }
+                UfsExtraValue::Section(children)
+            }
+            VDF_TYPE_STRING => UfsExtraValue::Value(self.read_null_string(cursor)),
+            VDF_TYPE_INT32 => UfsExtraValue::Int32(cursor.read_i32::<LittleEndian>().ok()?),
+            VDF_TYPE_UINT64 => UfsExtraValue::UInt64(cursor.read_u64::<LittleEndian>().ok()?),
+            _ => return None,
+        };
+        Some(UfsExtraKey { key, value })
+    }
+
     // 跳过 null 结尾字符串
     fn skip_null_string(&self, cursor: &mut Cursor<&[u8]>) {
         loop {
@@ -738,7 +951,7 @@ impl AppInfoWriter {
     }
 
     // 编码 savefile 条目 (V28 格式 - 直接字符串)
-    fn encode_savefile_v28(&self, savefile: &SaveFileConfig, index: u32) -> Vec<u8> {
+    fn encode_savefile_v28(&self, savefile: &SaveFileEntry, index: u32) -> Vec<u8> {
         let mut result = Vec::new();
 
         // Section 开始: 0x00 + index\0
@@ -827,7 +1040,7 @@ impl AppInfoWriter {
     // 编码单个 savefile 条目为二进制 VDF (V29 格式)
     fn encode_savefile_v29(
         &self,
-        savefile: &SaveFileConfig,
+        savefile: &SaveFileEntry,
         index: u32,
         string_table: &mut Vec<String>,
         string_to_idx: &mut HashMap<String, usize>,
@@ -890,14 +1103,19 @@ impl AppInfoWriter {
         result
     }
 
+    // oscompare 为空时使用默认的 "="
+    fn oscompare_of(override_entry: &RootOverrideEntry) -> &str {
+        if override_entry.oscompare.is_empty() {
+            "="
+        } else {
+            &override_entry.oscompare
+        }
+    }
+
     // 编码 rootoverride 条目 (V28 格式 - 直接字符串)
     // VDF 格式: root, os, oscompare, useinstead, addpath 或 pathtransforms
     // 注意：pathtransforms 和 addpath 互斥，有 pathtransforms 时不输出 addpath
-    fn encode_rootoverride_v28(
-        &self,
-        override_entry: &crate::config::RootOverrideEntry,
-        index: u32,
-    ) -> Vec<u8> {
+    fn encode_rootoverride_v28(&self, override_entry: &RootOverrideEntry, index: u32) -> Vec<u8> {
         let mut result = Vec::new();
 
         // Section 开始: 0x00 + index\0
@@ -920,7 +1138,7 @@ impl AppInfoWriter {
         // oscompare 字段 (比较符，已知值: "="，可能存在其他值如 "!=")
         result.push(VDF_TYPE_STRING);
         result.extend_from_slice(b"oscompare\0");
-        result.extend_from_slice(b"=");
+        result.extend_from_slice(Self::oscompare_of(override_entry).as_bytes());
         result.push(0);
 
         // useinstead 字段 (新的根目录名称)
@@ -978,7 +1196,7 @@ impl AppInfoWriter {
     // 注意：pathtransforms 和 addpath 互斥，有 pathtransforms 时不输出 addpath
     fn encode_rootoverride_v29(
         &self,
-        override_entry: &crate::config::RootOverrideEntry,
+        override_entry: &RootOverrideEntry,
         index: u32,
         string_table: &mut Vec<String>,
         string_to_idx: &mut HashMap<String, usize>,
@@ -1010,7 +1228,7 @@ impl AppInfoWriter {
             self.get_or_create_string_index("oscompare", string_table, string_to_idx);
         result.push(VDF_TYPE_STRING);
         result.extend_from_slice(&(oscompare_idx as u32).to_le_bytes());
-        result.extend_from_slice(b"=");
+        result.extend_from_slice(Self::oscompare_of(override_entry).as_bytes());
         result.push(0);
 
         // useinstead 字段 (新的根目录名称，字符串类型)
@@ -1090,12 +1308,12 @@ impl AppInfoWriter {
         &self,
         vdf_data: &[u8],
         string_table: &[String],
-        version: u32,
+        format: &AppInfoFormat,
     ) -> Result<[u8; 20]> {
         let mut text_output = String::new();
         let mut cursor = Cursor::new(vdf_data);
 
-        self.format_vdf_to_text(&mut cursor, string_table, version, 0, &mut text_output)?;
+        self.format_vdf_to_text(&mut cursor, string_table, format, 0, &mut text_output)?;
 
         // 重要：反斜杠需要双写
         let escaped = text_output.replace('\\', "\\\\");
@@ -1110,7 +1328,7 @@ impl AppInfoWriter {
         &self,
         cursor: &mut Cursor<&[u8]>,
         string_table: &[String],
-        version: u32,
+        format: &AppInfoFormat,
         indent: usize,
         output: &mut String,
     ) -> Result<()> {
@@ -1122,7 +1340,7 @@ impl AppInfoWriter {
             }
 
             // 读取 key
-            let key = if version >= 29 {
+            let key = if format.string_table {
                 let idx = cursor.read_u32::<LittleEndian>()? as usize;
                 string_table.get(idx).cloned().unwrap_or_default()
             } else {
@@ -1133,7 +1351,7 @@ impl AppInfoWriter {
                 VDF_TYPE_SECTION => {
                     output.push_str(&format!("{}\"{}\"\\n", indent_str, key));
                     output.push_str(&format!("{}{{\\n", indent_str));
-                    self.format_vdf_to_text(cursor, string_table, version, indent + 1, output)?;
+                    self.format_vdf_to_text(cursor, string_table, format, indent + 1, output)?;
                     output.push_str(&format!("{}}}\\n", indent_str));
                 }
                 VDF_TYPE_STRING => {
@@ -1194,10 +1412,59 @@ impl AppInfoWriter {
     }
 }
 
+// 按原始键的类型还原未识别键：文本中的数值按 INT32 / UINT64 写回
+fn restore_extra_types(extra: &mut [UfsExtraKey], original: &[UfsExtraKey]) {
+    for item in extra.iter_mut() {
+        let Some(orig) = original.iter().find(|o| o.key == item.key) else {
+            continue;
+        };
+        let restored = match (&item.value, &orig.value) {
+            (UfsExtraValue::Value(text), UfsExtraValue::Int32(_)) => {
+                text.trim().parse().ok().map(UfsExtraValue::Int32)
+            }
+            (UfsExtraValue::Value(text), UfsExtraValue::UInt64(_)) => {
+                text.trim().parse().ok().map(UfsExtraValue::UInt64)
+            }
+            _ => None,
+        };
+        if let Some(value) = restored {
+            item.value = value;
+        } else if let (UfsExtraValue::Section(children), UfsExtraValue::Section(orig_children)) =
+            (&mut item.value, &orig.value)
+        {
+            restore_extra_types(children, orig_children);
+        }
+    }
+}
+
+// 顶层键以及 savefiles / rootoverrides 各条目（按序号对应）的未识别键
+fn restore_config_types(config: &mut UfsGameConfig, original: &[UfsExtraKey]) {
+    fn children<'a>(keys: &'a [UfsExtraKey], key: &str) -> &'a [UfsExtraKey] {
+        match keys.iter().find(|k| k.key == key).map(|k| &k.value) {
+            Some(UfsExtraValue::Section(children)) => children,
+            _ => &[],
+        }
+    }
+
+    restore_extra_types(&mut config.settings.extra, original);
+    let savefiles = children(original, "savefiles");
+    for (i, savefile) in config.savefiles.iter_mut().enumerate() {
+        restore_extra_types(&mut savefile.extra, children(savefiles, &i.to_string()));
+    }
+    let overrides = children(original, "rootoverrides");
+    for (i, entry) in config.root_overrides.iter_mut().enumerate() {
+        restore_extra_types(&mut entry.extra, children(overrides, &i.to_string()));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn v28() -> &'static AppInfoFormat {
+        AppInfoFormat::from_magic(0x07564428).unwrap()
+    }
+
     #[test]
     fn test_binary_checksum() {
         let writer = AppInfoWriter {
@@ -1207,4 +1474,205 @@ mod tests {
         let checksum = writer.calculate_binary_checksum(data);
         assert_eq!(checksum.len(), 20);
     }
+
+    #[test]
+    fn inject_replaces_settings_and_drops_removed_keys() {
+        let writer = AppInfoWriter {
+            steam_path: PathBuf::new(),
+        };
+
+        // V28: "appinfo" { "ufs" { "quota" 100, "hidecloudui" "1", "future" "keep", "old" "x" } }
+        let mut vdf = Vec::new();
+        vdf.extend_from_slice(b"\x00appinfo\0\x00ufs\0");
+        vdf.extend_from_slice(b"\x02quota\0");
+        vdf.extend_from_slice(&100i32.to_le_bytes());
+        vdf.extend_from_slice(b"\x01hidecloudui\0");
+        vdf.extend_from_slice(b"1\0");
+        vdf.extend_from_slice(b"\x01future\0keep\0");
+        vdf.extend_from_slice(b"\x01old\0x\0");
+        vdf.extend_from_slice(&[VDF_TYPE_SECTION_END, VDF_TYPE_SECTION_END]);
+
+        // 编辑器中修改了 quota，删除了 hidecloudui 和 old
+        let config = UfsGameConfig {
+            app_id: 1,
+            settings: UfsSettings {
+                quota: Some(2048),
+                maxnumfiles: Some(50),
+                extra: vec![UfsExtraKey {
+                    key: "future".to_string(),
+                    value: UfsExtraValue::Value("keep".to_string()),
+                }],
+                removed: vec!["hidecloudui".to_string(), "old".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let result = writer
+            .inject_full_ufs_to_vdf(&vdf, &config, v28(), &mut Vec::new(), &mut HashMap::new())
+            .unwrap();
+
+        let count = |needle: &[u8]| {
+            result
+                .windows(needle.len())
+                .filter(|w| *w == needle)
+                .count()
+        };
+        assert_eq!(count(b"quota\0"), 1);
+        assert_eq!(count(&2048i32.to_le_bytes()), 1);
+        assert_eq!(count(b"maxnumfiles\0"), 1);
+        assert_eq!(count(b"future\0keep\0"), 1);
+        assert_eq!(count(b"hidecloudui\0"), 0);
+        assert_eq!(count(b"old\0"), 0);
+        assert!(result.ends_with(&[VDF_TYPE_SECTION_END, VDF_TYPE_SECTION_END]));
+
+        // 全部删除后 ufs 节只剩空壳
+        let removed_all = UfsGameConfig {
+            app_id: 1,
+            settings: UfsSettings {
+                removed: ["quota", "hidecloudui", "future", "old"]
+                    .map(String::from)
+                    .to_vec(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let result = writer
+            .inject_full_ufs_to_vdf(
+                &vdf,
+                &removed_all,
+                v28(),
+                &mut Vec::new(),
+                &mut HashMap::new(),
+            )
+            .unwrap();
+        assert_eq!(result, b"\x00appinfo\0\x00ufs\0\x08\x08");
+    }
+
+    #[test]
+    fn savefiles_only_injection_keeps_original_settings() {
+        let writer = AppInfoWriter {
+            steam_path: PathBuf::new(),
+        };
+
+        // V28: "appinfo" { "ufs" { "quota" 100, "maxnumfiles" 10 } }
+        let mut vdf = Vec::new();
+        vdf.extend_from_slice(b"\x00appinfo\0\x00ufs\0");
+        vdf.extend_from_slice(b"\x02quota\0");
+        vdf.extend_from_slice(&100i32.to_le_bytes());
+        vdf.extend_from_slice(b"\x02maxnumfiles\0");
+        vdf.extend_from_slice(&10i32.to_le_bytes());
+        vdf.extend_from_slice(&[VDF_TYPE_SECTION_END, VDF_TYPE_SECTION_END]);
+
+        // 没有顶层设置的配置（如旧版本保存的配置）
+        let config = UfsGameConfig {
+            app_id: 1,
+            savefiles: vec![SaveFileEntry {
+                root: "WinAppDataLocal".to_string(),
+                path: "Game".to_string(),
+                pattern: "*.sav".to_string(),
+                platforms: vec!["windows".to_string()],
+                recursive: true,
+                extra: Vec::new(),
+            }],
+            ..Default::default()
+        };
+
+        let result = writer
+            .inject_full_ufs_to_vdf(&vdf, &config, v28(), &mut Vec::new(), &mut HashMap::new())
+            .unwrap();
+
+        let mut quota = b"\x02quota\0".to_vec();
+        quota.extend_from_slice(&100i32.to_le_bytes());
+        let mut maxnumfiles = b"\x02maxnumfiles\0".to_vec();
+        maxnumfiles.extend_from_slice(&10i32.to_le_bytes());
+        let contains = |needle: &[u8]| result.windows(needle.len()).any(|w| w == needle);
+        assert!(contains(&quota));
+        assert!(contains(&maxnumfiles));
+        assert!(contains(b"savefiles\0"));
+        assert!(contains(b"*.sav\0"));
+    }
+
+    #[test]
+    fn unknown_int_keys_keep_their_vdf_type() {
+        let writer = AppInfoWriter {
+            steam_path: PathBuf::new(),
+        };
+
+        // V28: "appinfo" { "ufs" { "syncdelay" INT32 30, "budget" UINT64 1<<40, "label" "x" } }
+        let mut vdf = Vec::new();
+        vdf.extend_from_slice(b"\x00appinfo\0\x00ufs\0");
+        vdf.extend_from_slice(b"\x02syncdelay\0");
+        vdf.extend_from_slice(&30i32.to_le_bytes());
+        vdf.extend_from_slice(b"\x07budget\0");
+        vdf.extend_from_slice(&(1u64 << 40).to_le_bytes());
+        vdf.extend_from_slice(b"\x01label\0x\0");
+        vdf.extend_from_slice(&[VDF_TYPE_SECTION_END, VDF_TYPE_SECTION_END]);
+
+        // 经过文本编辑后未识别的键都是字符串
+        let text = crate::ufs_text::entries_to_ufs_text(
+            &UfsSettings {
+                extra: vec![
+                    UfsExtraKey {
+                        key: "syncdelay".to_string(),
+                        value: UfsExtraValue::Int32(45),
+                    },
+                    UfsExtraKey {
+                        key: "budget".to_string(),
+                        value: UfsExtraValue::UInt64(1 << 40),
+                    },
+                    UfsExtraKey {
+                        key: "label".to_string(),
+                        value: UfsExtraValue::Value("y".to_string()),
+                    },
+                ],
+                ..Default::default()
+            },
+            &[],
+            &[],
+        );
+        let (settings, savefiles, root_overrides) = crate::ufs_text::parse_ufs_text(&text);
+        assert_eq!(
+            settings.extra[0].value,
+            UfsExtraValue::Value("45".to_string())
+        );
+        let config = UfsGameConfig {
+            app_id: 1,
+            savefiles,
+            root_overrides,
+            settings,
+        };
+
+        let result = writer
+            .inject_full_ufs_to_vdf(&vdf, &config, v28(), &mut Vec::new(), &mut HashMap::new())
+            .unwrap();
+
+        let mut expected_int = b"\x02syncdelay\0".to_vec();
+        expected_int.extend_from_slice(&45i32.to_le_bytes());
+        let mut expected_u64 = b"\x07budget\0".to_vec();
+        expected_u64.extend_from_slice(&(1u64 << 40).to_le_bytes());
+        let contains = |needle: &[u8]| result.windows(needle.len()).any(|w| w == needle);
+        assert!(contains(&expected_int));
+        assert!(contains(&expected_u64));
+        assert!(contains(b"\x01label\0y\0"));
+        assert!(!contains(b"\x01syncdelay\0"));
+
+        // 读回后类型一致
+        let mut cursor = Cursor::new(&result[..]);
+        let root = writer.read_typed_key(&mut cursor, v28(), &[]).unwrap();
+        let UfsExtraValue::Section(root_children) = root.value else {
+            panic!("根节点应为子节");
+        };
+        let UfsExtraValue::Section(ufs) = &root_children[0].value else {
+            panic!("ufs 应为子节");
+        };
+        assert!(ufs.contains(&UfsExtraKey {
+            key: "syncdelay".to_string(),
+            value: UfsExtraValue::Int32(45),
+        }));
+        assert!(ufs.contains(&UfsExtraKey {
+            key: "budget".to_string(),
+            value: UfsExtraValue::UInt64(1 << 40),
+        }));
+    }
 }
@@ -1,12 +1,21 @@
 use crate::game_scanner::CloudGameInfo;
+use crate::remotestorage_html::{
+    self, FILES_PER_PAGE, FileRow, GAME_LIST_URL, GameRow, file_page_url,
+};
 use crate::steam_api::CloudFile;
 use anyhow::{Result, anyhow};
-use chrono::{Datelike, Local};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::VecDeque;
 use std::net::TcpStream;
+use std::time::{Duration, Instant};
 use tungstenite::{WebSocket, stream::MaybeTlsStream};
 
+// 单次 socket 读取的超时，用于在等待响应时检查整体截止时间
+const READ_POLL_INTERVAL: Duration = Duration::from_millis(200);
+// 事件队列上限，超出后丢弃最旧的事件
+const MAX_QUEUED_EVENTS: usize = 1000;
+
 #[derive(Debug, Deserialize)]
 struct Target {
     #[serde(rename = "webSocketDebuggerUrl")]
@@ -16,9 +25,97 @@ struct Target {
     url: Option<String>,
 }
 
+// CDP 调试端口地址
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CdpEndpoint {
+    pub host: String,
+    pub port: u16,
+}
+
+impl CdpEndpoint {
+    pub fn new(host: &str, port: u16) -> Self {
+        Self {
+            host: host.to_string(),
+            port,
+        }
+    }
+
+    // 从配置文件读取
+    pub fn from_config() -> Self {
+        let config = crate::config::get_config().cdp;
+        Self::new(&config.host, config.port)
+    }
+
+    fn json_url(&self) -> String {
+        format!("http://{}:{}/json", self.host, self.port)
+    }
+}
+
+impl std::fmt::Display for CdpEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.host, self.port)
+    }
+}
+
+// CDP 事件（如 Page.loadEventFired、Network.responseReceived）
+#[derive(Debug, Clone)]
+pub struct CdpEvent {
+    pub method: String,
+    pub params: Value,
+}
+
+// 事件匹配：精确匹配方法名，或 "Network.*" 匹配整个域
+fn event_matches(pattern: &str, method: &str) -> bool {
+    match pattern.strip_suffix(".*") {
+        Some(domain) => method
+            .strip_prefix(domain)
+            .is_some_and(|rest| rest.starts_with('.')),
+        None => pattern == method,
+    }
+}
+
+// 从 Network.responseReceived 事件中取主文档的 HTTP 状态码
+fn document_status(events: &[CdpEvent]) -> Option<u64> {
+    events
+        .iter()
+        .rev()
+        .find(|e| e.params.get("type").and_then(|t| t.as_str()) == Some("Document"))
+        .and_then(|e| e.params.get("response")?.get("status")?.as_u64())
+}
+
+// 连接被关闭或重置（CEF 重建页面目标时会出现）
+fn is_disconnect(e: &anyhow::Error) -> bool {
+    use std::io::ErrorKind;
+    match e.downcast_ref::<tungstenite::Error>() {
+        Some(tungstenite::Error::ConnectionClosed) | Some(tungstenite::Error::AlreadyClosed) => {
+            true
+        }
+        Some(tungstenite::Error::Protocol(
+            tungstenite::error::ProtocolError::ResetWithoutClosingHandshake,
+        )) => true,
+        Some(tungstenite::Error::Io(io)) => matches!(
+            io.kind(),
+            ErrorKind::ConnectionReset
+                | ErrorKind::ConnectionAborted
+                | ErrorKind::BrokenPipe
+                | ErrorKind::UnexpectedEof
+        ),
+        _ => false,
+    }
+}
+
 pub struct CdpClient {
     socket: WebSocket<MaybeTlsStream<TcpStream>>,
     message_id: u64,
+    endpoint: CdpEndpoint,
+    target_type: CdpTarget,
+    command_timeout: Duration,
+    page_load_timeout: Duration,
+    max_reconnects: u32,
+    // 已订阅的事件模式及已启用的域（重连后需要重新启用）
+    subscriptions: Vec<String>,
+    enabled_domains: Vec<String>,
+    events: VecDeque<CdpEvent>,
 }
 
 #[derive(Serialize)]
@@ -28,9 +125,12 @@ struct CdpCommand {
     params: Value,
 }
 
+// CDP 消息：带 id 的是命令响应，带 method 的是事件
 #[derive(Deserialize)]
-struct CdpResponse {
-    id: u64,
+struct CdpMessage {
+    id: Option<u64>,
+    method: Option<String>,
+    params: Option<Value>,
     result: Option<Value>,
     error: Option<Value>,
 }
@@ -48,97 +148,98 @@ impl CdpClient {
     // 检查 CDP 服务是否可用
     pub fn is_cdp_running() -> bool {
         static LOG_ONCE: std::sync::Once = std::sync::Once::new();
-        let result = ureq::get("http://127.0.0.1:8080/json").call().is_ok();
+        let endpoint = CdpEndpoint::from_config();
+        let result = Self::is_running_at(&endpoint);
         LOG_ONCE.call_once(|| {
             if result {
-                tracing::debug!("Steam CEF 调试端口可用 (127.0.0.1:8080)");
+                tracing::debug!("Steam CEF 调试端口可用 ({})", endpoint);
             } else {
-                tracing::debug!("Steam CEF 调试端口不可用");
+                tracing::debug!("Steam CEF 调试端口不可用 ({})", endpoint);
             }
         });
         result
     }
 
-    // 连接到指定类型的页面
-    pub fn connect_for(target_type: CdpTarget) -> Result<Self> {
-        let resp = ureq::get("http://127.0.0.1:8080/json")
-            .call()
-            .map_err(|e| anyhow!("无法连接到 Steam 调试端口: {}", e))?;
-
-        let text = resp.into_body().read_to_string()?;
-        let targets: Vec<Target> = serde_json::from_str(&text)?;
-
-        // 根据目标类型选择正确的页面
-        let target_desc = match target_type {
-            CdpTarget::GameList => "游戏列表页",
-            CdpTarget::FileList => "文件详情页",
-        };
-
-        tracing::debug!(
-            "CDP 发现 {} 个调试目标，正在查找{}...",
-            targets.len(),
-            target_desc
-        );
-
-        // 精确匹配目标页面
-        let target = targets
-            .iter()
-            .find(|t| {
-                t.websocket_debugger_url.is_some()
-                    && t.target_type == "page"
-                    && t.url.as_ref().is_some_and(|url| {
-                        match target_type {
-                            CdpTarget::FileList => url.contains("remotestorageapp"),
-                            CdpTarget::GameList => {
-                                // 匹配 remotestorage 但排除 remotestorageapp
-                                url.contains("remotestorage") && !url.contains("remotestorageapp")
-                            }
-                        }
-                    })
-            })
-            .or_else(|| {
-                // 回退：选择任意 store.steampowered.com 页面
-                targets.iter().find(|t| {
-                    t.websocket_debugger_url.is_some()
-                        && t.target_type == "page"
-                        && t.url
-                            .as_ref()
-                            .is_some_and(|url| url.contains("store.steampowered.com"))
-                })
-            })
-            .or_else(|| {
-                // 最后回退：任意非 about:blank 的 page
-                targets.iter().find(|t| {
-                    t.websocket_debugger_url.is_some()
-                        && t.target_type == "page"
-                        && t.url.as_ref().is_some_and(|url| !url.starts_with("about:"))
-                })
-            })
-            .ok_or_else(|| {
-                anyhow!(
-                    "未找到{}的调试目标，请先在 Steam 客户端打开云存储页面",
-                    target_desc
-                )
-            })?;
-
-        tracing::debug!(
-            "CDP 连接到目标 (期望: {}): type={}, url={:?}",
-            target_desc,
-            target.target_type,
-            target.url
-        );
+    pub fn is_running_at(endpoint: &CdpEndpoint) -> bool {
+        http_agent().get(&endpoint.json_url()).call().is_ok()
+    }
 
-        let ws_url = target.websocket_debugger_url.as_ref().unwrap();
-        let (socket, _) = tungstenite::connect(ws_url)?;
+    // 连接到指定类型的页面（地址和超时取自配置）
+    pub fn connect_for(target_type: CdpTarget) -> Result<Self> {
+        let config = crate::config::get_config().cdp;
+        Ok(
+            Self::connect_with(CdpEndpoint::new(&config.host, config.port), target_type)?
+                .with_command_timeout(Duration::from_secs(config.command_timeout_secs))
+                .with_page_load_timeout(Duration::from_secs(config.page_load_timeout_secs))
+                .with_max_reconnects(config.max_reconnects),
+        )
+    }
 
+    // 连接到指定地址的调试端口
+    pub fn connect_with(endpoint: CdpEndpoint, target_type: CdpTarget) -> Result<Self> {
+        let socket = open_socket(&endpoint, target_type)?;
         Ok(Self {
             socket,
             message_id: 0,
+            endpoint,
+            target_type,
+            command_timeout: Duration::from_secs(15),
+            page_load_timeout: Duration::from_secs(15),
+            max_reconnects: 2,
+            subscriptions: Vec::new(),
+            enabled_domains: Vec::new(),
+            events: VecDeque::new(),
         })
     }
 
-    // 发送命令并等待响应
+    pub fn with_command_timeout(mut self, timeout: Duration) -> Self {
+        self.command_timeout = timeout.max(READ_POLL_INTERVAL);
+        self
+    }
+
+    pub fn with_page_load_timeout(mut self, timeout: Duration) -> Self {
+        self.page_load_timeout = timeout;
+        self
+    }
+
+    pub fn with_max_reconnects(mut self, max_reconnects: u32) -> Self {
+        self.max_reconnects = max_reconnects;
+        self
+    }
+
+    // 重新查找目标页面并连接，恢复已启用的域
+    fn reconnect(&mut self) -> Result<()> {
+        self.socket = open_socket(&self.endpoint, self.target_type)?;
+        self.events.clear();
+        for domain in self.enabled_domains.clone() {
+            self.try_command(&format!("{}.enable", domain), serde_json::json!({}))?;
+        }
+        tracing::info!("CDP 已重新连接 ({})", self.endpoint);
+        Ok(())
+    }
+
+    // 发送命令并等待响应，连接断开时自动重连并重试
     fn send_command(&mut self, method: &str, params: Value) -> Result<Value> {
+        let mut attempts = 0;
+        loop {
+            match self.try_command(method, params.clone()) {
+                Err(e) if is_disconnect(&e) && attempts < self.max_reconnects => {
+                    attempts += 1;
+                    tracing::warn!(
+                        "CDP 连接断开 ({}), 正在重连 ({}/{})",
+                        e,
+                        attempts,
+                        self.max_reconnects
+                    );
+                    self.reconnect()?;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    // 发送一次命令，等待期间收到的事件放入队列
+    fn try_command(&mut self, method: &str, params: Value) -> Result<Value> {
         self.message_id += 1;
         let id = self.message_id;
 
@@ -151,71 +252,206 @@ impl CdpClient {
         let msg = serde_json::to_string(&command)?;
         self.socket.send(tungstenite::Message::Text(msg.into()))?;
 
+        let deadline = Instant::now() + self.command_timeout;
         loop {
-            let msg = self.socket.read()?;
-            if let tungstenite::Message::Text(text) = msg
-                && let Ok(resp) = serde_json::from_str::<CdpResponse>(&text)
-                && resp.id == id
-            {
-                if let Some(error) = resp.error {
+            if Instant::now() >= deadline {
+                return Err(anyhow!(
+                    "CDP 命令超时: {} ({:.0}s)",
+                    method,
+                    self.command_timeout.as_secs_f64()
+                ));
+            }
+
+            let msg = match self.read_message()? {
+                Some(msg) => msg,
+                None => continue,
+            };
+            if msg.id == Some(id) {
+                if let Some(error) = msg.error {
                     return Err(anyhow!("CDP 错误: {:?}", error));
                 }
-                return Ok(resp.result.unwrap_or(Value::Null));
+                return Ok(msg.result.unwrap_or(Value::Null));
             }
+            self.queue_event(msg);
         }
     }
 
-    // 导航到指定 URL
-    pub fn navigate(&mut self, url: &str) -> Result<()> {
-        self.send_command("Page.navigate", serde_json::json!({ "url": url }))?;
+    // 读取一条消息，读超时返回 None
+    fn read_message(&mut self) -> Result<Option<CdpMessage>> {
+        match self.socket.read() {
+            Ok(tungstenite::Message::Text(text)) => Ok(serde_json::from_str(&text).ok()),
+            Ok(tungstenite::Message::Close(_)) => Err(tungstenite::Error::ConnectionClosed.into()),
+            Ok(_) => Ok(None),
+            Err(tungstenite::Error::Io(e))
+                if matches!(
+                    e.kind(),
+                    std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                ) =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
 
-        let start = std::time::Instant::now();
-        let timeout = std::time::Duration::from_secs(15);
+    // 只保留已订阅的事件
+    fn queue_event(&mut self, msg: CdpMessage) {
+        let method = match msg.method {
+            Some(method) => method,
+            None => return,
+        };
+        if !self.subscriptions.iter().any(|p| event_matches(p, &method)) {
+            return;
+        }
+        if self.events.len() >= MAX_QUEUED_EVENTS {
+            self.events.pop_front();
+        }
+        self.events.push_back(CdpEvent {
+            method,
+            params: msg.params.unwrap_or(Value::Null),
+        });
+    }
+
+    // 订阅事件，支持 "Page.loadEventFired" 或 "Network.*"，会自动启用对应的域
+    pub fn subscribe(&mut self, pattern: &str) -> Result<()> {
+        let domain = pattern.split('.').next().unwrap_or(pattern).to_string();
+        if !self.enabled_domains.contains(&domain) {
+            self.send_command(&format!("{}.enable", domain), serde_json::json!({}))?;
+            self.enabled_domains.push(domain);
+        }
+        if !self.subscriptions.iter().any(|p| p == pattern) {
+            self.subscriptions.push(pattern.to_string());
+        }
+        Ok(())
+    }
 
-        // 等待导航开始
-        std::thread::sleep(std::time::Duration::from_millis(500));
+    // 取消订阅，并丢弃不再匹配任何订阅的已排队事件
+    pub fn unsubscribe(&mut self, pattern: &str) {
+        self.subscriptions.retain(|p| p != pattern);
+        let subscriptions = &self.subscriptions;
+        self.events
+            .retain(|e| subscriptions.iter().any(|p| event_matches(p, &e.method)));
+    }
 
-        // 轮询 document.readyState 直到 complete 或超时
+    // 取出队列中所有匹配的事件
+    pub fn take_events(&mut self, pattern: &str) -> Vec<CdpEvent> {
+        let (matched, rest): (VecDeque<_>, VecDeque<_>) = self
+            .events
+            .drain(..)
+            .partition(|e| event_matches(pattern, &e.method));
+        self.events = rest;
+        matched.into_iter().collect()
+    }
+
+    // 等待下一个匹配的事件（需先订阅）
+    pub fn wait_for_event(&mut self, pattern: &str, timeout: Duration) -> Result<CdpEvent> {
+        let deadline = Instant::now() + timeout;
+        let mut attempts = 0;
         loop {
-            if start.elapsed() > timeout {
-                tracing::warn!("CDP 页面加载超时 ({}s)", timeout.as_secs());
-                break;
+            if let Some(pos) = self
+                .events
+                .iter()
+                .position(|e| event_matches(pattern, &e.method))
+                && let Some(event) = self.events.remove(pos)
+            {
+                return Ok(event);
+            }
+            if Instant::now() >= deadline {
+                return Err(anyhow!(
+                    "等待 CDP 事件超时: {} ({:.0}s)",
+                    pattern,
+                    timeout.as_secs_f64()
+                ));
             }
 
-            match self.evaluate("document.readyState") {
-                Ok(value) if value.as_str() == Some("complete") => {
-                    tracing::debug!("CDP 页面加载完成 ({:.1}s)", start.elapsed().as_secs_f64());
-                    break;
-                }
-                Ok(_) => {
-                    std::thread::sleep(std::time::Duration::from_millis(300));
-                }
-                Err(_) => {
-                    // 导航中 JS 上下文可能暂时不可用
-                    std::thread::sleep(std::time::Duration::from_millis(500));
+            match self.read_message() {
+                Ok(Some(msg)) => self.queue_event(msg),
+                Ok(None) => {}
+                Err(e) if is_disconnect(&e) && attempts < self.max_reconnects => {
+                    attempts += 1;
+                    tracing::warn!("CDP 等待事件时连接断开 ({}), 正在重连", e);
+                    self.reconnect()?;
                 }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    // 导航到指定 URL，等待 Page.loadEventFired
+    pub fn navigate(&mut self, url: &str) -> Result<()> {
+        self.subscribe("Page.loadEventFired")?;
+        self.subscribe("Network.responseReceived")?;
+        self.take_events("Page.loadEventFired");
+        self.take_events("Network.responseReceived");
+
+        let start = Instant::now();
+        let result = self.send_command("Page.navigate", serde_json::json!({ "url": url }))?;
+        if let Some(error) = result.get("errorText").and_then(|v| v.as_str()) {
+            return Err(anyhow!("CDP 导航失败: {}", error));
+        }
+
+        match self.wait_for_event("Page.loadEventFired", self.page_load_timeout) {
+            Ok(_) => {
+                tracing::debug!("CDP 页面加载完成 ({:.1}s)", start.elapsed().as_secs_f64());
             }
+            Err(e) => {
+                // 重连后或事件丢失时退回到检查 document.readyState
+                tracing::debug!("CDP 未收到页面加载事件 ({}), 检查 readyState", e);
+                self.wait_ready_state(Duration::from_secs(5));
+            }
+        }
+
+        // 只在导航期间收集网络事件，检查主文档的 HTTP 状态
+        let responses = self.take_events("Network.responseReceived");
+        self.unsubscribe("Network.responseReceived");
+        if let Some(status) = document_status(&responses)
+            && status >= 400
+        {
+            return Err(anyhow!("CDP 页面返回 HTTP {}: {}", status, url));
         }
 
         Ok(())
     }
 
-    // 等待指定 CSS 选择器的元素出现
-    fn wait_for_element(&mut self, selector: &str, timeout_secs: u64) -> bool {
-        let script = format!("document.querySelector('{}') !== null", selector);
-        let start = std::time::Instant::now();
-        let timeout = std::time::Duration::from_secs(timeout_secs);
+    // 轮询 document.readyState 直到 complete 或超时
+    fn wait_ready_state(&mut self, timeout: Duration) {
+        let start = Instant::now();
+        while start.elapsed() < timeout {
+            match self.evaluate("document.readyState") {
+                Ok(value) if value.as_str() == Some("complete") => return,
+                // 导航中 JS 上下文可能暂时不可用
+                _ => std::thread::sleep(Duration::from_millis(300)),
+            }
+        }
+        tracing::warn!("CDP 页面加载超时 ({}s)", timeout.as_secs());
+    }
+
+    // 当前页面的完整 HTML，表格解析统一交给 remotestorage_html
+    fn page_html(&mut self) -> Result<String> {
+        let value = self.evaluate("document.documentElement.outerHTML")?;
+        value
+            .as_str()
+            .map(String::from)
+            .ok_or_else(|| anyhow!("CDP 未返回页面 HTML"))
+    }
+
+    // 等待数据表格出现，返回最后读取到的页面 HTML
+    fn wait_for_account_table(&mut self, timeout_secs: u64) -> String {
+        let start = Instant::now();
+        let timeout = Duration::from_secs(timeout_secs);
+        let mut html = String::new();
 
         while start.elapsed() < timeout {
-            if let Ok(value) = self.evaluate(&script)
-                && value.as_bool() == Some(true)
-            {
-                return true;
+            if let Ok(current) = self.page_html() {
+                html = current;
+                if remotestorage_html::has_account_table(&html) {
+                    break;
+                }
             }
-            std::thread::sleep(std::time::Duration::from_millis(500));
+            std::thread::sleep(Duration::from_millis(500));
         }
 
-        false
+        html
     }
 
     // 执行 JavaScript 并获取结果
@@ -250,99 +486,21 @@ impl CdpClient {
             .and_then(|v| v.as_str().map(String::from))
             .is_some_and(|url| url.contains("remotestorage") && !url.contains("remotestorageapp"));
 
-        if is_game_list_page {
-            // 已在正确页面，等待数据表格加载
-            self.wait_for_element(".accountTable", 10);
-        } else {
+        if !is_game_list_page {
             // 当前页面不是游戏列表页，导航到正确页面
             tracing::debug!("CDP 当前页面不是游戏列表页，导航到正确页面");
-            self.navigate("https://store.steampowered.com/account/remotestorage")?;
-            self.wait_for_element(".accountTable", 10);
+            self.navigate(GAME_LIST_URL)?;
         }
+        let html = self.wait_for_account_table(10);
 
-        let script = r#"
-            (function() {
-                // 只选择 accountTable 下的行
-                const rows = Array.from(document.querySelectorAll('.accountTable tr'));
-                // 过滤掉表头（通常第一行）和无效行
-                return rows.map(tr => {
-                    const tds = tr.querySelectorAll('td');
-                    // 游戏列表页有4列：游戏名, 文件数, 大小, 链接
-                    if(tds.length < 3) return null;
-                    
-                    // 在行内查找包含 appid 的链接
-                    const link = tr.querySelector('a[href*="appid="]');
-                    const appIdMatch = link ? link.href.match(/appid=(\d+)/) : null;
-                    if(!appIdMatch) return null;
-                    
-                    return {
-                        app_id: parseInt(appIdMatch[1]),
-                        game_name: tds[0].textContent.trim(),
-                        file_count: parseInt(tds[1].textContent.trim()) || 0,
-                        total_size_str: tds[2].textContent.trim()
-                    };
-                }).filter(x => x);
-            })()
-        "#;
-
-        let value = self.evaluate(script)?;
-        if let Some(arr) = value.as_array() {
-            let summary: Vec<String> = arr
-                .iter()
-                .map(|v| {
-                    format!(
-                        "{}: {}",
-                        v["app_id"].as_u64().unwrap_or(0),
-                        v["game_name"].as_str().unwrap_or("unknown")
-                    )
-                })
-                .collect();
-            tracing::debug!("CDP 获取到 {} 个游戏: {:?}", arr.len(), summary);
-        }
-
-        let mut games = Vec::new();
-
-        if let Some(arr) = value.as_array() {
-            for item in arr {
-                let app_id = item["app_id"].as_u64().unwrap_or(0) as u32;
-                if app_id == 0 {
-                    continue;
-                }
-
-                let game_name = item["game_name"]
-                    .as_str()
-                    .map(|s| s.to_string())
-                    .filter(|s| !s.is_empty());
-
-                let file_count = item["file_count"].as_u64().unwrap_or(0) as usize;
-
-                let size_str = item["total_size_str"].as_str().unwrap_or("");
-                let total_size = crate::file_manager::parse_size(size_str);
-                if total_size == 0 && !size_str.trim().is_empty() {
-                    tracing::warn!(
-                        "App {} ({:?}) 大小解析为0: Raw='{}'",
-                        app_id,
-                        game_name,
-                        size_str
-                    );
-                }
-
-                games.push(CloudGameInfo {
-                    app_id,
-                    file_count,
-                    total_size,
-                    last_played: None,
-                    playtime: None,
-                    game_name,
-                    is_installed: false,
-                    install_dir: None,
-                    categories: Vec::new(),
-                });
-            }
-        } else {
-            tracing::warn!("CDP 返回的数据不是数组: {:?}", value);
-        }
+        let rows = remotestorage_html::parse_game_rows(&html);
+        let summary: Vec<String> = rows
+            .iter()
+            .map(|r| format!("{}: {}", r.app_id, r.game_name))
+            .collect();
+        tracing::debug!("CDP 获取到 {} 个游戏: {:?}", rows.len(), summary);
 
+        let games: Vec<CloudGameInfo> = rows.iter().filter_map(GameRow::to_game_info).collect();
         tracing::info!("CDP 解析完成，获取到 {} 个游戏", games.len());
         Ok(games)
     }
@@ -352,113 +510,33 @@ impl CdpClient {
         let mut page = 1;
 
         loop {
-            let index = (page - 1) * 50;
-            let url = if page > 1 {
-                format!(
-                    "https://store.steampowered.com/account/remotestorageapp/?appid={}&index={}",
-                    app_id, index
-                )
-            } else {
-                format!(
-                    "https://store.steampowered.com/account/remotestorageapp/?appid={}",
-                    app_id
-                )
-            };
+            let index = (page - 1) * FILES_PER_PAGE;
+            let url = file_page_url(app_id, index);
 
             self.navigate(&url)?;
 
             // 等待数据表格出现
-            self.wait_for_element(".accountTable", 8);
-
-            let script = r#"
-                (function() {
-                    const rows = Array.from(document.querySelectorAll('.accountTable tr'));
-                    return rows.map(tr => {
-                        const tds = tr.querySelectorAll('td');
-                        // 文件列表页有 5 列: 文件夹, 文件名, 大小, 时间, 下载链接
-                        if(tds.length < 4) return null;
-                        
-                        // 查找下载链接：可能是 "ugc" 也可能是 "filedownload"
-                        const link = tr.querySelector('a[href*="ugc"], a[href*="filedownload"], a[href*="steamusercontent"]');
-                        const download_url = link ? link.href : "";
-                        
-                        // 文件夹, 文件名, 大小, 时间
-                        return {
-                            folder: tds[0].textContent.trim(),
-                            name: tds[1].textContent.trim(),
-                            size_str: tds[2].textContent.trim(),
-                            time_str: tds[3].textContent.trim(),
-                            url: download_url
-                        };
-                    }).filter(x => x && x.name);
-                })()
-            "#;
-
-            let mut value = self.evaluate(script)?;
+            let mut html = self.wait_for_account_table(8);
+            let mut rows = remotestorage_html::parse_file_rows(&html);
 
             // 首页结果为空时重试
-            if page == 1 && value.as_array().is_some_and(|a| a.is_empty()) {
+            if page == 1 && rows.is_empty() {
                 tracing::debug!("CDP 首次查询返回空结果，等待后重试");
                 if let Ok(page_info) =
                     self.evaluate("document.title + ' | ' + window.location.href")
                 {
                     tracing::debug!("CDP 当前页面: {:?}", page_info);
                 }
-                std::thread::sleep(std::time::Duration::from_secs(3));
-                if let Ok(retry_value) = self.evaluate(script) {
-                    value = retry_value;
+                std::thread::sleep(Duration::from_secs(3));
+                if let Ok(retry_html) = self.page_html() {
+                    html = retry_html;
+                    rows = remotestorage_html::parse_file_rows(&html);
                 }
             }
+            all_files.extend(rows.iter().map(FileRow::to_cloud_file));
 
-            if let Some(arr) = value.as_array() {
-                if arr.is_empty() {
-                    // 如果本页没数据，停止翻页
-                }
-
-                for item in arr {
-                    let folder = item["folder"].as_str().unwrap_or("").to_string();
-                    let filename = item["name"].as_str().unwrap_or("unknown").to_string();
-                    let size_str = item["size_str"].as_str().unwrap_or("");
-                    let time_str = item["time_str"].as_str().unwrap_or("");
-
-                    // 存储 URL 到 root_description，格式: CDP:<URL>|<原始FOLDER>
-                    let url = item["url"].as_str().unwrap_or("");
-                    let root_description = if !url.is_empty() {
-                        format!("CDP:{}|{}", url, folder)
-                    } else {
-                        folder.clone()
-                    };
-
-                    let timestamp = parse_steam_time(time_str).unwrap_or_else(Local::now);
-
-                    all_files.push(CloudFile {
-                        name: filename,
-                        size: crate::file_manager::parse_size(size_str),
-                        timestamp,
-                        is_persisted: true,
-                        exists: true,
-                        root: 0,
-                        root_description,
-                    });
-                }
-            }
-
-            // 下一页检测
-            let next_index = index + 50;
-            // 使用 JS 遍历查找链接，比 querySelector 更稳健
-            let check_next_script = format!(
-                r#"
-                (function() {{
-                    const links = Array.from(document.querySelectorAll('a'));
-                    return links.some(a => a.href.includes('index={0}'));
-                }})()
-                "#,
-                next_index
-            );
-            let has_next = self
-                .evaluate(&check_next_script)?
-                .as_bool()
-                .unwrap_or(false);
+            // 下一页检测：页面中有指向 index=下一页 的链接
+            let has_next = remotestorage_html::has_index_link(&html, index + FILES_PER_PAGE);
 
             if has_next {
                 page += 1;
@@ -471,104 +549,227 @@ impl CdpClient {
     }
 }
 
-fn parse_steam_time(s: &str) -> Option<chrono::DateTime<Local>> {
-    use chrono::TimeZone;
-    // Example English: "23 Nov, 2025 @ 5:30pm" or "23 Nov @ 5:30pm"
-    // Example Chinese: "2021 年 3 月 6 日 下午 8:04"
-
-    let s = s.trim();
-    if s.is_empty() {
-        return None;
-    }
-
-    // 中文格式解析
-    if s.contains('年') && s.contains('月') && s.contains('日') {
-        let s_clean = s
-            .replace("年", " ")
-            .replace("月", " ")
-            .replace("日", " ")
-            .replace(":", " ");
-        let parts: Vec<&str> = s_clean.split_whitespace().collect();
-
-        // "2021 3 6 下午 8 04" -> ["2021", "3", "6", "下午", "8", "04"]
-        if parts.len() >= 6 {
-            let year = parts[0].parse::<i32>().ok()?;
-            let month = parts[1].parse::<u32>().ok()?;
-            let day = parts[2].parse::<u32>().ok()?;
-            let ampm = parts[3];
-            let mut hour = parts[4].parse::<u32>().ok()?;
-            let minute = parts[5].parse::<u32>().ok()?;
-
-            if (ampm == "下午" || ampm.to_lowercase() == "pm") && hour < 12 {
-                hour += 12;
-            }
-            if (ampm == "上午" || ampm.to_lowercase() == "am") && hour == 12 {
-                hour = 0;
-            }
+// 短超时的 HTTP agent，调试端口未开启时不至于卡住界面
+fn http_agent() -> ureq::Agent {
+    ureq::config::Config::builder()
+        .timeout_global(Some(Duration::from_secs(3)))
+        .build()
+        .new_agent()
+}
 
-            return Local
-                .with_ymd_and_hms(year, month, day, hour, minute, 0)
-                .single();
-        }
+// 查找目标页面并建立 WebSocket 连接
+fn open_socket(
+    endpoint: &CdpEndpoint,
+    target_type: CdpTarget,
+) -> Result<WebSocket<MaybeTlsStream<TcpStream>>> {
+    let resp = http_agent()
+        .get(&endpoint.json_url())
+        .call()
+        .map_err(|e| anyhow!("无法连接到 Steam 调试端口 {}: {}", endpoint, e))?;
+
+    let text = resp.into_body().read_to_string()?;
+    let targets: Vec<Target> = serde_json::from_str(&text)?;
+
+    // 根据目标类型选择正确的页面
+    let target_desc = match target_type {
+        CdpTarget::GameList => "游戏列表页",
+        CdpTarget::FileList => "文件详情页",
+    };
+
+    tracing::debug!(
+        "CDP 发现 {} 个调试目标，正在查找{}...",
+        targets.len(),
+        target_desc
+    );
+
+    // 精确匹配目标页面
+    let target = targets
+        .iter()
+        .find(|t| {
+            t.websocket_debugger_url.is_some()
+                && t.target_type == "page"
+                && t.url.as_ref().is_some_and(|url| {
+                    match target_type {
+                        CdpTarget::FileList => url.contains("remotestorageapp"),
+                        CdpTarget::GameList => {
+                            // 匹配 remotestorage 但排除 remotestorageapp
+                            url.contains("remotestorage") && !url.contains("remotestorageapp")
+                        }
+                    }
+                })
+        })
+        .or_else(|| {
+            // 回退：选择任意 store.steampowered.com 页面
+            targets.iter().find(|t| {
+                t.websocket_debugger_url.is_some()
+                    && t.target_type == "page"
+                    && t.url
+                        .as_ref()
+                        .is_some_and(|url| url.contains("store.steampowered.com"))
+            })
+        })
+        .or_else(|| {
+            // 最后回退：任意非 about:blank 的 page
+            targets.iter().find(|t| {
+                t.websocket_debugger_url.is_some()
+                    && t.target_type == "page"
+                    && t.url.as_ref().is_some_and(|url| !url.starts_with("about:"))
+            })
+        })
+        .ok_or_else(|| {
+            anyhow!(
+                "未找到{}的调试目标，请先在 Steam 客户端打开云存储页面",
+                target_desc
+            )
+        })?;
+
+    tracing::debug!(
+        "CDP 连接到目标 (期望: {}): type={}, url={:?}",
+        target_desc,
+        target.target_type,
+        target.url
+    );
+
+    let ws_url = target.websocket_debugger_url.as_ref().unwrap();
+    let (socket, _) = tungstenite::connect(ws_url)?;
+
+    // 设置读超时，使等待响应时可以检查截止时间
+    if let MaybeTlsStream::Plain(stream) = socket.get_ref() {
+        stream.set_read_timeout(Some(READ_POLL_INTERVAL))?;
     }
 
-    // 英文格式解析
-    let s_clean = s.replace(" @", "").replace(",", "");
-    let parts: Vec<&str> = s_clean.split_whitespace().collect();
-    if parts.len() < 3 {
-        return None;
-    }
-
-    let day = parts[0].parse::<u32>().ok()?;
-    let month_str = parts[1];
-    let month = match month_str.to_lowercase().as_str() {
-        "jan" => 1,
-        "feb" => 2,
-        "mar" => 3,
-        "apr" => 4,
-        "may" => 5,
-        "jun" => 6,
-        "jul" => 7,
-        "aug" => 8,
-        "sep" => 9,
-        "oct" => 10,
-        "nov" => 11,
-        "dec" => 12,
-        _ => return None,
-    };
+    Ok(socket)
+}
 
-    let mut year = Local::now().year();
-    let time_str;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cdp_test_server::CdpTestServer;
+    use crate::remotestorage_html::GAME_LIST_URL;
+    use chrono::{Local, TimeZone};
+
+    const APP_480_URL: &str = "https://store.steampowered.com/account/remotestorageapp/?appid=480";
+    const APP_480_PAGE2_URL: &str =
+        "https://store.steampowered.com/account/remotestorageapp/?appid=480&index=50";
+
+    fn connect(server: &CdpTestServer, target: CdpTarget) -> CdpClient {
+        CdpClient::connect_with(CdpEndpoint::new("127.0.0.1", server.port()), target)
+            .unwrap()
+            .with_command_timeout(Duration::from_secs(2))
+            .with_page_load_timeout(Duration::from_secs(2))
+    }
 
-    if parts.len() == 4 {
-        year = parts[2].parse::<i32>().ok()?;
-        time_str = parts[3];
-    } else {
-        time_str = parts[2];
+    fn app_480_server() -> CdpTestServer {
+        CdpTestServer::start(GAME_LIST_URL)
+            .with_page(
+                APP_480_URL,
+                include_str!("../tests/fixtures/cdp/remotestorageapp_480_page1.html"),
+            )
+            .with_page(
+                APP_480_PAGE2_URL,
+                include_str!("../tests/fixtures/cdp/remotestorageapp_480_page2.html"),
+            )
     }
 
-    let time_len = time_str.len();
-    if time_len < 3 {
-        return None;
+    #[test]
+    fn fetches_game_list_from_current_page() {
+        let server = CdpTestServer::start(GAME_LIST_URL).with_page(
+            GAME_LIST_URL,
+            include_str!("../tests/fixtures/cdp/remotestorage.html"),
+        );
+        let mut client = connect(&server, CdpTarget::GameList);
+
+        let games = client.fetch_game_list().unwrap();
+        let ids: Vec<u32> = games.iter().map(|g| g.app_id).collect();
+        assert_eq!(ids, vec![480, 620, 1030300]);
+        assert_eq!(games[0].game_name.as_deref(), Some("Spacewar"));
+        assert_eq!(games[0].total_size, 1_572_864);
+        assert_eq!(games[1].file_count, 12);
+        assert_eq!(
+            games[2].game_name.as_deref(),
+            Some("Hollow Knight: Silksong & Friends")
+        );
+        assert_eq!(games[2].total_size, 512);
+        // 已在游戏列表页，不需要导航
+        assert!(server.navigations().is_empty());
     }
-    let ampm = &time_str[time_len - 2..].to_lowercase();
-    let time_val = &time_str[..time_len - 2];
-    let time_parts: Vec<&str> = time_val.split(':').collect();
-    let mut hour = time_parts[0].parse::<u32>().ok()?;
-    let minute = if time_parts.len() > 1 {
-        time_parts[1].parse::<u32>().ok()?
-    } else {
-        0
-    };
 
-    if ampm == "pm" && hour < 12 {
-        hour += 12;
+    #[test]
+    fn follows_index_pagination() {
+        let server = app_480_server();
+        let mut client = connect(&server, CdpTarget::FileList);
+
+        let files = client.fetch_game_files(480).unwrap();
+        assert_eq!(server.navigations(), vec![APP_480_URL, APP_480_PAGE2_URL]);
+
+        let names: Vec<&str> = files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "saves/slot1.sav",
+                "settings.cfg",
+                "local_only.dat",
+                "saves/slot51.sav"
+            ]
+        );
+        assert_eq!(
+            files[0].root_description,
+            "CDP:https://steamusercontent-a.akamaihd.net/ugc/111/AAA/|WinMyDocuments"
+        );
+        assert_eq!(
+            files[1].root_description,
+            "CDP:https://store.steampowered.com/account/filedownload/?appid=480&file=settings.cfg|SteamRemote"
+        );
+        // 没有下载链接时只保留文件夹名
+        assert_eq!(files[2].root_description, "SteamRemote");
+        assert_eq!(files[0].size, 1_258_291);
+        assert_eq!(files[3].size, 921_600);
+        assert_eq!(
+            files[1].timestamp,
+            Local.with_ymd_and_hms(2021, 3, 6, 20, 4, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn reconnects_when_target_is_recreated() {
+        let server = app_480_server().drop_on_navigate(1);
+        let mut client = connect(&server, CdpTarget::FileList);
+
+        client.navigate(APP_480_URL).unwrap();
+        assert_eq!(server.connections(), 2);
+        assert_eq!(server.navigations(), vec![APP_480_URL, APP_480_URL]);
+        assert_eq!(
+            client.evaluate("window.location.href").unwrap(),
+            Value::from(APP_480_URL)
+        );
+    }
+
+    #[test]
+    fn navigation_reports_http_errors() {
+        let server = app_480_server().with_page_status(APP_480_URL, "", 429);
+        let mut client = connect(&server, CdpTarget::FileList);
+
+        let err = client.navigate(APP_480_URL).unwrap_err();
+        assert!(err.to_string().contains("429"));
     }
-    if ampm == "am" && hour == 12 {
-        hour = 0;
+
+    #[test]
+    fn test_server_does_not_fake_scripts() {
+        let server = app_480_server();
+        let mut client = connect(&server, CdpTarget::FileList);
+
+        // 表格提取只依赖页面 HTML，替身服务器不会模拟任意脚本的结果
+        let err = client
+            .evaluate("document.querySelectorAll('.accountTable tr').length")
+            .unwrap_err();
+        assert!(err.to_string().contains("JS 执行异常"));
     }
 
-    Local
-        .with_ymd_and_hms(year, month, day, hour, minute, 0)
-        .single()
+    #[test]
+    fn event_patterns() {
+        assert!(event_matches("Network.*", "Network.responseReceived"));
+        assert!(!event_matches("Network.*", "NetworkX.foo"));
+        assert!(event_matches("Page.loadEventFired", "Page.loadEventFired"));
+        assert!(!event_matches("Page.loadEventFired", "Page.frameNavigated"));
+    }
 }
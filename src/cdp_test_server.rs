@@ -0,0 +1,227 @@
+// 测试用的 CDP 替身服务器
+// 提供 /json 调试目标列表和 WebSocket 端点，用录制的网页版云存储 HTML 回放 Page.navigate。
+// Runtime.evaluate 只回答读取页面属性的表达式（如 outerHTML），其余脚本按 JS 异常返回，
+// 表格提取和翻页检测都走客户端真实的解析代码
+
+use regex::Regex;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+
+// 录制的页面
+#[derive(Clone)]
+struct FixturePage {
+    html: String,
+    status: u16,
+}
+
+#[derive(Default)]
+struct ServerState {
+    pages: HashMap<String, FixturePage>,
+    current_url: String,
+    // 剩余需要在收到 Page.navigate 时直接断开的次数（模拟 CEF 重建页面目标）
+    drop_on_navigate: u32,
+    navigations: Vec<String>,
+    connections: usize,
+}
+
+pub struct CdpTestServer {
+    port: u16,
+    state: Arc<Mutex<ServerState>>,
+}
+
+impl CdpTestServer {
+    // 启动服务器，initial_url 为当前打开的页面
+    pub fn start(initial_url: &str) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let state = Arc::new(Mutex::new(ServerState {
+            current_url: initial_url.to_string(),
+            ..Default::default()
+        }));
+
+        let server_state = state.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let state = server_state.clone();
+                std::thread::spawn(move || handle_connection(stream, port, state));
+            }
+        });
+
+        Self { port, state }
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    pub fn with_page(self, url: &str, html: &str) -> Self {
+        self.with_page_status(url, html, 200)
+    }
+
+    pub fn with_page_status(self, url: &str, html: &str, status: u16) -> Self {
+        self.state.lock().unwrap().pages.insert(
+            url.to_string(),
+            FixturePage {
+                html: html.to_string(),
+                status,
+            },
+        );
+        self
+    }
+
+    pub fn drop_on_navigate(self, times: u32) -> Self {
+        self.state.lock().unwrap().drop_on_navigate = times;
+        self
+    }
+
+    pub fn navigations(&self) -> Vec<String> {
+        self.state.lock().unwrap().navigations.clone()
+    }
+
+    pub fn connections(&self) -> usize {
+        self.state.lock().unwrap().connections
+    }
+}
+
+fn handle_connection(stream: TcpStream, port: u16, state: Arc<Mutex<ServerState>>) {
+    let mut head = [0u8; 64];
+    let n = match stream.peek(&mut head) {
+        Ok(n) => n,
+        Err(_) => return,
+    };
+    let request_line = String::from_utf8_lossy(&head[..n]).to_string();
+
+    if request_line.starts_with("GET /json") {
+        serve_target_list(stream, port, &state);
+        return;
+    }
+
+    let mut socket = match tungstenite::accept(stream) {
+        Ok(socket) => socket,
+        Err(_) => return,
+    };
+    state.lock().unwrap().connections += 1;
+
+    loop {
+        let text = match socket.read() {
+            Ok(tungstenite::Message::Text(text)) => text.to_string(),
+            Ok(tungstenite::Message::Close(_)) | Err(_) => return,
+            Ok(_) => continue,
+        };
+        let command: Value = match serde_json::from_str(&text) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+        let id = command["id"].as_u64().unwrap_or(0);
+        let method = command["method"].as_str().unwrap_or("");
+        let params = &command["params"];
+
+        let mut events = Vec::new();
+        let result = match method {
+            "Page.navigate" => {
+                let url = params["url"].as_str().unwrap_or("").to_string();
+                let mut s = state.lock().unwrap();
+                s.navigations.push(url.clone());
+                if s.drop_on_navigate > 0 {
+                    s.drop_on_navigate -= 1;
+                    // 直接断开，不发送关闭帧
+                    return;
+                }
+                let status = s.pages.get(&url).map(|p| p.status).unwrap_or(404);
+                s.current_url = url.clone();
+                events.push(json!({
+                    "method": "Network.responseReceived",
+                    "params": {"type": "Document", "response": {"url": url, "status": status}}
+                }));
+                events.push(json!({"method": "Page.loadEventFired", "params": {"timestamp": 1.0}}));
+                json!({"frameId": "1"})
+            }
+            "Runtime.evaluate" => {
+                let script = params["expression"].as_str().unwrap_or("");
+                let s = state.lock().unwrap();
+                let html = s
+                    .pages
+                    .get(&s.current_url)
+                    .map(|p| p.html.clone())
+                    .unwrap_or_default();
+                match evaluate(script, &s.current_url, &html) {
+                    Some(value) => json!({"result": {"type": "string", "value": value}}),
+                    None => json!({
+                        "result": {"type": "object", "subtype": "error"},
+                        "exceptionDetails": {"text": format!("测试服务器不支持的脚本: {}", script)}
+                    }),
+                }
+            }
+            _ => json!({}),
+        };
+
+        let response = json!({"id": id, "result": result});
+        if socket
+            .send(tungstenite::Message::Text(response.to_string().into()))
+            .is_err()
+        {
+            return;
+        }
+        for event in events {
+            if socket
+                .send(tungstenite::Message::Text(event.to_string().into()))
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+}
+
+fn serve_target_list(mut stream: TcpStream, port: u16, state: &Arc<Mutex<ServerState>>) {
+    // 读完请求头
+    let mut request = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+        match stream.read(&mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => request.extend_from_slice(&buf[..n]),
+        }
+    }
+
+    let url = state.lock().unwrap().current_url.clone();
+    let body = json!([
+        {"type": "service_worker", "url": "https://store.steampowered.com/sw.js"},
+        {
+            "type": "page",
+            "url": url,
+            "webSocketDebuggerUrl": format!("ws://127.0.0.1:{}/devtools/page/1", port)
+        }
+    ])
+    .to_string();
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+// 页面属性读取，与浏览器对同一份 HTML 的求值结果一致
+fn evaluate(script: &str, url: &str, html: &str) -> Option<Value> {
+    match script {
+        "document.readyState" => Some(json!("complete")),
+        "window.location.href" => Some(json!(url)),
+        "document.documentElement.outerHTML" => Some(json!(html)),
+        "document.title + ' | ' + window.location.href" => {
+            let title = capture(r"(?s)<title>(.*?)</title>", html).unwrap_or_default();
+            Some(json!(format!("{} | {}", title, url)))
+        }
+        _ => None,
+    }
+}
+
+fn capture(pattern: &str, text: &str) -> Option<String> {
+    Regex::new(pattern)
+        .unwrap()
+        .captures(text)
+        .map(|c| c[1].to_string())
+}
@@ -1,14 +1,21 @@
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Mutex, OnceLock};
 
 // 全局配置实例
 static CONFIG: OnceLock<Mutex<AppConfig>> = OnceLock::new();
 
+// 配置结构版本，字段改名或移动时递增，并在 MIGRATIONS 末尾追加对应的迁移函数
+pub const CONFIG_SCHEMA_VERSION: u32 = 2;
+
 // 应用配置结构
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
+    // 写入该文件的配置结构版本，旧文件没有此字段，视为版本 1
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
+
     // 路径设置
     #[serde(default)]
     pub paths: PathsConfig,
@@ -24,6 +31,42 @@ pub struct AppConfig {
     // 软链接配置
     #[serde(default)]
     pub symlinks: Vec<SymlinkConfigEntry>,
+
+    // 上传设置
+    #[serde(default)]
+    pub upload: UploadConfig,
+
+    // 下载设置
+    #[serde(default)]
+    pub download: DownloadConfig,
+
+    // Steam CEF 调试端口设置
+    #[serde(default)]
+    pub cdp: CdpConfig,
+
+    // 网页版云存储读取方式
+    #[serde(default)]
+    pub remote_storage: RemoteStorageConfig,
+}
+
+fn default_schema_version() -> u32 {
+    CONFIG_SCHEMA_VERSION
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            schema_version: CONFIG_SCHEMA_VERSION,
+            paths: PathsConfig::default(),
+            appearance: AppearanceConfig::default(),
+            logging: LoggingConfig::default(),
+            symlinks: Vec::new(),
+            upload: UploadConfig::default(),
+            download: DownloadConfig::default(),
+            cdp: CdpConfig::default(),
+            remote_storage: RemoteStorageConfig::default(),
+        }
+    }
 }
 
 // 软链接配置项
@@ -31,8 +74,8 @@ pub struct AppConfig {
 pub struct SymlinkConfigEntry {
     pub id: String,
     pub app_id: u32,
-    pub direction: String, // "remote_to_local" or "local_to_remote"
-    pub local_path: PathBuf,
+    pub direction: String,   // "remote_to_local" or "local_to_remote"
+    pub local_path: PathBuf, // 绝对路径，或 {RootName}/... 、$HOME/... 形式的可移植路径
     pub remote_subfolder: String,
     #[serde(default)]
     pub platform: String, // "windows", "macos", "linux"
@@ -52,6 +95,8 @@ pub struct SaveFileEntry {
     pub platforms: Vec<String>, // 支持的平台
     #[serde(default = "default_recursive")]
     pub recursive: bool, // 是否递归
+    #[serde(default)]
+    pub extra: Vec<UfsExtraKey>, // 未识别的键，写回时原样保留
 }
 
 fn default_recursive() -> bool {
@@ -72,22 +117,174 @@ pub struct RootOverrideEntry {
     pub original_root: String, // 原始根名称 (VDF: root)
     pub os: String,            // 目标操作系统 (VDF: os)
     pub new_root: String,      // 新的根名称 (VDF: useinstead)
+    #[serde(default = "default_oscompare")]
+    pub oscompare: String, // 系统比较符 (VDF: oscompare)，已知值 "="
     #[serde(default)]
     pub add_path: String, // 附加路径 (VDF: addpath), 当无 pathtransforms 时使用
     #[serde(default)]
     pub path_transforms: Vec<PathTransform>, // 路径转换规则 (VDF: pathtransforms)
-                               // 注意：Steamworks 中 "Replace Path" 勾选框的实际行为是：
-                               // - 勾选: 生成 pathtransforms 结构，不生成 addpath
-                               // - 不勾选: 生成 addpath 字段，不生成 pathtransforms
+    // 注意：Steamworks 中 "Replace Path" 勾选框的实际行为是：
+    // - 勾选: 生成 pathtransforms 结构，不生成 addpath
+    // - 不勾选: 生成 addpath 字段，不生成 pathtransforms
+    #[serde(default)]
+    pub extra: Vec<UfsExtraKey>, // 未识别的键，写回时原样保留
+}
+
+fn default_oscompare() -> String {
+    "=".to_string()
+}
+
+// UFS 节中未识别的键值（值或子节），数值保留 appinfo.vdf 中的原始类型
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum UfsExtraValue {
+    Value(String),
+    Int32(i32),
+    UInt64(u64),
+    Section(Vec<UfsExtraKey>),
+}
+
+impl UfsExtraValue {
+    // 标量值的文本形式，子节返回 None
+    pub fn as_text(&self) -> Option<String> {
+        match self {
+            UfsExtraValue::Value(value) => Some(value.clone()),
+            UfsExtraValue::Int32(value) => Some(value.to_string()),
+            UfsExtraValue::UInt64(value) => Some(value.to_string()),
+            UfsExtraValue::Section(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UfsExtraKey {
+    pub key: String,
+    pub value: UfsExtraValue,
+}
+
+// UFS 节中 savefiles / rootoverrides 之外的顶层设置
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct UfsSettings {
+    #[serde(default)]
+    pub quota: Option<u64>, // 配额字节数 (VDF: quota)
+    #[serde(default)]
+    pub maxnumfiles: Option<u32>, // 最大文件数 (VDF: maxnumfiles)
+    #[serde(default)]
+    pub hidecloudui: Option<bool>, // 隐藏 Steam 云存档界面 (VDF: hidecloudui)
+    #[serde(default)]
+    pub extra: Vec<UfsExtraKey>, // 未识别的顶层键，写回时原样保留
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>, // 编辑器中删除的原有顶层键，注入时从 appinfo 中移除
+}
+
+impl UfsSettings {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    // 已设置的顶层键名
+    pub fn keys(&self) -> Vec<&str> {
+        let mut keys = Vec::new();
+        if self.quota.is_some() {
+            keys.push("quota");
+        }
+        if self.maxnumfiles.is_some() {
+            keys.push("maxnumfiles");
+        }
+        if self.hidecloudui.is_some() {
+            keys.push("hidecloudui");
+        }
+        keys.extend(self.extra.iter().map(|e| e.key.as_str()));
+        keys
+    }
+
+    // 记录 original 中有而当前没有的键，注入时才会删除它们
+    pub fn with_removed_from(mut self, original: &UfsSettings) -> Self {
+        let current = self.keys();
+        let removed: Vec<String> = original
+            .keys()
+            .into_iter()
+            .filter(|key| !current.contains(key))
+            .map(str::to_string)
+            .collect();
+        self.removed = removed;
+        self
+    }
 }
 
 // 游戏 UFS 配置
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct UfsGameConfig {
     pub app_id: u32,
     pub savefiles: Vec<SaveFileEntry>,
     #[serde(default)]
     pub root_overrides: Vec<RootOverrideEntry>,
+    #[serde(default, skip_serializing_if = "UfsSettings::is_empty")]
+    pub settings: UfsSettings,
+}
+
+// 游戏配置档：按 app_id 覆盖全局偏好，连接到该游戏时自动应用
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct GameProfile {
+    pub app_id: u32,
+    // 默认下载目录，设置后下载时不再弹出目录选择
+    #[serde(default)]
+    pub download_dir: Option<PathBuf>,
+    // 上传时默认的云端虚拟根目录 (UploadQueue::virtual_root)
+    #[serde(default)]
+    pub upload_virtual_root: Option<String>,
+    // 上传过滤规则，未设置时使用全局 [upload] 配置
+    #[serde(default)]
+    pub upload_filter: Option<UploadConfig>,
+    // 自动备份间隔（小时），0 表示关闭
+    #[serde(default)]
+    pub backup_interval_hours: u32,
+    // 上次自动备份的时间戳（秒）
+    #[serde(default)]
+    pub last_backup_at: Option<i64>,
+    // 新建软链接时的默认方向（remote_to_local / local_to_remote）
+    #[serde(default)]
+    pub symlink_direction: Option<String>,
+    // 新建软链接时的默认云端子目录
+    #[serde(default)]
+    pub symlink_remote_subfolder: Option<String>,
+}
+
+impl GameProfile {
+    pub fn new(app_id: u32) -> Self {
+        Self {
+            app_id,
+            ..Default::default()
+        }
+    }
+
+    // 没有任何覆盖项时视为空配置档（last_backup_at 只是运行记录）
+    pub fn is_empty(&self) -> bool {
+        Self {
+            last_backup_at: None,
+            ..self.clone()
+        } == Self::new(self.app_id)
+    }
+
+    // 本游戏生效的上传过滤配置
+    pub fn upload_config(&self) -> UploadConfig {
+        self.upload_config_or(&get_config().upload)
+    }
+
+    // 未设置时使用传入的全局上传配置
+    pub fn upload_config_or(&self, global: &UploadConfig) -> UploadConfig {
+        self.upload_filter.clone().unwrap_or_else(|| global.clone())
+    }
+
+    // 是否到了自动备份时间
+    pub fn backup_due(&self, now: i64) -> bool {
+        if self.backup_interval_hours == 0 {
+            return false;
+        }
+        match self.last_backup_at {
+            Some(last) => now - last >= i64::from(self.backup_interval_hours) * 3600,
+            None => true,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -143,6 +340,201 @@ impl Default for LoggingConfig {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UploadConfig {
+    // 跳过隐藏文件/文件夹（以 . 开头）
+    #[serde(default = "default_true")]
+    pub skip_hidden: bool,
+
+    // 跳过临时文件（*.tmp, *~, .DS_Store 等）
+    #[serde(default = "default_true")]
+    pub skip_temp: bool,
+
+    // 包含规则（gitignore 风格），为空表示包含全部
+    #[serde(default)]
+    pub include: Vec<String>,
+
+    // 排除规则（gitignore 风格），支持 ! 取反
+    #[serde(default)]
+    pub exclude: Vec<String>,
+
+    // 云端路径改写规则，按顺序应用
+    #[serde(default)]
+    pub path_rules: Vec<PathRewriteRule>,
+
+    // 上传后从云端读回文件并校验 SHA-1
+    #[serde(default)]
+    pub verify_after_write: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for UploadConfig {
+    fn default() -> Self {
+        Self {
+            skip_hidden: true,
+            skip_temp: true,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            path_rules: Vec::new(),
+            verify_after_write: false,
+        }
+    }
+}
+
+// 网页版云存储读取方式
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RemoteStorageConfig {
+    #[serde(default)]
+    pub provider: RemoteStorageProvider,
+
+    // 网页版会话 Cookie（steamLoginSecure），仅 web 方式使用
+    #[serde(default)]
+    pub session_cookie: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RemoteStorageProvider {
+    // 通过 Steam 内置浏览器的 CEF 调试端口读取（需要以调试模式重启 Steam）
+    #[default]
+    Cdp,
+    // 直接请求 store.steampowered.com，使用用户提供的会话 Cookie
+    Web,
+}
+
+// Steam CEF 调试端口设置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CdpConfig {
+    #[serde(default = "default_cdp_host")]
+    pub host: String,
+
+    #[serde(default = "default_cdp_port")]
+    pub port: u16,
+
+    // 单个命令等待响应的超时（秒）
+    #[serde(default = "default_cdp_timeout")]
+    pub command_timeout_secs: u64,
+
+    // 导航后等待页面加载事件的超时（秒）
+    #[serde(default = "default_cdp_timeout")]
+    pub page_load_timeout_secs: u64,
+
+    // 页面目标被重建导致连接断开时的最大重连次数
+    #[serde(default = "default_cdp_reconnects")]
+    pub max_reconnects: u32,
+}
+
+fn default_cdp_host() -> String {
+    "127.0.0.1".to_string()
+}
+
+fn default_cdp_port() -> u16 {
+    8080
+}
+
+fn default_cdp_timeout() -> u64 {
+    15
+}
+
+fn default_cdp_reconnects() -> u32 {
+    2
+}
+
+impl Default for CdpConfig {
+    fn default() -> Self {
+        Self {
+            host: default_cdp_host(),
+            port: default_cdp_port(),
+            command_timeout_secs: default_cdp_timeout(),
+            page_load_timeout_secs: default_cdp_timeout(),
+            max_reconnects: default_cdp_reconnects(),
+        }
+    }
+}
+
+// 下载设置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadConfig {
+    // 同时下载的文件数（仅对 CDP 链接生效，Steam API 读取始终串行）
+    #[serde(default = "default_download_concurrency")]
+    pub concurrency: usize,
+
+    // 总下载速度上限（KB/s），0 表示不限速
+    #[serde(default)]
+    pub rate_limit_kbps: u64,
+
+    // 下载来源尝试顺序
+    #[serde(default = "default_source_order")]
+    pub source_order: Vec<DownloadSource>,
+
+    // 严格模式：不回退到可能过期的本地副本，本地复制只在排在首位时使用；
+    // 云端来源之间仍按顺序回退
+    #[serde(default)]
+    pub strict: bool,
+
+    // 按备份的 root 目录结构保存（root0_SteamRemote/...），避免不同 root 的同名文件互相覆盖
+    #[serde(default)]
+    pub mirror_root_layout: bool,
+
+    // 在下载目录写入清单文件，记录云端路径、时间戳和 SHA-1，便于重新上传或对比
+    #[serde(default = "default_true")]
+    pub write_manifest: bool,
+}
+
+// 下载来源
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DownloadSource {
+    // 网页版云存储的 CDP 下载链接
+    Cdp,
+    // 通过 Steam Worker 读取
+    SteamApi,
+    // 从本地存档目录复制
+    Local,
+}
+
+pub const DEFAULT_SOURCE_ORDER: [DownloadSource; 3] = [
+    DownloadSource::Cdp,
+    DownloadSource::SteamApi,
+    DownloadSource::Local,
+];
+
+fn default_source_order() -> Vec<DownloadSource> {
+    DEFAULT_SOURCE_ORDER.to_vec()
+}
+
+fn default_download_concurrency() -> usize {
+    4
+}
+
+impl Default for DownloadConfig {
+    fn default() -> Self {
+        Self {
+            concurrency: default_download_concurrency(),
+            rate_limit_kbps: 0,
+            source_order: default_source_order(),
+            strict: false,
+            mirror_root_layout: false,
+            write_manifest: true,
+        }
+    }
+}
+
+// 云端路径改写规则
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PathRewriteRule {
+    // 去掉路径前缀，如 "MyGame/" -> ""
+    StripPrefix { prefix: String },
+    // 整个路径转为小写
+    Lowercase,
+    // 替换完整路径段，如 "Saves" -> "save"
+    ReplaceSegment { from: String, to: String },
+}
+
 impl AppConfig {
     // 生成默认配置文件内容
     pub fn default_toml_with_comments() -> String {
@@ -201,8 +593,7 @@ pub fn load_config() -> Result<AppConfig> {
 
     // 如果配置文件存在，加载它
     if config_path.exists() {
-        let content = std::fs::read_to_string(&config_path)?;
-        let config: AppConfig = toml::from_str(&content)?;
+        let config = load_config_from(&config_path)?;
         tracing::debug!("加载配置文件: {:?}", config_path);
         return Ok(config);
     }
@@ -214,22 +605,119 @@ pub fn load_config() -> Result<AppConfig> {
     Ok(config)
 }
 
+// 读取指定配置文件，旧版本先备份原文件，迁移后写回
+pub fn load_config_from(config_path: &Path) -> Result<AppConfig> {
+    let content = std::fs::read_to_string(config_path)?;
+    let (config, from_version) = parse_config(&content)?;
+    if from_version < CONFIG_SCHEMA_VERSION {
+        let backup_path = backup_config(config_path, from_version)?;
+        tracing::info!(
+            "配置文件已从版本 {} 迁移到 {}，原文件备份为 {:?}",
+            from_version,
+            CONFIG_SCHEMA_VERSION,
+            backup_path
+        );
+        write_config_to(config_path, &config)?;
+    }
+    Ok(config)
+}
+
+// 解析配置文本并迁移到当前版本，返回 (配置, 文件原版本)
+pub fn parse_config(content: &str) -> Result<(AppConfig, u32)> {
+    let mut table: toml::Table = toml::from_str(content)?;
+    let from_version = migrate_config(&mut table)?;
+    let config: AppConfig = toml::Value::Table(table).try_into()?;
+    Ok((config, from_version))
+}
+
+type Migration = fn(&mut toml::Table) -> Result<()>;
+
+// 第 i 项把版本 i + 1 的配置迁移到版本 i + 2
+const MIGRATIONS: &[Migration] = &[migrate_v1_to_v2];
+
+// 依次执行迁移函数，返回迁移前的版本
+pub fn migrate_config(table: &mut toml::Table) -> Result<u32> {
+    let from_version = match table.get("schema_version") {
+        None => 1,
+        Some(value) => value
+            .as_integer()
+            .and_then(|v| u32::try_from(v).ok())
+            .filter(|v| *v >= 1)
+            .ok_or_else(|| anyhow!("无效的配置版本: {}", value))?,
+    };
+    if from_version > CONFIG_SCHEMA_VERSION {
+        return Err(anyhow!(
+            "配置文件版本 {} 高于当前支持的版本 {}，请升级程序",
+            from_version,
+            CONFIG_SCHEMA_VERSION
+        ));
+    }
+
+    for version in from_version..CONFIG_SCHEMA_VERSION {
+        MIGRATIONS[(version - 1) as usize](table)
+            .map_err(|e| anyhow!("配置从版本 {} 迁移失败: {}", version, e))?;
+        table.insert(
+            "schema_version".to_string(),
+            toml::Value::Integer(i64::from(version + 1)),
+        );
+    }
+    Ok(from_version)
+}
+
+// v1 → v2: 软链接本地路径改写为 {Root}/... 或 $HOME/... 形式，可在其他电脑上使用。
+// 只使用当前平台原生的 Root，其余路径保持绝对路径
+fn migrate_v1_to_v2(table: &mut toml::Table) -> Result<()> {
+    make_symlink_paths_portable(table, crate::path_resolver::to_portable_path);
+    Ok(())
+}
+
+// 用 to_portable 改写软链接的本地路径，已是可移植形式或无法转换的保持不变
+fn make_symlink_paths_portable(
+    table: &mut toml::Table,
+    to_portable: impl Fn(&Path) -> Option<String>,
+) {
+    let Some(symlinks) = table.get_mut("symlinks").and_then(|v| v.as_array_mut()) else {
+        return;
+    };
+    for entry in symlinks.iter_mut().filter_map(|v| v.as_table_mut()) {
+        let Some(local_path) = entry.get("local_path").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        if crate::path_resolver::is_portable_path(local_path) {
+            continue;
+        }
+        if let Some(portable) = to_portable(Path::new(local_path)) {
+            entry.insert("local_path".to_string(), toml::Value::String(portable));
+        }
+    }
+}
+
+// 迁移前备份原配置文件：config.v<版本>.<时间>.toml.bak
+fn backup_config(config_path: &Path, version: u32) -> Result<PathBuf> {
+    let backup_path = config_path.with_file_name(format!(
+        "config.v{}.{}.toml.bak",
+        version,
+        chrono::Local::now().format("%Y%m%d%H%M%S")
+    ));
+    std::fs::copy(config_path, &backup_path).map_err(|e| anyhow!("备份配置文件失败: {}", e))?;
+    Ok(backup_path)
+}
+
 // 保存配置
 pub fn save_config(config: &AppConfig) -> Result<()> {
-    let config_path = get_config_path()?;
+    write_config_to(&get_config_path()?, config)
+}
 
-    // 如果是默认配置，写入带注释的版本
-    let content = if config.paths.steam_path.is_none()
-        && config.appearance.theme_mode == "system"
-        && config.appearance.language == "auto"
-        && config.logging.enabled
-    {
+fn write_config_to(config_path: &Path, config: &AppConfig) -> Result<()> {
+    // 如果是默认配置，写入带注释的版本（迁移写回时不能丢掉软链接等其他字段，因此比较完整内容）
+    let content = toml::to_string_pretty(config)?;
+    let content = if content == toml::to_string_pretty(&AppConfig::default())? {
         AppConfig::default_toml_with_comments()
     } else {
-        toml::to_string_pretty(config)?
+        content
     };
 
-    std::fs::write(&config_path, content)?;
+    std::fs::write(config_path, content)?;
     tracing::debug!("保存配置文件: {:?}", config_path);
 
     Ok(())
@@ -386,6 +874,24 @@ pub fn remove_symlink_config(id: &str) -> Result<()> {
     Ok(())
 }
 
+// 更新软链接配置（按 id 替换）
+pub fn update_symlink_config(entry: SymlinkConfigEntry) -> Result<()> {
+    let config = CONFIG
+        .get()
+        .ok_or_else(|| anyhow::anyhow!("配置未初始化"))?;
+    let mut config = config.lock().map_err(|_| anyhow::anyhow!("配置锁定失败"))?;
+    match config.symlinks.iter_mut().find(|c| c.id == entry.id) {
+        Some(existing) => *existing = entry,
+        None => return Err(anyhow::anyhow!("软链接配置不存在: {}", entry.id)),
+    }
+
+    // 保存到文件
+    let config_path = get_config_path()?;
+    let content = toml::to_string_pretty(&*config)?;
+    std::fs::write(&config_path, content)?;
+    Ok(())
+}
+
 // ============== UFS 游戏配置管理函数 ==============
 
 // 获取 UFS 配置目录
@@ -428,3 +934,252 @@ pub fn remove_ufs_game_config(app_id: u32) -> Result<()> {
     }
     Ok(())
 }
+
+// 列出所有已保存的 UFS 游戏配置（按 app_id 排序）
+pub fn list_ufs_game_configs() -> Vec<UfsGameConfig> {
+    let entries = match get_ufs_config_dir().and_then(|dir| Ok(std::fs::read_dir(dir)?)) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut configs: Vec<UfsGameConfig> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("json"))
+        .filter_map(|p| {
+            let content = std::fs::read_to_string(&p).ok()?;
+            match serde_json::from_str::<UfsGameConfig>(&content) {
+                Ok(config) => Some(config),
+                Err(e) => {
+                    tracing::warn!("跳过无效的 UFS 配置 {}: {}", p.display(), e);
+                    None
+                }
+            }
+        })
+        .collect();
+    configs.sort_by_key(|c| c.app_id);
+    configs
+}
+
+// ============== 游戏配置档管理函数 ==============
+
+// 获取游戏配置档目录（与 ufs_configs 并列）
+pub fn get_game_profile_dir() -> Result<PathBuf> {
+    let dir = get_config_dir()?.join("game_profiles");
+    if !dir.exists() {
+        std::fs::create_dir_all(&dir)?;
+    }
+    Ok(dir)
+}
+
+// 获取指定游戏的配置档
+pub fn get_game_profile(app_id: u32) -> Option<GameProfile> {
+    let path = get_game_profile_dir()
+        .ok()?
+        .join(format!("{}.json", app_id));
+    if !path.exists() {
+        return None;
+    }
+    let content = std::fs::read_to_string(&path).ok()?;
+    match serde_json::from_str(&content) {
+        Ok(profile) => Some(profile),
+        Err(e) => {
+            tracing::warn!("跳过无效的游戏配置档 {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+// 保存游戏配置档，空配置档直接删除文件
+pub fn save_game_profile(profile: &GameProfile) -> Result<()> {
+    let path = get_game_profile_dir()?.join(format!("{}.json", profile.app_id));
+    if profile.is_empty() {
+        if path.exists() {
+            std::fs::remove_file(&path)?;
+            tracing::info!("已删除游戏配置档: {}", path.display());
+        }
+        return Ok(());
+    }
+    let content = serde_json::to_string_pretty(profile)?;
+    std::fs::write(&path, content)?;
+    tracing::info!("已保存游戏配置档: {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V1_INITIAL: &str = include_str!("../tests/fixtures/config/v1_initial.toml");
+    // 1.3.5 首次启动写入的带注释模板，以及修改设置后由 toml 序列化写出的配置
+    const V1_3_5_DEFAULT: &str = include_str!("../tests/fixtures/config/v1_3_5_default.toml");
+    const V1_3_5_SAVED: &str = include_str!("../tests/fixtures/config/v1_3_5_saved.toml");
+    const V2_CURRENT: &str = include_str!("../tests/fixtures/config/v2_current.toml");
+
+    #[test]
+    fn migrates_fixtures_from_past_releases() {
+        let (config, from) = parse_config(V1_INITIAL).unwrap();
+        assert_eq!(from, 1);
+        assert_eq!(config.schema_version, CONFIG_SCHEMA_VERSION);
+        assert_eq!(config.paths.steam_path, Some(PathBuf::from("/opt/steam")));
+        assert_eq!(config.appearance.theme_mode, "dark");
+        assert_eq!(config.symlinks.len(), 1);
+        // 不在已知系统目录下的路径保持原样
+        assert_eq!(
+            config.symlinks[0].local_path,
+            PathBuf::from("/opt/games/Spacewar/Saves")
+        );
+        assert_eq!(config.download.concurrency, 4);
+
+        let (config, from) = parse_config(V1_3_5_DEFAULT).unwrap();
+        assert_eq!(from, 1);
+        assert_eq!(config.paths.steam_path, None);
+        assert_eq!(config.appearance.theme_mode, "system");
+        assert!(config.symlinks.is_empty());
+
+        let (config, from) = parse_config(V1_3_5_SAVED).unwrap();
+        assert_eq!(from, 1);
+        assert_eq!(config.appearance.language, "en");
+        assert!(!config.logging.enabled);
+        assert_eq!(config.symlinks[0].note, "shared config");
+        // 旧版本没有的配置段使用默认值
+        assert_eq!(config.upload, UploadConfig::default());
+        assert_eq!(config.download.concurrency, 4);
+        assert!(!config.download.strict);
+        assert_eq!(config.remote_storage.provider, RemoteStorageProvider::Cdp);
+        assert_eq!(config.cdp.port, 8080);
+
+        let (config, from) = parse_config(V2_CURRENT).unwrap();
+        assert_eq!(from, 2);
+        assert_eq!(
+            config.symlinks[0].local_path,
+            PathBuf::from("$HOME/Games/Spacewar")
+        );
+
+        let (config, from) = parse_config(&AppConfig::default_toml_with_comments()).unwrap();
+        assert_eq!(from, CONFIG_SCHEMA_VERSION);
+        assert_eq!(config.download.concurrency, 4);
+
+        assert!(parse_config("schema_version = 99").is_err());
+        assert!(parse_config("schema_version = 0").is_err());
+    }
+
+    #[test]
+    fn migrates_home_paths_to_portable_form() {
+        use crate::path_resolver::RootType;
+
+        // 注入主目录和系统目录，不读取也不修改进程环境变量；
+        // 按 Linux 上的实际映射提供 Windows Root，确认迁移不会写入它们
+        let home = PathBuf::from("/scfm_home");
+        let data_home = home.join(".local").join("share");
+        let config_home = home.join(".config");
+        let root_base = |root| match root {
+            RootType::LinuxXdgDataHome
+            | RootType::WinAppDataLocal
+            | RootType::WinAppDataLocalLow => Some(data_home.clone()),
+            RootType::LinuxXdgConfigHome | RootType::WinAppDataRoaming => Some(config_home.clone()),
+            _ => None,
+        };
+        let to_portable = |path: &Path| {
+            let roots = crate::path_resolver::portable_roots_for("linux");
+            crate::path_resolver::to_portable_path_with(path, roots, Some(&home), root_base)
+        };
+
+        let content = format!(
+            "[[symlinks]]\nlocal_path = {:?}\n\n[[symlinks]]\nlocal_path = {:?}\n\n[[symlinks]]\nlocal_path = {:?}\n\n[[symlinks]]\nlocal_path = \"/srv/saves\"\n\n[[symlinks]]\nlocal_path = \"{{WinMyDocuments}}/Game\"\n",
+            home.join("scfm_saves").to_string_lossy(),
+            data_home.join("Game").join("Saves").to_string_lossy(),
+            config_home.join("Game").to_string_lossy(),
+        );
+        let mut table: toml::Table = toml::from_str(&content).unwrap();
+        make_symlink_paths_portable(&mut table, to_portable);
+
+        let paths: Vec<&str> = table["symlinks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["local_path"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "$HOME/scfm_saves",
+                "{LinuxXdgDataHome}/Game/Saves",
+                "{LinuxXdgConfigHome}/Game",
+                "/srv/saves",
+                "{WinMyDocuments}/Game",
+            ]
+        );
+    }
+
+    #[test]
+    fn game_profile_overrides_and_schedule() {
+        let mut profile = GameProfile::new(480);
+        assert!(profile.is_empty());
+        let global = UploadConfig {
+            exclude: vec!["*.tmp".to_string()],
+            ..UploadConfig::default()
+        };
+        assert_ne!(global, UploadConfig::default());
+        assert_eq!(profile.upload_config_or(&global), global);
+        assert!(!profile.backup_due(0));
+
+        // 只有备份记录不算覆盖项
+        profile.last_backup_at = Some(1_000);
+        assert!(profile.is_empty());
+
+        profile.backup_interval_hours = 2;
+        profile.upload_filter = Some(UploadConfig {
+            skip_hidden: false,
+            exclude: vec!["*.log".to_string()],
+            ..UploadConfig::default()
+        });
+        assert!(!profile.is_empty());
+        assert!(!profile.upload_config_or(&global).skip_hidden);
+        assert_eq!(profile.upload_config_or(&global).exclude, vec!["*.log"]);
+        assert!(!profile.backup_due(1_000 + 7_199));
+        assert!(profile.backup_due(1_000 + 7_200));
+
+        // 旧版本写出的配置档缺少字段时按默认值读取
+        let loaded: GameProfile =
+            serde_json::from_str(r#"{"app_id": 480, "upload_virtual_root": "saves"}"#).unwrap();
+        assert_eq!(loaded.upload_virtual_root.as_deref(), Some("saves"));
+        assert_eq!(loaded.backup_interval_hours, 0);
+        let json = serde_json::to_string(&profile).unwrap();
+        assert_eq!(serde_json::from_str::<GameProfile>(&json).unwrap(), profile);
+    }
+
+    #[test]
+    fn backs_up_before_migrating() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path();
+        let path = dir.join("config.toml");
+        std::fs::write(&path, V1_3_5_SAVED).unwrap();
+
+        let config = load_config_from(&path).unwrap();
+        assert_eq!(config.schema_version, CONFIG_SCHEMA_VERSION);
+
+        let backups: Vec<PathBuf> = std::fs::read_dir(dir)
+            .unwrap()
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p != &path)
+            .collect();
+        assert_eq!(backups.len(), 1);
+        let name = backups[0]
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+        assert!(name.starts_with("config.v1.") && name.ends_with(".toml.bak"));
+        assert_eq!(std::fs::read_to_string(&backups[0]).unwrap(), V1_3_5_SAVED);
+
+        // 写回的文件已是当前版本，保留了全部设置，再次加载不会重复备份
+        let (rewritten, from) = parse_config(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(from, CONFIG_SCHEMA_VERSION);
+        assert_eq!(rewritten.symlinks.len(), 1);
+        assert!(!rewritten.logging.enabled);
+        load_config_from(&path).unwrap();
+        assert_eq!(std::fs::read_dir(dir).unwrap().count(), 2);
+    }
+}
@@ -1,10 +1,24 @@
 use anyhow::{Result, anyhow};
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
+use crate::config::{DEFAULT_SOURCE_ORDER, DownloadSource};
+use crate::path_resolver::get_root_type_name;
 use crate::steam_api::CloudFile;
+use serde::{Deserialize, Serialize};
+
+// 正在下载的单个文件进度
+#[derive(Debug, Clone)]
+pub struct FileProgress {
+    pub task: usize, // 任务序号，不同 root 下的同名文件靠它区分
+    pub name: String,
+    pub downloaded: u64,
+    pub total: u64,
+}
 
 // 下载进度
 #[derive(Debug, Clone)]
@@ -13,6 +27,9 @@ pub struct DownloadProgress {
     pub completed_files: usize,
     pub current_file: String,
     pub failed_files: Vec<(String, String)>,
+    pub bytes_total: u64,
+    pub bytes_downloaded: u64,
+    pub active_files: Vec<FileProgress>,
 }
 
 impl DownloadProgress {
@@ -22,15 +39,44 @@ impl DownloadProgress {
             completed_files: 0,
             current_file: String::new(),
             failed_files: Vec::new(),
+            bytes_total: 0,
+            bytes_downloaded: 0,
+            active_files: Vec::new(),
         }
     }
 
+    // 已知总大小时按字节计算，否则按文件数
     pub fn percent(&self) -> f32 {
+        if self.bytes_total > 0 {
+            return (self.bytes_downloaded.min(self.bytes_total) as f32 / self.bytes_total as f32)
+                * 100.0;
+        }
         if self.total_files == 0 {
             return 100.0;
         }
         (self.completed_files as f32 / self.total_files as f32) * 100.0
     }
+
+    fn update_file(&mut self, task: usize, downloaded: u64) {
+        if let Some(file) = self.active_files.iter_mut().find(|f| f.task == task) {
+            self.bytes_downloaded += downloaded.saturating_sub(file.downloaded);
+            file.downloaded = downloaded;
+        }
+    }
+
+    // 文件结束时移出活动列表，并把已计字节校正为文件大小（失败时扣除）
+    fn finish_file(&mut self, task: usize, size: u64, success: bool) {
+        if let Some(pos) = self.active_files.iter().position(|f| f.task == task) {
+            let file = self.active_files.remove(pos);
+            self.bytes_downloaded = self.bytes_downloaded.saturating_sub(file.downloaded);
+        }
+        if success {
+            self.bytes_downloaded += size;
+        } else {
+            self.bytes_total = self.bytes_total.saturating_sub(size);
+        }
+        self.completed_files += 1;
+    }
 }
 
 // 下载结果
@@ -41,6 +87,98 @@ pub struct DownloadResult {
     pub total_files: usize,
     pub success_count: usize,
     pub failed_files: Vec<(String, String)>,
+    // 每个成功文件实际使用的来源
+    pub sources: Vec<(String, DownloadSource)>,
+}
+
+impl DownloadResult {
+    // 使用指定来源的文件数
+    pub fn source_count(&self, source: DownloadSource) -> usize {
+        self.sources.iter().filter(|(_, s)| *s == source).count()
+    }
+}
+
+// 下载清单文件名
+pub const MANIFEST_FILE_NAME: &str = "download_manifest.json";
+
+// 下载清单（记录每个文件的云端路径和时间戳，便于重新上传或对比）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadManifest {
+    pub version: u32,
+    pub app_id: u32,
+    pub download_time: String, // ISO 8601 格式
+    pub files: Vec<DownloadManifestEntry>,
+}
+
+// 下载清单条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadManifestEntry {
+    pub name: String,          // 云端文件名
+    pub relative_path: String, // 相对下载目录的路径（/ 分隔）
+    pub size: u64,
+    pub sha1: Option<String>,
+    pub timestamp: i64, // 云端修改时间（Unix 秒）
+    pub root_index: u32,
+    pub root_name: String,
+    pub source: DownloadSource,
+}
+
+impl DownloadManifest {
+    fn build(
+        app_id: u32,
+        base_dir: &Path,
+        tasks: &[DownloadTask],
+        sources: &[(usize, DownloadSource)],
+    ) -> Self {
+        let files = sources
+            .iter()
+            .map(|&(index, source)| {
+                let task = &tasks[index];
+                let relative_path = task
+                    .target_path
+                    .strip_prefix(base_dir)
+                    .unwrap_or(&task.target_path)
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                let sha1 = std::fs::read(&task.target_path)
+                    .ok()
+                    .map(|data| crate::file_manager::sha1_hex(&data));
+                DownloadManifestEntry {
+                    name: task.file.name.clone(),
+                    relative_path,
+                    size: task.file.size,
+                    sha1,
+                    timestamp: task.file.timestamp.timestamp(),
+                    root_index: task.file.root,
+                    root_name: get_root_type_name(task.file.root).to_string(),
+                    source,
+                }
+            })
+            .collect();
+
+        Self {
+            version: 1,
+            app_id,
+            download_time: chrono::Local::now().to_rfc3339(),
+            files,
+        }
+    }
+
+    fn save(&self, base_dir: &Path) -> Result<PathBuf> {
+        let path = base_dir.join(MANIFEST_FILE_NAME);
+        let content = serde_json::to_string_pretty(self)?;
+        let ctx = DownloadContext {
+            cancel_flag: None,
+            rate_limiter: None,
+            on_bytes: None,
+            source_order: &DEFAULT_SOURCE_ORDER,
+            strict: false,
+        };
+        write_atomic(&path, content.as_bytes(), &ctx)?;
+        Ok(path)
+    }
 }
 
 // 下载任务
@@ -50,12 +188,76 @@ pub struct DownloadTask {
     pub local_save_paths: Vec<(String, PathBuf)>,
 }
 
+// 全局限速器（所有下载线程共享一个令牌桶）
+pub struct RateLimiter {
+    bytes_per_sec: u64,
+    next_free: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    pub fn new(bytes_per_sec: u64) -> Self {
+        Self {
+            bytes_per_sec: bytes_per_sec.max(1),
+            next_free: Mutex::new(Instant::now()),
+        }
+    }
+
+    // 按配置创建，0 表示不限速
+    pub fn from_kbps(kbps: u64) -> Option<Self> {
+        (kbps > 0).then(|| Self::new(kbps * 1024))
+    }
+
+    // 为 bytes 预约发送时间，返回需要等待的时长
+    fn reserve(&self, now: Instant, bytes: u64) -> Duration {
+        let mut next_free = match self.next_free.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let start = (*next_free).max(now);
+        *next_free = start + Duration::from_secs_f64(bytes as f64 / self.bytes_per_sec as f64);
+        start - now
+    }
+
+    pub fn acquire(&self, bytes: u64) {
+        let wait = self.reserve(Instant::now(), bytes);
+        if !wait.is_zero() {
+            std::thread::sleep(wait);
+        }
+    }
+}
+
+// 单个文件下载时的共享上下文
+pub struct DownloadContext<'a> {
+    pub cancel_flag: Option<&'a AtomicBool>,
+    pub rate_limiter: Option<&'a RateLimiter>,
+    pub on_bytes: Option<&'a dyn Fn(u64)>,
+    pub source_order: &'a [DownloadSource],
+    pub strict: bool,
+}
+
+impl DownloadContext<'_> {
+    fn is_cancelled(&self) -> bool {
+        self.cancel_flag
+            .map(|f| f.load(Ordering::Relaxed))
+            .unwrap_or(false)
+    }
+}
+
+// 进度上报最小间隔，避免每个数据块都发送一次
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
 // 批量下载执行器
 pub struct BatchDownloader {
     tasks: Vec<DownloadTask>,
     cancel_flag: Arc<AtomicBool>,
     progress_tx: Option<Sender<DownloadProgress>>,
     steam_manager: Option<Arc<std::sync::Mutex<crate::steam_worker::SteamWorkerManager>>>,
+    concurrency: usize,
+    rate_limiter: Option<RateLimiter>,
+    source_order: Vec<DownloadSource>,
+    strict: bool,
+    // 清单写入目录和 app_id
+    manifest: Option<(PathBuf, u32)>,
 }
 
 impl BatchDownloader {
@@ -65,6 +267,11 @@ impl BatchDownloader {
             cancel_flag: Arc::new(AtomicBool::new(false)),
             progress_tx: None,
             steam_manager: None,
+            concurrency: 1,
+            rate_limiter: None,
+            source_order: DEFAULT_SOURCE_ORDER.to_vec(),
+            strict: false,
+            manifest: None,
         }
     }
 
@@ -86,6 +293,51 @@ impl BatchDownloader {
         self
     }
 
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn with_rate_limit(mut self, kbps: u64) -> Self {
+        self.rate_limiter = RateLimiter::from_kbps(kbps);
+        self
+    }
+
+    // 设置来源顺序，重复项只保留第一次出现，为空时使用默认顺序
+    pub fn with_source_order(mut self, order: &[DownloadSource]) -> Self {
+        let mut deduped: Vec<DownloadSource> = Vec::new();
+        for &source in order {
+            if !deduped.contains(&source) {
+                deduped.push(source);
+            }
+        }
+        self.source_order = if deduped.is_empty() {
+            DEFAULT_SOURCE_ORDER.to_vec()
+        } else {
+            deduped
+        };
+        self
+    }
+
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    // 下载完成后在 base_dir 写入清单
+    pub fn with_manifest(mut self, base_dir: PathBuf, app_id: u32) -> Self {
+        self.manifest = Some((base_dir, app_id));
+        self
+    }
+
+    // 按配置文件设置并发数、限速和来源
+    pub fn with_config(self, config: &crate::config::DownloadConfig) -> Self {
+        self.with_concurrency(config.concurrency)
+            .with_rate_limit(config.rate_limit_kbps)
+            .with_source_order(&config.source_order)
+            .with_strict(config.strict)
+    }
+
     pub fn is_cancelled(&self) -> bool {
         self.cancel_flag.load(Ordering::Relaxed)
     }
@@ -99,69 +351,248 @@ impl BatchDownloader {
     // 执行批量下载
     pub fn execute(self) -> DownloadResult {
         let total_files = self.tasks.len();
-        let mut progress = DownloadProgress::new(total_files);
-        let mut success_count = 0;
-        let target_dir = self
+        let target_dir = match &self.manifest {
+            Some((base_dir, _)) => base_dir.clone(),
+            None => self
+                .tasks
+                .first()
+                .and_then(|t| t.target_path.parent())
+                .map(|p| p.to_path_buf())
+                .unwrap_or_default(),
+        };
+
+        let mut initial = DownloadProgress::new(total_files);
+        initial.bytes_total = self.tasks.iter().map(|t| t.file.size).sum();
+        self.send_progress(&initial);
+
+        let progress = Mutex::new(initial);
+        let last_sent = Mutex::new(Instant::now());
+        let next_task = AtomicUsize::new(0);
+        let sources = Mutex::new(Vec::new());
+
+        // 只有存在 CDP 链接时才开多个线程，Steam API 读取经由同一个 Worker 串行执行
+        let has_cdp = self
             .tasks
-            .first()
-            .and_then(|t| t.target_path.parent())
-            .map(|p| p.to_path_buf())
-            .unwrap_or_default();
-
-        for task in &self.tasks {
-            // 检查取消
-            if self.is_cancelled() {
-                tracing::info!("下载已取消");
-                break;
-            }
+            .iter()
+            .any(|t| t.file.root_description.starts_with("CDP:"));
+        let workers = if has_cdp {
+            self.concurrency.min(total_files).max(1)
+        } else {
+            1
+        };
+        tracing::info!("开始批量下载: {} 个文件, {} 个线程", total_files, workers);
 
-            progress.current_file = task.file.name.clone();
-            self.send_progress(&progress);
-
-            // 执行下载
-            let result = download_file_full(
-                &task.file,
-                &task.target_path,
-                &task.local_save_paths,
-                self.steam_manager.as_ref(),
-            );
-
-            match result {
-                Ok(_) => {
-                    success_count += 1;
-                    tracing::debug!("下载成功: {}", task.file.name);
-                }
-                Err(e) => {
-                    let err_msg = e.to_string();
-                    tracing::warn!("下载失败: {} - {}", task.file.name, err_msg);
-                    progress
-                        .failed_files
-                        .push((task.file.name.clone(), err_msg));
-                }
+        let lock_progress = || match progress.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        std::thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|| {
+                    loop {
+                        if self.is_cancelled() {
+                            break;
+                        }
+                        let index = next_task.fetch_add(1, Ordering::Relaxed);
+                        let task = match self.tasks.get(index) {
+                            Some(task) => task,
+                            None => break,
+                        };
+
+                        {
+                            let mut p = lock_progress();
+                            p.current_file = task.file.name.clone();
+                            p.active_files.push(FileProgress {
+                                task: index,
+                                name: task.file.name.clone(),
+                                downloaded: 0,
+                                total: task.file.size,
+                            });
+                            self.send_progress(&p);
+                        }
+
+                        let on_bytes = |downloaded: u64| {
+                            let mut p = lock_progress();
+                            p.update_file(index, downloaded);
+                            if let Ok(mut last) = last_sent.try_lock()
+                                && last.elapsed() >= PROGRESS_INTERVAL
+                            {
+                                *last = Instant::now();
+                                self.send_progress(&p);
+                            }
+                        };
+                        let ctx = DownloadContext {
+                            cancel_flag: Some(&self.cancel_flag),
+                            rate_limiter: self.rate_limiter.as_ref(),
+                            on_bytes: Some(&on_bytes),
+                            source_order: &self.source_order,
+                            strict: self.strict,
+                        };
+
+                        let result = download_file_with(
+                            &task.file,
+                            &task.target_path,
+                            &task.local_save_paths,
+                            self.steam_manager.as_ref(),
+                            &ctx,
+                        );
+
+                        let mut p = lock_progress();
+                        match result {
+                            Ok(source) => {
+                                if let Ok(mut sources) = sources.lock() {
+                                    sources.push((index, source));
+                                }
+                                tracing::debug!(
+                                    "下载成功: {} ({})",
+                                    task.file.name,
+                                    source_name(source)
+                                );
+                                p.finish_file(index, task.file.size, true);
+                            }
+                            Err(e) => {
+                                let err_msg = e.to_string();
+                                tracing::warn!("下载失败: {} - {}", task.file.name, err_msg);
+                                p.failed_files.push((task.file.name.clone(), err_msg));
+                                p.finish_file(index, task.file.size, false);
+                            }
+                        }
+                        self.send_progress(&p);
+                    }
+                });
             }
+        });
 
-            progress.completed_files += 1;
-            self.send_progress(&progress);
+        let cancelled = self.is_cancelled();
+        if cancelled {
+            tracing::info!("下载已取消");
         }
+        let progress = match progress.into_inner() {
+            Ok(p) => p,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let mut sources = match sources.into_inner() {
+            Ok(s) => s,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        sources.sort_by_key(|(index, _)| *index);
 
-        let cancelled = self.is_cancelled();
+        if let Some((base_dir, app_id)) = &self.manifest
+            && !sources.is_empty()
+        {
+            let manifest = DownloadManifest::build(*app_id, base_dir, &self.tasks, &sources);
+            match manifest.save(base_dir) {
+                Ok(path) => tracing::info!("下载清单已写入: {}", path.display()),
+                Err(e) => tracing::warn!("写入下载清单失败: {}", e),
+            }
+        }
+
+        let sources: Vec<(String, DownloadSource)> = sources
+            .into_iter()
+            .map(|(index, source)| (self.tasks[index].file.name.clone(), source))
+            .collect();
         DownloadResult {
             success: progress.failed_files.is_empty() && !cancelled,
             target_dir,
             total_files,
-            success_count,
+            success_count: sources.len(),
             failed_files: progress.failed_files,
+            sources,
+        }
+    }
+}
+
+// 下载时使用的临时文件路径（与目标文件同目录，保证 rename 是原子的）。
+// 每次调用带不同的序号，并发写同一目标时各自的临时文件互不覆盖
+fn temp_path_for(target_path: &Path) -> PathBuf {
+    static NEXT_TEMP_ID: AtomicUsize = AtomicUsize::new(0);
+    let mut name = target_path
+        .file_name()
+        .map(|n| n.to_os_string())
+        .unwrap_or_default();
+    name.push(format!(
+        ".{}.{}.part",
+        std::process::id(),
+        NEXT_TEMP_ID.fetch_add(1, Ordering::Relaxed)
+    ));
+    target_path.with_file_name(name)
+}
+
+// 把数据流写入临时文件，完成后重命名为目标文件
+fn stream_to_file(reader: &mut dyn Read, target_path: &Path, ctx: &DownloadContext) -> Result<u64> {
+    let tmp_path = temp_path_for(target_path);
+    let result = (|| -> Result<u64> {
+        let mut out = std::io::BufWriter::new(std::fs::File::create(&tmp_path)?);
+        let mut buf = vec![0u8; 64 * 1024];
+        let mut written = 0u64;
+        loop {
+            if ctx.is_cancelled() {
+                return Err(anyhow!("下载已取消"));
+            }
+            let n = reader
+                .read(&mut buf)
+                .map_err(|e| anyhow!("读取响应失败: {}", e))?;
+            if n == 0 {
+                break;
+            }
+            if let Some(limiter) = ctx.rate_limiter {
+                limiter.acquire(n as u64);
+            }
+            out.write_all(&buf[..n])?;
+            written += n as u64;
+            if let Some(on_bytes) = ctx.on_bytes {
+                on_bytes(written);
+            }
+        }
+        out.flush()?;
+        Ok(written)
+    })();
+
+    match result {
+        Ok(written) => {
+            std::fs::rename(&tmp_path, target_path)
+                .map_err(|e| anyhow!("重命名临时文件失败: {}", e))?;
+            Ok(written)
+        }
+        Err(e) => {
+            let _ = std::fs::remove_file(&tmp_path);
+            Err(e)
         }
     }
 }
 
-// 完整下载单个文件（CDP -> Steam API -> 本地复制）
+// 内存中的数据同样经临时文件落盘
+fn write_atomic(target_path: &Path, data: &[u8], ctx: &DownloadContext) -> Result<()> {
+    stream_to_file(&mut std::io::Cursor::new(data), target_path, ctx)?;
+    Ok(())
+}
+
+// 完整下载单个文件（按默认顺序 CDP -> Steam API -> 本地复制）
 pub fn download_file_full(
     file: &CloudFile,
     target_path: &Path,
     local_save_paths: &[(String, PathBuf)],
     steam_manager: Option<&Arc<std::sync::Mutex<crate::steam_worker::SteamWorkerManager>>>,
-) -> Result<()> {
+) -> Result<DownloadSource> {
+    let ctx = DownloadContext {
+        cancel_flag: None,
+        rate_limiter: None,
+        on_bytes: None,
+        source_order: &DEFAULT_SOURCE_ORDER,
+        strict: false,
+    };
+    download_file_with(file, target_path, local_save_paths, steam_manager, &ctx)
+}
+
+// 按上下文中的来源顺序下载，返回实际使用的来源
+pub fn download_file_with(
+    file: &CloudFile,
+    target_path: &Path,
+    local_save_paths: &[(String, PathBuf)],
+    steam_manager: Option<&Arc<std::sync::Mutex<crate::steam_worker::SteamWorkerManager>>>,
+    ctx: &DownloadContext,
+) -> Result<DownloadSource> {
     // 创建父目录
     if let Some(parent) = target_path.parent()
         && !parent.exists()
@@ -169,81 +600,368 @@ pub fn download_file_full(
         std::fs::create_dir_all(parent)?;
     }
 
-    // 使用 CDP URL 下载
-    if file.root_description.starts_with("CDP:") {
-        let content = &file.root_description[4..];
-        let url = content.split('|').next().unwrap_or("");
+    let mut errors = Vec::new();
+    let mut skipped_local = false;
+    for (index, &source) in ctx.source_order.iter().enumerate() {
+        // 严格模式下本地副本可能过期，只有排在首位时才使用，其余位置跳过并继续尝试后续云端来源
+        if ctx.strict && source == DownloadSource::Local && index > 0 {
+            skipped_local = true;
+            continue;
+        }
 
-        if !url.is_empty() {
-            tracing::debug!("CDP 下载: {} -> {}", file.name, target_path.display());
+        // None 表示该来源对此文件不可用
+        let attempt = match source {
+            DownloadSource::Cdp => download_from_cdp(file, target_path, ctx),
+            DownloadSource::SteamApi => download_from_steam(file, target_path, steam_manager, ctx),
+            DownloadSource::Local => copy_from_local(file, target_path, local_save_paths, ctx),
+        };
 
-            match ureq::get(url).call() {
-                Ok(resp) => {
-                    let mut data = Vec::new();
-                    std::io::Read::read_to_end(&mut resp.into_body().into_reader(), &mut data)
-                        .map_err(|e| anyhow!("读取响应失败: {}", e))?;
-                    std::fs::write(target_path, &data)?;
-                    return Ok(());
-                }
-                Err(e) => {
-                    tracing::warn!("CDP 下载失败，尝试其他方式: {}", e);
+        match attempt {
+            None => continue,
+            Some(Ok(())) => {
+                set_cloud_mtime(target_path, file);
+                return Ok(source);
+            }
+            Some(Err(e)) => {
+                if ctx.is_cancelled() {
+                    return Err(e);
                 }
+                tracing::warn!("{} 下载失败: {} - {}", source_name(source), file.name, e);
+                errors.push(format!("{}: {}", source_name(source), e));
             }
         }
     }
 
-    // Steam API 下载
-    if file.is_persisted
-        && let Some(manager) = steam_manager
-    {
-        tracing::debug!("使用 Steam API 下载: {}", file.name);
-        if let Ok(mut mgr) = manager.lock()
-            && let Ok(data) = mgr.read_file(&file.name)
-        {
-            std::fs::write(target_path, &data)?;
-            return Ok(());
+    if skipped_local {
+        let mut message = format!("严格模式下云端来源不可用，不回退到本地副本: {}", file.name);
+        if !errors.is_empty() {
+            message.push_str(&format!(" ({})", errors.join("; ")));
         }
+        Err(anyhow!(message))
+    } else if errors.is_empty() {
+        Err(anyhow!("文件既不在云端也无法从本地找到"))
+    } else {
+        Err(anyhow!("所有下载来源均失败: {}", errors.join("; ")))
     }
+}
 
-    // 从本地复制
-    if file.exists {
-        let file_root_desc = if file.root_description.starts_with("CDP:") {
-            file.root_description
-                .split('|')
-                .nth(1)
-                .unwrap_or(&file.root_description)
-        } else {
-            &file.root_description
-        };
+// 把文件修改时间设为云端时间戳，失败只记录日志
+fn set_cloud_mtime(target_path: &Path, file: &CloudFile) {
+    let mtime = std::time::SystemTime::from(file.timestamp);
+    let result = std::fs::File::options()
+        .write(true)
+        .open(target_path)
+        .and_then(|f| f.set_modified(mtime));
+    if let Err(e) = result {
+        tracing::warn!("设置文件时间失败 {}: {}", target_path.display(), e);
+    }
+}
 
-        for (desc, base_path) in local_save_paths {
-            if desc == file_root_desc || file_root_desc.contains(desc) {
-                let local_file_path = base_path.join(&file.name);
-                if local_file_path.exists() {
-                    tracing::debug!("从本地复制: {} -> {:?}", file.name, local_file_path);
-                    let data = std::fs::read(&local_file_path)?;
-                    std::fs::write(target_path, &data)?;
-                    return Ok(());
-                }
-            }
-        }
+// 日志中使用的来源名称
+fn source_name(source: DownloadSource) -> &'static str {
+    match source {
+        DownloadSource::Cdp => "CDP",
+        DownloadSource::SteamApi => "Steam API",
+        DownloadSource::Local => "本地复制",
+    }
+}
+
+// 使用 CDP URL 下载
+fn download_from_cdp(
+    file: &CloudFile,
+    target_path: &Path,
+    ctx: &DownloadContext,
+) -> Option<Result<()>> {
+    let content = file.root_description.strip_prefix("CDP:")?;
+    let url = content.split('|').next().unwrap_or("");
+    if url.is_empty() {
+        return None;
+    }
 
-        // 直接匹配文件名
-        for (_desc, base_path) in local_save_paths {
-            let local_file_path = base_path.join(&file.name);
-            if local_file_path.exists() {
-                tracing::debug!("从本地复制: {} -> {:?}", file.name, local_file_path);
-                let data = std::fs::read(&local_file_path)?;
-                std::fs::write(target_path, &data)?;
-                return Ok(());
+    tracing::debug!("CDP 下载: {} -> {}", file.name, target_path.display());
+    let mut request = ureq::get(url);
+    let remote_storage = crate::config::get_config().remote_storage;
+    if let Some(cookie) = crate::web_remotestorage::download_cookie(&remote_storage, url) {
+        request = request.header("Cookie", &cookie);
+    }
+    Some(match request.call() {
+        Ok(resp) => {
+            let content_type = resp
+                .headers()
+                .get("Content-Type")
+                .and_then(|v| v.to_str().ok());
+            if is_html_content_type(content_type) {
+                Err(anyhow!(
+                    "服务器返回了网页而不是文件，会话 Cookie 可能已失效"
+                ))
+            } else {
+                let mut reader = resp.into_body().into_reader();
+                stream_to_file(&mut reader, target_path, ctx).map(|_| ())
             }
         }
+        Err(e) => Err(anyhow!("请求失败: {}", e)),
+    })
+}
+
+// 存档文件不会以 text/html 返回，收到网页通常是登录页
+fn is_html_content_type(content_type: Option<&str>) -> bool {
+    content_type.is_some_and(|v| {
+        v.split(';')
+            .next()
+            .is_some_and(|mime| mime.trim().eq_ignore_ascii_case("text/html"))
+    })
+}
+
+// Steam API 下载
+fn download_from_steam(
+    file: &CloudFile,
+    target_path: &Path,
+    steam_manager: Option<&Arc<std::sync::Mutex<crate::steam_worker::SteamWorkerManager>>>,
+    ctx: &DownloadContext,
+) -> Option<Result<()>> {
+    if !file.is_persisted {
+        return None;
+    }
+    let manager = steam_manager?;
+
+    tracing::debug!("使用 Steam API 下载: {}", file.name);
+    let data = match manager.lock() {
+        Ok(mut mgr) => mgr.read_file(&file.name),
+        Err(e) => Err(anyhow!("Steam 管理器锁定失败: {}", e)),
+    };
+    Some(data.and_then(|data| write_atomic(target_path, &data, ctx)))
+}
+
+// 从本地复制
+fn copy_from_local(
+    file: &CloudFile,
+    target_path: &Path,
+    local_save_paths: &[(String, PathBuf)],
+    ctx: &DownloadContext,
+) -> Option<Result<()>> {
+    if !file.exists {
+        return None;
     }
 
-    Err(anyhow!("文件既不在云端也无法从本地找到"))
+    let file_root_desc = if file.root_description.starts_with("CDP:") {
+        file.root_description
+            .split('|')
+            .nth(1)
+            .unwrap_or(&file.root_description)
+    } else {
+        &file.root_description
+    };
+
+    // 先按 root 描述匹配，再直接匹配文件名
+    let local_file_path = local_save_paths
+        .iter()
+        .filter(|(desc, _)| desc == file_root_desc || file_root_desc.contains(desc.as_str()))
+        .chain(local_save_paths.iter())
+        .map(|(_, base_path)| base_path.join(&file.name))
+        .find(|path| path.exists())?;
+
+    tracing::debug!("从本地复制: {} -> {:?}", file.name, local_file_path);
+    Some(
+        std::fs::File::open(&local_file_path)
+            .map_err(|e| anyhow!("打开本地文件失败: {}", e))
+            .and_then(|mut source| stream_to_file(&mut source, target_path, ctx).map(|_| ())),
+    )
 }
 
 // 仅 CDP 下载（用于备份）
 pub fn download_single_file(file: &CloudFile, target_path: &Path) -> Result<()> {
-    download_file_full(file, target_path, &[], None)
+    download_file_full(file, target_path, &[], None).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_html_responses() {
+        assert!(is_html_content_type(Some("text/html; charset=UTF-8")));
+        assert!(is_html_content_type(Some("Text/HTML")));
+        assert!(!is_html_content_type(Some("application/octet-stream")));
+        assert!(!is_html_content_type(None));
+    }
+
+    #[test]
+    fn rate_limiter_spaces_reservations() {
+        let limiter = RateLimiter::new(1000);
+        let now = Instant::now();
+        *limiter.next_free.lock().unwrap() = now;
+        assert_eq!(limiter.reserve(now, 500), Duration::ZERO);
+        assert_eq!(limiter.reserve(now, 500), Duration::from_millis(500));
+        assert_eq!(limiter.reserve(now, 1000), Duration::from_millis(1000));
+        // 空闲后不会累积额度
+        let later = now + Duration::from_secs(10);
+        assert_eq!(limiter.reserve(later, 1000), Duration::ZERO);
+    }
+
+    #[test]
+    fn streams_to_temp_file_then_renames() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let target = dir.path().join("slot1.sav");
+        let seen = AtomicUsize::new(0);
+        let on_bytes = |n: u64| seen.store(n as usize, Ordering::Relaxed);
+        let ctx = DownloadContext {
+            cancel_flag: None,
+            rate_limiter: None,
+            on_bytes: Some(&on_bytes),
+            source_order: &DEFAULT_SOURCE_ORDER,
+            strict: false,
+        };
+
+        // 目录中只剩目标文件，没有遗留的临时文件
+        let only_target = || std::fs::read_dir(dir.path()).unwrap().count() == 1;
+
+        let data = vec![7u8; 200 * 1024];
+        write_atomic(&target, &data, &ctx).unwrap();
+        assert_eq!(std::fs::read(&target).unwrap(), data);
+        assert!(only_target());
+        assert_eq!(seen.load(Ordering::Relaxed), data.len());
+
+        // 取消时不留下临时文件，也不覆盖已有文件
+        let cancel = AtomicBool::new(true);
+        let ctx = DownloadContext {
+            cancel_flag: Some(&cancel),
+            rate_limiter: None,
+            on_bytes: None,
+            source_order: &DEFAULT_SOURCE_ORDER,
+            strict: false,
+        };
+        assert!(write_atomic(&target, b"new", &ctx).is_err());
+        assert!(only_target());
+        assert_eq!(std::fs::read(&target).unwrap().len(), data.len());
+    }
+
+    #[test]
+    fn temp_paths_are_unique_per_call() {
+        let target = Path::new("/saves/slot1.sav");
+        let first = temp_path_for(target);
+        let second = temp_path_for(target);
+        assert_ne!(first, second);
+        assert_eq!(first.parent(), target.parent());
+        assert!(first.to_string_lossy().ends_with(".part"));
+    }
+
+    // 本地目录中有一份旧副本的云端文件
+    fn stale_local_fixture(
+        root_description: &str,
+    ) -> (tempfile::TempDir, Vec<(String, PathBuf)>, CloudFile) {
+        let dir = tempfile::TempDir::new().unwrap();
+        let local_dir = dir.path().join("local");
+        std::fs::create_dir_all(&local_dir).unwrap();
+        std::fs::write(local_dir.join("slot1.sav"), b"stale").unwrap();
+        let local_paths = vec![("WinAppDataLocal".to_string(), local_dir)];
+
+        let file = CloudFile {
+            name: "slot1.sav".to_string(),
+            size: 5,
+            timestamp: chrono::DateTime::from_timestamp(1_600_000_000, 0)
+                .unwrap()
+                .with_timezone(&chrono::Local),
+            is_persisted: true,
+            exists: true,
+            root: 0,
+            root_description: root_description.to_string(),
+        };
+        (dir, local_paths, file)
+    }
+
+    fn source_ctx(source_order: &[DownloadSource], strict: bool) -> DownloadContext<'_> {
+        DownloadContext {
+            cancel_flag: None,
+            rate_limiter: None,
+            on_bytes: None,
+            source_order,
+            strict,
+        }
+    }
+
+    #[test]
+    fn strict_mode_tries_remote_sources_before_refusing_local_copy() {
+        // 无法连接的地址，CDP 必定失败
+        let (dir, local_paths, file) =
+            stale_local_fixture("CDP:http://127.0.0.1:1/slot1.sav|WinAppDataLocal");
+        let target = dir.path().join("out").join("slot1.sav");
+
+        let ctx = source_ctx(&DEFAULT_SOURCE_ORDER, false);
+        let source = download_file_with(&file, &target, &local_paths, None, &ctx).unwrap();
+        assert_eq!(source, DownloadSource::Local);
+        assert_eq!(std::fs::read(&target).unwrap(), b"stale");
+
+        // 严格模式：CDP 失败后仍尝试 Steam API（未启动的 Worker 必定失败），最后拒绝本地副本
+        std::fs::remove_file(&target).unwrap();
+        let steam = Arc::new(std::sync::Mutex::new(
+            crate::steam_worker::SteamWorkerManager::new(),
+        ));
+        let ctx = source_ctx(&DEFAULT_SOURCE_ORDER, true);
+        let err = download_file_with(&file, &target, &local_paths, Some(&steam), &ctx).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("本地副本"));
+        assert!(message.contains("CDP:"));
+        assert!(message.contains("Steam API:"));
+        assert!(message.find("CDP:") < message.find("Steam API:"));
+        assert!(!target.exists());
+    }
+
+    #[test]
+    fn strict_mode_tries_sources_after_local_in_the_middle() {
+        let (dir, local_paths, file) =
+            stale_local_fixture("CDP:http://127.0.0.1:1/slot1.sav|WinAppDataLocal");
+        let target = dir.path().join("out").join("slot1.sav");
+        let steam = Arc::new(std::sync::Mutex::new(
+            crate::steam_worker::SteamWorkerManager::new(),
+        ));
+
+        // 本地复制排在中间：严格模式跳过它，继续尝试后面的 Steam API
+        let order = [
+            DownloadSource::Cdp,
+            DownloadSource::Local,
+            DownloadSource::SteamApi,
+        ];
+        let ctx = source_ctx(&order, true);
+        let err = download_file_with(&file, &target, &local_paths, Some(&steam), &ctx).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("本地副本"));
+        assert!(message.contains("CDP:"));
+        assert!(message.contains("Steam API:"));
+        assert!(!target.exists());
+    }
+
+    #[test]
+    fn strict_mode_skips_local_copy_when_remote_sources_are_unavailable() {
+        // 没有 CDP 链接，也没有 Steam 连接：两个云端来源都不适用
+        let (dir, local_paths, file) = stale_local_fixture("WinAppDataLocal");
+        let target = dir.path().join("out").join("slot1.sav");
+
+        let ctx = source_ctx(&DEFAULT_SOURCE_ORDER, true);
+        let err = download_file_with(&file, &target, &local_paths, None, &ctx).unwrap_err();
+        assert!(err.to_string().contains("本地副本"));
+        assert!(!target.exists());
+
+        // 用户把本地复制排在首位时，严格模式仍可使用
+        let order = [DownloadSource::Local, DownloadSource::Cdp];
+        let ctx = source_ctx(&order, true);
+        let source = download_file_with(&file, &target, &local_paths, None, &ctx).unwrap();
+        assert_eq!(source, DownloadSource::Local);
+    }
+
+    #[test]
+    fn downloaded_files_keep_the_cloud_mtime() {
+        let (dir, local_paths, file) = stale_local_fixture("WinAppDataLocal");
+        let target = dir.path().join("out").join("slot1.sav");
+
+        let ctx = source_ctx(&DEFAULT_SOURCE_ORDER, false);
+        download_file_with(&file, &target, &local_paths, None, &ctx).unwrap();
+        let mtime = std::fs::metadata(&target).unwrap().modified().unwrap();
+        assert_eq!(
+            mtime
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            1_600_000_000
+        );
+    }
 }
@@ -142,16 +142,13 @@ impl FileService {
         mut files: Vec<CloudFile>,
         app_id: u32,
     ) -> Result<Vec<CloudFile>> {
-        if !crate::cdp_client::CdpClient::is_cdp_running() {
+        if !crate::web_remotestorage::is_available() {
             return Ok(files);
         }
 
-        tracing::info!("尝试通过 CDP 补充文件信息");
+        tracing::info!("尝试通过网页版云存储补充文件信息");
 
-        if let Ok(mut client) =
-            crate::cdp_client::CdpClient::connect_for(crate::cdp_client::CdpTarget::FileList)
-            && let Ok(cdp_files) = client.fetch_game_files(app_id)
-        {
+        if let Ok(cdp_files) = crate::web_remotestorage::fetch_game_files(app_id) {
             tracing::info!(count = cdp_files.len(), "CDP 返回文件");
 
             let file_map: std::collections::HashMap<String, usize> = files
@@ -256,21 +253,21 @@ impl FileService {
             return Err(anyhow!("未设置 App ID"));
         }
 
-        if !crate::cdp_client::CdpClient::is_cdp_running() {
-            return Err(anyhow!(
-                "CDP 服务未运行，请确保 Steam 客户端已打开并访问云存储页面"
-            ));
+        if !crate::web_remotestorage::is_available() {
+            return Err(match crate::config::get_config().remote_storage.provider {
+                crate::config::RemoteStorageProvider::Cdp => {
+                    anyhow!("CDP 服务未运行，请确保 Steam 客户端已打开并访问云存储页面")
+                }
+                crate::config::RemoteStorageProvider::Web => {
+                    anyhow!("未设置网页版会话 Cookie，请在设置中填写")
+                }
+            });
         }
 
-        tracing::info!("通过 CDP 获取系统级 App ID {} 的文件列表", app_id);
-
-        let mut client =
-            crate::cdp_client::CdpClient::connect_for(crate::cdp_client::CdpTarget::FileList)
-                .map_err(|e| anyhow!("CDP 连接失败: {}", e))?;
+        tracing::info!("通过网页版云存储获取系统级 App ID {} 的文件列表", app_id);
 
-        let cdp_files = client
-            .fetch_game_files(app_id)
-            .map_err(|e| anyhow!("CDP 获取文件失败: {}", e))?;
+        let cdp_files = crate::web_remotestorage::fetch_game_files(app_id)
+            .map_err(|e| anyhow!("获取网页版云存储文件失败: {}", e))?;
 
         tracing::info!(count = cdp_files.len(), "CDP 返回文件");
 
@@ -512,24 +509,45 @@ impl FileOperations {
     }
 
     // 准备下载任务（用于异步下载）
+    // mirror_roots 为 true 时按备份的 root 目录结构保存；
+    // 多个文件会写入同一目标路径（不同 root 下的同名文件）时返回这些文件名
     pub fn prepare_download_tasks(
         files: &[CloudFile],
         selected_indices: &[usize],
         base_dir: &std::path::Path,
         local_save_paths: &[(String, PathBuf)],
-    ) -> Vec<crate::downloader::DownloadTask> {
-        selected_indices
+        mirror_roots: bool,
+    ) -> std::result::Result<Vec<crate::downloader::DownloadTask>, Vec<String>> {
+        let tasks: Vec<crate::downloader::DownloadTask> = selected_indices
             .iter()
             .filter_map(|&index| {
-                files
-                    .get(index)
-                    .map(|file| crate::downloader::DownloadTask {
+                files.get(index).map(|file| {
+                    let target_dir = if mirror_roots {
+                        base_dir.join(crate::backup::get_root_folder_name(file.root))
+                    } else {
+                        base_dir.to_path_buf()
+                    };
+                    crate::downloader::DownloadTask {
                         file: file.clone(),
-                        target_path: base_dir.join(&file.name),
+                        target_path: target_dir.join(&file.name),
                         local_save_paths: local_save_paths.to_vec(),
-                    })
+                    }
+                })
             })
-            .collect()
+            .collect();
+
+        let mut seen = std::collections::HashSet::new();
+        let mut collisions: Vec<String> = Vec::new();
+        for task in &tasks {
+            if !seen.insert(&task.target_path) && !collisions.contains(&task.file.name) {
+                collisions.push(task.file.name.clone());
+            }
+        }
+        if collisions.is_empty() {
+            Ok(tasks)
+        } else {
+            Err(collisions)
+        }
     }
 
     // 选择下载目录
@@ -1096,6 +1114,9 @@ pub enum FileOperationResult {
     SuccessWithRefresh(String), // 成功消息 + 需要刷新
 }
 
+use crate::upload_filter::{SkipReason, UploadFilter};
+use sha1::{Digest, Sha1};
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::time::{Duration, SystemTime};
 use walkdir::WalkDir;
@@ -1115,7 +1136,7 @@ pub struct UploadTask {
 }
 
 // 任务状态
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum TaskStatus {
     Pending,         // 等待中
     Retrying(usize), // 重试中 (第N次)
@@ -1127,6 +1148,13 @@ pub enum TaskStatus {
 pub struct UploadQueue {
     pub tasks: Vec<UploadTask>,
     pub virtual_root: Option<String>,
+    // 被过滤规则跳过的文件
+    pub skipped: Vec<(PathBuf, SkipReason)>,
+    filter: UploadFilter,
+    // 用户添加的原始文件/文件夹，修改过滤规则后据此重建队列
+    sources: Vec<PathBuf>,
+    // 用户从队列中移除的云端路径，重建队列时继续排除
+    removed: HashSet<String>,
 }
 
 impl UploadQueue {
@@ -1134,11 +1162,93 @@ impl UploadQueue {
         Self {
             tasks: Vec::new(),
             virtual_root: None,
+            skipped: Vec::new(),
+            filter: UploadFilter::default(),
+            sources: Vec::new(),
+            removed: HashSet::new(),
+        }
+    }
+
+    pub fn with_filter(mut self, filter: UploadFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    // 按游戏配置档设置过滤规则和默认虚拟根目录，未配置的项沿用全局设置
+    pub fn with_profile(mut self, profile: Option<&crate::config::GameProfile>) -> Self {
+        let config = profile
+            .map(|p| p.upload_config())
+            .unwrap_or_else(|| crate::config::get_config().upload);
+        self.virtual_root = profile
+            .and_then(|p| p.upload_virtual_root.clone())
+            .map(|root| root.trim().trim_matches('/').to_string())
+            .filter(|root| !root.is_empty());
+        self.with_filter(UploadFilter::new(&config))
+    }
+
+    // 更换过滤规则并按原始来源重建队列（手动修改的云端路径会被重置，已移除的任务不会恢复）
+    pub fn set_filter(&mut self, filter: UploadFilter) {
+        self.filter = filter;
+        self.tasks.clear();
+        self.skipped.clear();
+        for source in self.sources.clone() {
+            let result = if source.is_dir() {
+                self.walk_folder(&source)
+            } else {
+                self.push_file(source.clone())
+            };
+            if let Err(e) = result {
+                tracing::warn!("重建上传队列失败 {}: {}", source.display(), e);
+            }
         }
+        let removed = &self.removed;
+        self.tasks
+            .retain(|task| !removed.contains(&task.cloud_path));
     }
 
-    // 添加单个文件
+    pub fn clear(&mut self) {
+        self.tasks.clear();
+        self.skipped.clear();
+        self.sources.clear();
+        self.removed.clear();
+    }
+
+    // 从队列中移除任务，并记住其云端路径
+    pub fn remove_task(&mut self, index: usize) -> Option<UploadTask> {
+        if index >= self.tasks.len() {
+            return None;
+        }
+        let task = self.tasks.remove(index);
+        self.removed.insert(task.cloud_path.clone());
+        Some(task)
+    }
+
+    // 添加单个文件（用户明确选择的文件不受过滤规则影响，只应用路径改写）
     pub fn add_file(&mut self, local_path: PathBuf) -> Result<()> {
+        let start = self.tasks.len();
+        self.push_file(local_path.clone())?;
+        self.sources.push(local_path);
+        self.forget_removed_from(start);
+        Ok(())
+    }
+
+    // 添加文件夹（递归）
+    pub fn add_folder(&mut self, folder_path: &Path) -> Result<()> {
+        let start = self.tasks.len();
+        self.walk_folder(folder_path)?;
+        self.sources.push(folder_path.to_path_buf());
+        self.forget_removed_from(start);
+        Ok(())
+    }
+
+    // 用户重新添加的文件不再视为已移除
+    fn forget_removed_from(&mut self, start: usize) {
+        for task in &self.tasks[start..] {
+            self.removed.remove(&task.cloud_path);
+        }
+    }
+
+    fn push_file(&mut self, local_path: PathBuf) -> Result<()> {
         let size = std::fs::metadata(&local_path)?.len();
 
         // 验证文件大小
@@ -1160,6 +1270,7 @@ impl UploadQueue {
         } else {
             filename.to_string()
         };
+        let cloud_path = self.filter.rewrite(&cloud_path);
 
         self.tasks.push(UploadTask {
             local_path,
@@ -1173,8 +1284,7 @@ impl UploadQueue {
         Ok(())
     }
 
-    // 添加文件夹（递归）
-    pub fn add_folder(&mut self, folder_path: &Path) -> Result<()> {
+    fn walk_folder(&mut self, folder_path: &Path) -> Result<()> {
         let folder_name = folder_path
             .file_name()
             .and_then(|n| n.to_str())
@@ -1206,6 +1316,13 @@ impl UploadQueue {
                     .ok_or_else(|| anyhow!("路径包含非 UTF-8 字符: {:?}", relative_path))?
                     .replace("\\", "/");
 
+                // 过滤规则相对所添加的文件夹匹配
+                if let Some(reason) = self.filter.check(&relative_path_str) {
+                    tracing::debug!("跳过文件 {} ({:?})", file_path.display(), reason);
+                    self.skipped.push((file_path.to_path_buf(), reason));
+                    continue;
+                }
+
                 // 构建云端路径：[virtual_root]/[folder_name]/[relative_path]
                 // 注意：如果 relative_path 为空（即直接是文件夹本身，虽然 WalkDir 这里是文件所以不会空，但以防万一），
                 // 或者 relative_path 只是文件名，这里逻辑是一样的。
@@ -1222,6 +1339,7 @@ impl UploadQueue {
                 } else {
                     folder_relative_path
                 };
+                let cloud_path = self.filter.rewrite(&cloud_path);
 
                 self.tasks.push(UploadTask {
                     local_path: file_path.to_path_buf(),
@@ -1244,6 +1362,168 @@ impl UploadQueue {
     pub fn total_files(&self) -> usize {
         self.tasks.len()
     }
+
+    // 预测上传后的云端占用
+    // existing 为云端现有文件 (名称, 大小)，同名文件会被覆盖，不重复计入文件数；
+    // 名称按原样比较，只有大小写不同的云端文件是不同的文件
+    pub fn project<'a>(
+        &self,
+        existing: impl IntoIterator<Item = (&'a str, u64)>,
+        limits: &UploadLimits,
+    ) -> UploadProjection {
+        let existing: HashMap<&str, u64> = existing.into_iter().collect();
+
+        // 队列中同一云端路径只保留最后一个任务
+        let mut uploads: HashMap<&str, u64> = HashMap::new();
+        for task in &self.tasks {
+            uploads.insert(task.cloud_path.as_str(), task.size);
+        }
+
+        let current_bytes: u64 = existing.values().sum();
+        let mut projection = UploadProjection {
+            current_bytes,
+            current_files: existing.len(),
+            upload_bytes: uploads.values().sum(),
+            ..Default::default()
+        };
+
+        for path in uploads.keys() {
+            if let Some(old_size) = existing.get(path) {
+                projection.overwritten_files += 1;
+                projection.overwritten_bytes += old_size;
+            }
+        }
+
+        projection.projected_bytes =
+            current_bytes - projection.overwritten_bytes + projection.upload_bytes;
+        projection.projected_files = existing.len() + uploads.len() - projection.overwritten_files;
+        projection.issues = projection.check(limits);
+        projection
+    }
+}
+
+// 上传限制
+// quota/max_files 来自 appinfo.vdf 的 ufs 配置，available 为 Steam 实时可用配额
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct UploadLimits {
+    pub quota: Option<u64>,
+    pub max_files: Option<u32>,
+    pub available: Option<u64>,
+}
+
+impl UploadLimits {
+    // 从 appinfo.vdf 的 ufs 配置构建（0 表示未设置）
+    pub fn from_ufs(quota: u64, maxnumfiles: u32) -> Self {
+        Self {
+            quota: (quota > 0).then_some(quota),
+            max_files: (maxnumfiles > 0).then_some(maxnumfiles),
+            available: None,
+        }
+    }
+}
+
+// 配额接近上限的警告阈值（百分比）
+const QUOTA_WARN_PERCENT: u64 = 90;
+
+// 配额检查问题
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuotaIssue {
+    QuotaExceeded { projected: u64, limit: u64 },
+    MaxFilesExceeded { projected: usize, limit: u32 },
+    AvailableExceeded { needed: u64, available: u64 },
+    NearQuota { projected: u64, limit: u64 },
+}
+
+impl QuotaIssue {
+    // 是否阻止上传
+    pub fn is_blocking(&self) -> bool {
+        !matches!(self, QuotaIssue::NearQuota { .. })
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            QuotaIssue::QuotaExceeded { projected, limit } => format!(
+                "上传后占用 {} 超过配额 {}",
+                format_size(*projected),
+                format_size(*limit)
+            ),
+            QuotaIssue::MaxFilesExceeded { projected, limit } => {
+                format!("上传后文件数 {} 超过上限 {}", projected, limit)
+            }
+            QuotaIssue::AvailableExceeded { needed, available } => format!(
+                "需要新增 {}，Steam 剩余可用 {}",
+                format_size(*needed),
+                format_size(*available)
+            ),
+            QuotaIssue::NearQuota { projected, limit } => format!(
+                "上传后占用 {} 接近配额 {}",
+                format_size(*projected),
+                format_size(*limit)
+            ),
+        }
+    }
+}
+
+// 上传后的配额预测
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UploadProjection {
+    pub current_bytes: u64,
+    pub current_files: usize,
+    pub upload_bytes: u64,
+    pub overwritten_files: usize,
+    pub overwritten_bytes: u64,
+    pub projected_bytes: u64,
+    pub projected_files: usize,
+    pub issues: Vec<QuotaIssue>,
+}
+
+impl UploadProjection {
+    pub fn is_blocked(&self) -> bool {
+        self.issues.iter().any(|i| i.is_blocking())
+    }
+
+    // 净增长字节数（覆盖文件只计算差值）
+    pub fn growth_bytes(&self) -> u64 {
+        self.projected_bytes.saturating_sub(self.current_bytes)
+    }
+
+    fn check(&self, limits: &UploadLimits) -> Vec<QuotaIssue> {
+        let mut issues = Vec::new();
+
+        if let Some(limit) = limits.quota {
+            if self.projected_bytes > limit {
+                issues.push(QuotaIssue::QuotaExceeded {
+                    projected: self.projected_bytes,
+                    limit,
+                });
+            } else if self.projected_bytes * 100 >= limit * QUOTA_WARN_PERCENT {
+                issues.push(QuotaIssue::NearQuota {
+                    projected: self.projected_bytes,
+                    limit,
+                });
+            }
+        }
+
+        if let Some(limit) = limits.max_files
+            && self.projected_files > limit as usize
+        {
+            issues.push(QuotaIssue::MaxFilesExceeded {
+                projected: self.projected_files,
+                limit,
+            });
+        }
+
+        if let Some(available) = limits.available
+            && self.growth_bytes() > available
+        {
+            issues.push(QuotaIssue::AvailableExceeded {
+                needed: self.growth_bytes(),
+                available,
+            });
+        }
+
+        issues
+    }
 }
 
 // 打开文件夹，返回 Err 说明路径不存在
@@ -1291,6 +1571,12 @@ pub struct UploadExecutor {
     steam_manager: Arc<Mutex<crate::steam_worker::SteamWorkerManager>>,
     retry_config: UploadRetryConfig,
     progress_callback: Option<ProgressCallback>,
+    limits: UploadLimits,
+    journal: Option<crate::upload_session::UploadJournal>,
+    // 云端已有相同内容（大小和 SHA-1 一致）时跳过，用于续传
+    skip_matching: bool,
+    // 写入后读回校验
+    verify_after_write: bool,
 }
 
 impl UploadExecutor {
@@ -1299,9 +1585,35 @@ impl UploadExecutor {
             steam_manager,
             retry_config: UploadRetryConfig::default(),
             progress_callback: None,
+            limits: UploadLimits::default(),
+            journal: None,
+            skip_matching: false,
+            verify_after_write: false,
         }
     }
 
+    pub fn with_verify_after_write(mut self, verify: bool) -> Self {
+        self.verify_after_write = verify;
+        self
+    }
+
+    // 每个任务结束后写入会话日志，全部成功后删除
+    pub fn with_journal(mut self, journal: crate::upload_session::UploadJournal) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+
+    pub fn with_skip_matching(mut self, skip_matching: bool) -> Self {
+        self.skip_matching = skip_matching;
+        self
+    }
+
+    // 设置 appinfo.vdf 中的配额限制，执行前会结合实时配额再检查一次
+    pub fn with_limits(mut self, limits: UploadLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     pub fn with_progress_callback<F>(mut self, callback: F) -> Self
     where
         F: Fn(usize, usize, &str) + Send + 'static,
@@ -1312,29 +1624,53 @@ impl UploadExecutor {
 
     // 执行上传
     pub fn execute(&self, queue: &mut UploadQueue) -> Result<UploadResult> {
+        let (cloud_files, quota_warning) = self.check_quota(queue)?;
+
         let start_time = SystemTime::now();
         let mut success_count = 0;
+        let mut skipped_count = 0;
         let mut failed_files = Vec::new();
         let total_size: u64 = queue.tasks.iter().map(|t| t.size).sum();
         let total_files = queue.tasks.len();
 
-        for (index, task) in queue.tasks.iter_mut().enumerate() {
+        self.save_journal(queue);
+
+        for index in 0..total_files {
+            let task = &mut queue.tasks[index];
+
             // 发送进度更新
             if let Some(ref callback) = self.progress_callback {
                 callback(index + 1, total_files, &task.cloud_path);
             }
 
-            match self.upload_task_with_retry(task) {
-                Ok(_) => {
-                    task.status = TaskStatus::Success;
-                    success_count += 1;
-                }
-                Err(e) => {
-                    task.status = TaskStatus::Failed;
-                    task.error = Some(e.to_string());
-                    failed_files.push((task.cloud_path.clone(), e.to_string()));
+            if self.skip_matching && self.cloud_copy_matches(task, &cloud_files) {
+                tracing::info!("云端已有相同文件，跳过: {}", task.cloud_path);
+                task.status = TaskStatus::Success;
+                success_count += 1;
+                skipped_count += 1;
+            } else {
+                match self.upload_task_with_retry(task) {
+                    Ok(_) => {
+                        task.status = TaskStatus::Success;
+                        success_count += 1;
+                    }
+                    Err(e) => {
+                        task.status = TaskStatus::Failed;
+                        task.error = Some(e.to_string());
+                        failed_files.push((task.cloud_path.clone(), e.to_string()));
+                    }
                 }
             }
+
+            self.save_journal(queue);
+        }
+
+        // 全部成功后删除会话日志，有失败时保留以便下次续传
+        if failed_files.is_empty()
+            && let Some(ref journal) = self.journal
+            && let Err(e) = journal.finish()
+        {
+            tracing::warn!("删除上传会话日志失败: {}", e);
         }
 
         let elapsed = start_time.elapsed().unwrap_or(Duration::from_secs(0));
@@ -1349,13 +1685,76 @@ impl UploadExecutor {
 
         Ok(UploadResult {
             success_count,
+            skipped_count,
             failed_count: failed_files.len(),
             total_size,
             elapsed_secs: elapsed.as_secs(),
             failed_files,
+            quota_warning,
         })
     }
 
+    fn save_journal(&self, queue: &UploadQueue) {
+        if let Some(ref journal) = self.journal
+            && let Err(e) = journal.save(queue)
+        {
+            tracing::warn!("写入上传会话日志失败: {}", e);
+        }
+    }
+
+    // 云端副本与本地文件大小和 SHA-1 一致
+    fn cloud_copy_matches(
+        &self,
+        task: &UploadTask,
+        cloud_files: &[crate::steam_worker::WorkerCloudFile],
+    ) -> bool {
+        let same_size = cloud_files
+            .iter()
+            .any(|f| f.exists && f.size == task.size && f.name == task.cloud_path);
+        if !same_size {
+            return false;
+        }
+
+        let local = match std::fs::read(&task.local_path) {
+            Ok(data) => data,
+            Err(_) => return false,
+        };
+        let remote = match self.steam_manager.lock() {
+            Ok(mut manager) => match manager.read_file(&task.cloud_path) {
+                Ok(data) => data,
+                Err(_) => return false,
+            },
+            Err(_) => return false,
+        };
+        Sha1::digest(&local) == Sha1::digest(&remote)
+    }
+
+    // 读回云端文件校验内容；读回失败时退而比较文件列表中的大小
+    fn verify_upload(&self, cloud_path: &str, data: &[u8]) -> Result<()> {
+        let mut manager = self
+            .steam_manager
+            .lock()
+            .map_err(|e| anyhow!("Steam 管理器锁错误: {}", e))?;
+
+        let read_back = manager.read_file(cloud_path);
+        check_uploaded_copy(cloud_path, data, read_back, || manager.get_files())
+    }
+
+    // 上传前配额检查，使用最新的云端文件列表和实时配额，返回云端文件列表和提示信息
+    fn check_quota(
+        &self,
+        queue: &UploadQueue,
+    ) -> Result<(Vec<crate::steam_worker::WorkerCloudFile>, Option<String>)> {
+        let (files, live_quota) = {
+            let mut manager = self
+                .steam_manager
+                .lock()
+                .map_err(|e| anyhow!("Steam 管理器锁错误: {}", e))?;
+            (manager.get_files(), manager.get_quota().ok())
+        };
+        check_upload_quota(queue, self.limits, files, live_quota)
+    }
+
     // 上传单个任务
     fn upload_task_with_retry(&self, task: &mut UploadTask) -> Result<()> {
         let mut attempt = 0;
@@ -1368,8 +1767,16 @@ impl UploadExecutor {
             // 读取文件数据
             let data = std::fs::read(&task.local_path)?;
 
-            // 尝试上传
-            match self.upload_to_steam(&task.cloud_path, &data) {
+            // 尝试上传，开启校验时写入后读回比对
+            let result = self.upload_to_steam(&task.cloud_path, &data).and_then(|_| {
+                if self.verify_after_write {
+                    self.verify_upload(&task.cloud_path, &data)
+                } else {
+                    Ok(())
+                }
+            });
+
+            match result {
                 Ok(_) => {
                     tracing::info!("文件上传成功: {} (尝试 {})", task.cloud_path, attempt);
                     return Ok(());
@@ -1431,10 +1838,106 @@ impl UploadExecutor {
 #[derive(Debug, Clone)]
 pub struct UploadResult {
     pub success_count: usize,
+    // 云端内容一致而跳过的文件数（计入 success_count）
+    pub skipped_count: usize,
     pub failed_count: usize,
     pub total_size: u64,
     pub elapsed_secs: u64,
     pub failed_files: Vec<(String, String)>,
+    // 上传前未能读取云端文件列表时的错误，此时只按 appinfo 限制检查了配额
+    pub quota_warning: Option<String>,
+}
+
+// 按云端文件列表和限制检查上传队列；文件列表读取失败时只按 appinfo 配额检查本次上传，
+// 返回提示信息，列表读取失败本身不阻止上传
+fn check_upload_quota(
+    queue: &UploadQueue,
+    mut limits: UploadLimits,
+    files: Result<Vec<crate::steam_worker::WorkerCloudFile>>,
+    live_quota: Option<(u64, u64)>,
+) -> Result<(Vec<crate::steam_worker::WorkerCloudFile>, Option<String>)> {
+    if let Some((_total, available)) = live_quota {
+        limits.available = Some(available);
+    }
+    let (files, warning) = match files {
+        Ok(files) => (files, None),
+        Err(e) => {
+            tracing::warn!("获取云端文件列表失败，仅按 appinfo 限制检查配额: {}", e);
+            (Vec::new(), Some(e.to_string()))
+        }
+    };
+
+    let projection = queue.project(
+        files
+            .iter()
+            .filter(|f| f.exists)
+            .map(|f| (f.name.as_str(), f.size)),
+        &limits,
+    );
+
+    for issue in projection.issues.iter().filter(|i| !i.is_blocking()) {
+        tracing::warn!("上传配额警告: {}", issue.describe());
+    }
+
+    if projection.is_blocked() {
+        let summary = projection
+            .issues
+            .iter()
+            .filter(|i| i.is_blocking())
+            .map(|i| i.describe())
+            .collect::<Vec<_>>()
+            .join("; ");
+        return Err(anyhow!("上传前配额检查未通过: {}", summary));
+    }
+
+    Ok((files, warning))
+}
+
+// SHA-1 十六进制字符串
+pub fn sha1_hex(data: &[u8]) -> String {
+    Sha1::digest(data)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+// 比较读回的云端内容与本地数据；读回失败时用 list_files 取文件列表比较大小
+fn check_uploaded_copy(
+    cloud_path: &str,
+    data: &[u8],
+    read_back: Result<Vec<u8>>,
+    list_files: impl FnOnce() -> Result<Vec<crate::steam_worker::WorkerCloudFile>>,
+) -> Result<()> {
+    match read_back {
+        Ok(remote) => {
+            let local_hash = sha1_hex(data);
+            let remote_hash = sha1_hex(&remote);
+            if local_hash != remote_hash {
+                return Err(anyhow!(
+                    "上传校验失败: 云端 SHA-1 {} 与本地 {} 不一致 (云端 {} / 本地 {})",
+                    remote_hash,
+                    local_hash,
+                    format_size(remote.len() as u64),
+                    format_size(data.len() as u64)
+                ));
+            }
+            tracing::debug!("上传校验通过: {} ({})", cloud_path, local_hash);
+            Ok(())
+        }
+        Err(read_err) => {
+            tracing::warn!("读回 {} 失败，改用文件列表校验: {}", cloud_path, read_err);
+            let files = list_files()?;
+            match files.iter().find(|f| f.exists && f.name == cloud_path) {
+                Some(f) if f.size == data.len() as u64 => Ok(()),
+                Some(f) => Err(anyhow!(
+                    "上传校验失败: 云端大小 {} 与本地 {} 不一致",
+                    format_size(f.size),
+                    format_size(data.len() as u64)
+                )),
+                None => Err(anyhow!("上传校验失败: 云端未找到 {}", cloud_path)),
+            }
+        }
+    }
 }
 
 // 格式化文件大小
@@ -1477,3 +1980,230 @@ pub fn parse_size(s: &str) -> u64 {
         num as u64
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(cloud_path: &str, size: u64) -> UploadTask {
+        UploadTask {
+            local_path: PathBuf::from(cloud_path),
+            cloud_path: cloud_path.to_string(),
+            size,
+            status: TaskStatus::Pending,
+            retry_count: 0,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn projection_accounts_for_overwritten_files() {
+        let mut queue = UploadQueue::new();
+        queue.tasks.push(task("save/slot1.sav", 300));
+        queue.tasks.push(task("save/slot2.sav", 200));
+
+        let existing = [("save/slot1.sav", 100), ("config.ini", 50)];
+        let limits = UploadLimits {
+            quota: Some(1000),
+            max_files: Some(3),
+            available: Some(400),
+        };
+        let projection = queue.project(existing, &limits);
+
+        assert_eq!(projection.overwritten_files, 1);
+        assert_eq!(projection.overwritten_bytes, 100);
+        assert_eq!(projection.projected_bytes, 550);
+        assert_eq!(projection.projected_files, 3);
+        assert_eq!(projection.growth_bytes(), 400);
+        assert!(projection.issues.is_empty());
+
+        // 只有大小写不同的云端文件是不同的文件，不会被覆盖
+        let existing = [("Save/Slot1.sav", 100), ("save/SLOT2.sav", 100)];
+        let projection = queue.project(existing, &UploadLimits::default());
+        assert_eq!(projection.current_files, 2);
+        assert_eq!(projection.overwritten_files, 0);
+        assert_eq!(projection.projected_files, 4);
+    }
+
+    #[test]
+    fn projection_reports_blocking_and_warning_issues() {
+        let mut queue = UploadQueue::new();
+        queue.tasks.push(task("a.sav", 950));
+        queue.tasks.push(task("b.sav", 10));
+
+        let near = queue.project([], &UploadLimits::from_ufs(1000, 0));
+        assert_eq!(
+            near.issues,
+            vec![QuotaIssue::NearQuota {
+                projected: 960,
+                limit: 1000
+            }]
+        );
+        assert!(!near.is_blocked());
+
+        let blocked = queue.project([("c.sav", 100)], &UploadLimits::from_ufs(1000, 2));
+        assert!(blocked.is_blocked());
+        assert!(blocked.issues.contains(&QuotaIssue::MaxFilesExceeded {
+            projected: 3,
+            limit: 2
+        }));
+        assert!(blocked.issues.contains(&QuotaIssue::QuotaExceeded {
+            projected: 1060,
+            limit: 1000
+        }));
+    }
+
+    #[test]
+    fn set_filter_keeps_removed_tasks_out() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let folder = dir.path().join("saves");
+        std::fs::create_dir_all(&folder).unwrap();
+        for name in ["a.sav", "b.sav", "c.log"] {
+            std::fs::write(folder.join(name), b"data").unwrap();
+        }
+        let cloud_paths = |queue: &UploadQueue| {
+            let mut paths: Vec<String> = queue.tasks.iter().map(|t| t.cloud_path.clone()).collect();
+            paths.sort();
+            paths
+        };
+        let exclude_logs = || {
+            UploadFilter::new(&crate::config::UploadConfig {
+                exclude: vec!["*.log".to_string()],
+                ..Default::default()
+            })
+        };
+
+        let mut queue = UploadQueue::new();
+        queue.add_folder(&folder).unwrap();
+        let index = queue
+            .tasks
+            .iter()
+            .position(|t| t.cloud_path == "saves/b.sav")
+            .unwrap();
+        assert_eq!(queue.remove_task(index).unwrap().cloud_path, "saves/b.sav");
+
+        queue.set_filter(exclude_logs());
+        assert_eq!(cloud_paths(&queue), vec!["saves/a.sav"]);
+
+        // 重新添加文件夹后恢复
+        queue.add_folder(&folder).unwrap();
+        queue.set_filter(exclude_logs());
+        assert!(cloud_paths(&queue).contains(&"saves/b.sav".to_string()));
+    }
+
+    #[test]
+    fn quota_check_falls_back_to_appinfo_limits_when_listing_fails() {
+        let mut queue = UploadQueue::new();
+        queue.tasks.push(task("a.sav", 300));
+        let limits = UploadLimits::from_ufs(1000, 1);
+        let list_failed = || Err(anyhow!("获取文件列表失败"));
+
+        let (files, warning) = check_upload_quota(&queue, limits, list_failed(), None).unwrap();
+        assert!(files.is_empty());
+        assert!(warning.unwrap().contains("获取文件列表失败"));
+
+        // 本次上传本身超出 appinfo 限制时仍然阻止
+        queue.tasks.push(task("b.sav", 300));
+        let err = check_upload_quota(&queue, limits, list_failed(), None).unwrap_err();
+        assert!(err.to_string().contains("配额检查未通过"), "{}", err);
+
+        // 列表正常时没有提示
+        let listed = Ok(vec![cloud_file("c.sav", 10)]);
+        let limits = UploadLimits::from_ufs(1000, 3);
+        let (files, warning) = check_upload_quota(&queue, limits, listed, None).unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(warning.is_none());
+    }
+
+    fn cloud_file(name: &str, size: u64) -> crate::steam_worker::WorkerCloudFile {
+        crate::steam_worker::WorkerCloudFile {
+            name: name.to_string(),
+            size,
+            timestamp: 0,
+            is_persisted: true,
+            exists: true,
+            root: 0,
+            root_description: String::new(),
+        }
+    }
+
+    #[test]
+    fn verify_upload_compares_sha1_of_read_back() {
+        let no_list = || -> Result<Vec<crate::steam_worker::WorkerCloudFile>> {
+            panic!("读回成功时不应读取文件列表")
+        };
+        assert!(check_uploaded_copy("save.dat", b"hello", Ok(b"hello".to_vec()), no_list).is_ok());
+
+        let err = check_uploaded_copy("save.dat", b"hello", Ok(b"hellp".to_vec()), no_list)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("SHA-1"), "{}", err);
+        assert!(err.contains(&sha1_hex(b"hello")), "{}", err);
+        assert!(err.contains(&sha1_hex(b"hellp")), "{}", err);
+    }
+
+    #[test]
+    fn verify_upload_falls_back_to_size_when_read_fails() {
+        let read_failed = || Err(anyhow!("Worker 未启动"));
+        let list = || Ok(vec![cloud_file("save.dat", 5), cloud_file("Other.dat", 1)]);
+
+        assert!(check_uploaded_copy("save.dat", b"hello", read_failed(), list).is_ok());
+
+        let err = check_uploaded_copy("save.dat", b"hello!", read_failed(), list)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("大小"), "{}", err);
+
+        // 名称按原样比较，只有大小写不同的文件不算同一个
+        let err = check_uploaded_copy("other.dat", b"h", read_failed(), list)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("未找到"), "{}", err);
+
+        let err = check_uploaded_copy("missing.dat", b"hello", read_failed(), list)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("未找到"), "{}", err);
+
+        // 文件列表也读取失败时返回该错误
+        let err = check_uploaded_copy("save.dat", b"hello", read_failed(), || {
+            Err(anyhow!("获取文件列表失败"))
+        })
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("获取文件列表失败"), "{}", err);
+    }
+
+    #[test]
+    fn prepare_download_tasks_rejects_same_name_from_different_roots() {
+        let file = |name: &str, root: u32| CloudFile {
+            name: name.to_string(),
+            size: 1,
+            timestamp: Local::now(),
+            is_persisted: true,
+            exists: true,
+            root,
+            root_description: String::new(),
+        };
+        let files = vec![
+            file("slot1.sav", 0),
+            file("slot1.sav", 3),
+            file("other.sav", 3),
+        ];
+        let base = Path::new("/downloads");
+
+        let err = FileOperations::prepare_download_tasks(&files, &[0, 1, 2], base, &[], false)
+            .err()
+            .unwrap();
+        assert_eq!(err, vec!["slot1.sav".to_string()]);
+
+        // 按 root 分目录保存时不冲突
+        let tasks =
+            FileOperations::prepare_download_tasks(&files, &[0, 1, 2], base, &[], true).unwrap();
+        assert_eq!(tasks.len(), 3);
+        assert_ne!(tasks[0].target_path, tasks[1].target_path);
+
+        // 只选其中一个同名文件时也不冲突
+        assert!(FileOperations::prepare_download_tasks(&files, &[0, 2], base, &[], false).is_ok());
+    }
+}
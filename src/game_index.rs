@@ -0,0 +1,120 @@
+// 游戏库索引缓存
+// 按 app id 记录扫描结果和来源文件的修改时间，重新扫描时只解析有变化的文件；
+// 同时保存上次合并后的完整游戏列表，打开游戏选择器时可以直接显示
+
+use crate::game_scanner::{AppManifest, CloudGameInfo, GameCategory, GameConfig};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+// 结构变化时递增，旧版本索引直接丢弃重建
+pub const INDEX_VERSION: u32 = 3;
+
+// 单个 app 的 remotecache.vdf 解析结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteCacheEntry {
+    pub mtime: u64,
+    pub file_count: usize,
+    pub total_size: u64,
+}
+
+// 单个 appmanifest_*.acf 的解析结果，解析失败时 manifest 为 None
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub mtime: u64,
+    pub manifest: Option<AppManifest>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameIndex {
+    pub version: u32,
+    pub user_id: String,
+    pub remotecache: HashMap<u32, RemoteCacheEntry>,
+    // 键为 manifest 文件路径
+    pub manifests: HashMap<String, ManifestEntry>,
+    pub localconfig_mtime: Option<u64>,
+    pub configs: HashMap<u32, GameConfig>,
+    pub sharedconfig_mtime: Option<u64>,
+    pub cloudstorage_mtime: Option<u64>,
+    // sharedconfig.vdf 与 cloudstorage 收藏夹合并后的分类
+    pub categories: HashMap<u32, GameCategory>,
+    // 动态收藏夹名称，规则无法在本地求值
+    pub dynamic_collections: Vec<String>,
+    // 上次合并后的完整游戏列表（含网页版云存储数据）
+    pub games: Vec<CloudGameInfo>,
+}
+
+impl GameIndex {
+    pub fn new(user_id: &str) -> Self {
+        Self {
+            version: INDEX_VERSION,
+            user_id: user_id.to_string(),
+            remotecache: HashMap::new(),
+            manifests: HashMap::new(),
+            localconfig_mtime: None,
+            configs: HashMap::new(),
+            sharedconfig_mtime: None,
+            cloudstorage_mtime: None,
+            categories: HashMap::new(),
+            dynamic_collections: Vec::new(),
+            games: Vec::new(),
+        }
+    }
+
+    // 读取指定用户的索引，不存在、损坏或版本不符时返回空索引
+    pub fn load(user_id: &str) -> Self {
+        let path = match index_path(user_id) {
+            Ok(path) => path,
+            Err(_) => return Self::new(user_id),
+        };
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(_) => return Self::new(user_id),
+        };
+        match serde_json::from_str::<GameIndex>(&content) {
+            Ok(index) if index.version == INDEX_VERSION && index.user_id == user_id => index,
+            Ok(_) => {
+                tracing::info!("游戏库索引版本不符，将重新扫描");
+                Self::new(user_id)
+            }
+            Err(e) => {
+                tracing::warn!("游戏库索引损坏 {}: {}", path.display(), e);
+                Self::new(user_id)
+            }
+        }
+    }
+
+    // 写入索引（先写临时文件再重命名）
+    pub fn save(&self) -> Result<()> {
+        let dir = index_dir()?;
+        std::fs::create_dir_all(&dir)?;
+
+        let content = serde_json::to_string(self)?;
+        let path = index_path(&self.user_id)?;
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, content)?;
+        std::fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+}
+
+fn index_dir() -> Result<PathBuf> {
+    Ok(crate::config::get_config_dir()?.join("game_index"))
+}
+
+fn index_path(user_id: &str) -> Result<PathBuf> {
+    Ok(index_dir()?.join(format!("{}.json", user_id)))
+}
+
+// 上次合并的游戏列表，用于启动时立即填充游戏选择器
+pub fn load_cached_games(user_id: &str) -> Vec<CloudGameInfo> {
+    GameIndex::load(user_id).games
+}
+
+// 文件修改时间（毫秒），文件不存在时为 None
+pub fn file_mtime(path: &Path) -> Option<u64> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    let duration = modified.duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some(duration.as_millis() as u64)
+}
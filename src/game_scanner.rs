@@ -1,3 +1,4 @@
+use crate::game_index::{GameIndex, ManifestEntry, RemoteCacheEntry, file_mtime};
 use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -15,6 +16,8 @@ pub struct CloudGameInfo {
     pub is_installed: bool,
     pub install_dir: Option<String>,
     pub categories: Vec<String>,
+    pub is_favorite: bool,
+    pub is_hidden: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,6 +36,38 @@ pub struct GameCategory {
     pub is_hidden: bool,
 }
 
+// 游戏选择器的收藏夹筛选
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum CollectionFilter {
+    #[default]
+    All,
+    Favorites,
+    Hidden,
+    Collection(String),
+}
+
+impl CollectionFilter {
+    pub fn matches(&self, game: &CloudGameInfo) -> bool {
+        match self {
+            CollectionFilter::All => true,
+            CollectionFilter::Favorites => game.is_favorite,
+            CollectionFilter::Hidden => game.is_hidden,
+            CollectionFilter::Collection(name) => game.categories.contains(name),
+        }
+    }
+}
+
+// 游戏列表中出现的全部收藏夹名称（排序去重）
+pub fn collection_names(games: &[CloudGameInfo]) -> Vec<String> {
+    let mut names: Vec<String> = games
+        .iter()
+        .flat_map(|g| g.categories.iter().cloned())
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppManifest {
     pub app_id: u32,
@@ -105,39 +140,65 @@ pub fn parse_app_manifest(path: &Path) -> Result<AppManifest> {
     }
 }
 
-// 扫描所有已安装游戏的 manifest
-pub fn scan_app_manifests(steam_path: &Path) -> Result<HashMap<u32, AppManifest>> {
+// 扫描所有已安装游戏的 manifest，修改时间未变的文件沿用索引中的解析结果
+pub fn scan_app_manifests(
+    steam_path: &Path,
+    cache: &mut HashMap<String, ManifestEntry>,
+) -> HashMap<u32, AppManifest> {
     let mut manifests = HashMap::new();
+    let mut entries_seen = HashMap::new();
     for steamapps_path in discover_library_steamapps(steam_path) {
         if let Ok(entries) = fs::read_dir(&steamapps_path) {
             for entry in entries.flatten() {
                 let filename = entry.file_name().to_string_lossy().to_string();
-                if filename.starts_with("appmanifest_")
-                    && filename.ends_with(".acf")
-                    && let Ok(manifest) = parse_app_manifest(&entry.path())
-                {
-                    manifests.entry(manifest.app_id).or_insert(manifest);
+                if !(filename.starts_with("appmanifest_") && filename.ends_with(".acf")) {
+                    continue;
                 }
+
+                let path = entry.path();
+                let key = path.to_string_lossy().to_string();
+                let mtime = file_mtime(&path).unwrap_or(0);
+                let cached = match cache.remove(&key).filter(|c| c.mtime == mtime) {
+                    Some(cached) => cached,
+                    None => ManifestEntry {
+                        mtime,
+                        manifest: parse_app_manifest(&path).ok(),
+                    },
+                };
+                if let Some(ref manifest) = cached.manifest {
+                    manifests
+                        .entry(manifest.app_id)
+                        .or_insert_with(|| manifest.clone());
+                }
+                entries_seen.insert(key, cached);
             }
         }
     }
 
-    Ok(manifests)
+    // 已删除的 manifest 不再保留
+    *cache = entries_seen;
+    manifests
 }
 
-// 获取游戏配置信息
-pub fn get_game_config(steam_path: &Path, user_id: &str, app_id: u32) -> Result<GameConfig> {
-    let localconfig_path = steam_path
+fn localconfig_path(steam_path: &Path, user_id: &str) -> PathBuf {
+    steam_path
         .join("userdata")
         .join(user_id)
         .join("config")
-        .join("localconfig.vdf");
+        .join("localconfig.vdf")
+}
 
-    if !localconfig_path.exists() {
-        return Err(anyhow!("localconfig.vdf 不存在"));
-    }
+fn sharedconfig_path(steam_path: &Path, user_id: &str) -> PathBuf {
+    steam_path
+        .join("userdata")
+        .join(user_id)
+        .join("7")
+        .join("remote")
+        .join("sharedconfig.vdf")
+}
 
-    let content = fs::read_to_string(&localconfig_path)?;
+// 从 localconfig.vdf 内容中获取游戏配置信息
+pub fn parse_game_config(content: &str, app_id: u32) -> GameConfig {
     let app_id_str = app_id.to_string();
     let mut in_app_section = false;
     let mut last_played = None;
@@ -173,23 +234,18 @@ pub fn get_game_config(steam_path: &Path, user_id: &str, app_id: u32) -> Result<
         }
     }
 
-    Ok(GameConfig {
+    GameConfig {
         app_id,
         last_played,
         playtime,
         launch_options,
-    })
+    }
 }
 
 // 解析 sharedconfig.vdf 获取游戏分类
 pub fn parse_shared_config(steam_path: &Path, user_id: &str) -> Result<HashMap<u32, GameCategory>> {
     let mut categories = HashMap::new();
-    let sharedconfig_path = steam_path
-        .join("userdata")
-        .join(user_id)
-        .join("7")
-        .join("remote")
-        .join("sharedconfig.vdf");
+    let sharedconfig_path = sharedconfig_path(steam_path, user_id);
 
     if !sharedconfig_path.exists() {
         return Ok(categories);
@@ -261,7 +317,12 @@ pub fn parse_shared_config(steam_path: &Path, user_id: &str) -> Result<HashMap<u
 }
 
 // 扫描所有有云存档的游戏
-pub fn scan_cloud_games(steam_path: &Path, user_id: &str) -> Result<Vec<CloudGameInfo>> {
+// 来源文件的修改时间与索引一致时直接沿用索引中的结果，索引随扫描结果一起更新
+pub fn scan_cloud_games(
+    steam_path: &Path,
+    user_id: &str,
+    index: &mut GameIndex,
+) -> Result<Vec<CloudGameInfo>> {
     let mut games = Vec::new();
     let userdata_path = steam_path.join("userdata").join(user_id);
 
@@ -273,8 +334,38 @@ pub fn scan_cloud_games(steam_path: &Path, user_id: &str) -> Result<Vec<CloudGam
         return Ok(games);
     }
 
-    let all_manifests = scan_app_manifests(steam_path).unwrap_or_default();
-    let all_categories = parse_shared_config(steam_path, user_id).unwrap_or_default();
+    let all_manifests = scan_app_manifests(steam_path, &mut index.manifests);
+
+    // 分类来自旧版 sharedconfig.vdf 和新版 cloudstorage 收藏夹，任一变化时都重新合并
+    let sharedconfig_mtime = file_mtime(&sharedconfig_path(steam_path, user_id));
+    let cloudstorage_mtime = crate::steam_collections::latest_mtime(steam_path, user_id);
+    if sharedconfig_mtime != index.sharedconfig_mtime
+        || cloudstorage_mtime != index.cloudstorage_mtime
+        || (sharedconfig_mtime.is_none() && cloudstorage_mtime.is_none())
+    {
+        let mut categories = parse_shared_config(steam_path, user_id).unwrap_or_default();
+        let collections = crate::steam_collections::load_collections(steam_path, user_id);
+        crate::steam_collections::merge_into_categories(&collections, &mut categories);
+        index.categories = categories;
+        index.dynamic_collections =
+            crate::steam_collections::dynamic_collection_names(&collections);
+        index.sharedconfig_mtime = sharedconfig_mtime;
+        index.cloudstorage_mtime = cloudstorage_mtime;
+    }
+
+    let localconfig_path = localconfig_path(steam_path, user_id);
+    let localconfig_mtime = file_mtime(&localconfig_path);
+    if localconfig_mtime.is_none() || localconfig_mtime != index.localconfig_mtime {
+        index.configs.clear();
+        index.localconfig_mtime = localconfig_mtime;
+    }
+    // 只有存在未缓存的 app 时才读取 localconfig.vdf
+    let mut localconfig_content: Option<Option<String>> = None;
+
+    let parser =
+        crate::vdf_parser::VdfParser::with_user_id(steam_path.to_path_buf(), user_id.to_string());
+    let mut remotecache = HashMap::new();
+    let mut reparsed = 0;
 
     // 收集有云存档的 app 信息用于日志汇总
     let mut app_file_counts: Vec<(u32, usize)> = Vec::new();
@@ -287,45 +378,77 @@ pub fn scan_cloud_games(steam_path: &Path, user_id: &str) -> Result<Vec<CloudGam
             let entry_name = entry.file_name().to_string_lossy().to_string();
             if let Ok(app_id) = entry_name.parse::<u32>() {
                 let vdf_path = entry.path().join("remotecache.vdf");
-                if vdf_path.exists() {
-                    // 使用 VdfParser 解析文件
-                    let files = if let Ok(parser) = crate::vdf_parser::VdfParser::new() {
-                        parser.parse_remotecache(app_id).unwrap_or_default()
-                    } else {
-                        Vec::new()
-                    };
-
-                    if !files.is_empty() {
-                        app_file_counts.push((app_id, files.len()));
+                let mtime = match file_mtime(&vdf_path) {
+                    Some(mtime) => mtime,
+                    None => continue,
+                };
+
+                let cache_entry = match index.remotecache.remove(&app_id) {
+                    Some(cached) if cached.mtime == mtime => cached,
+                    _ => {
+                        reparsed += 1;
+                        let files = parser.parse_remotecache(app_id).unwrap_or_default();
+                        RemoteCacheEntry {
+                            mtime,
+                            file_count: files.len(),
+                            total_size: files.iter().map(|f| f.size).sum(),
+                        }
                     }
+                };
 
-                    let total_size: u64 = files.iter().map(|f| f.size).sum();
-                    let config = get_game_config(steam_path, user_id, app_id).ok();
-                    let manifest = all_manifests.get(&app_id);
-                    let category = all_categories.get(&app_id);
-
-                    // 游戏名称从 manifest 获取，未安装游戏由 CDP 补充
-                    let game_name = manifest.as_ref().map(|m| m.name.clone());
-
-                    games.push(CloudGameInfo {
-                        app_id,
-                        file_count: files.len(),
-                        total_size,
-                        last_played: config.as_ref().and_then(|c| c.last_played),
-                        playtime: config.as_ref().and_then(|c| c.playtime),
-                        game_name,
-                        is_installed: manifest.is_some(),
-                        install_dir: manifest.as_ref().map(|m| m.install_dir.clone()),
-                        categories: category
-                            .as_ref()
-                            .map(|c| c.tags.clone())
-                            .unwrap_or_default(),
-                    });
+                if cache_entry.file_count > 0 {
+                    app_file_counts.push((app_id, cache_entry.file_count));
                 }
+
+                if !index.configs.contains_key(&app_id)
+                    && let Some(content) = localconfig_content
+                        .get_or_insert_with(|| fs::read_to_string(&localconfig_path).ok())
+                {
+                    index
+                        .configs
+                        .insert(app_id, parse_game_config(content, app_id));
+                }
+
+                let config = index.configs.get(&app_id);
+                let manifest = all_manifests.get(&app_id);
+                let category = index.categories.get(&app_id);
+
+                // 游戏名称从 manifest 获取，未安装游戏由 CDP 补充
+                let game_name = manifest.as_ref().map(|m| m.name.clone());
+
+                games.push(CloudGameInfo {
+                    app_id,
+                    file_count: cache_entry.file_count,
+                    total_size: cache_entry.total_size,
+                    last_played: config.and_then(|c| c.last_played),
+                    playtime: config.and_then(|c| c.playtime),
+                    game_name,
+                    is_installed: manifest.is_some(),
+                    install_dir: manifest.as_ref().map(|m| m.install_dir.clone()),
+                    categories: category
+                        .as_ref()
+                        .map(|c| c.tags.clone())
+                        .unwrap_or_default(),
+                    is_favorite: category.is_some_and(|c| c.is_favorite),
+                    is_hidden: category.is_some_and(|c| c.is_hidden),
+                });
+                remotecache.insert(app_id, cache_entry);
             }
         }
     }
 
+    // 已不存在的 app 从索引中移除
+    index.remotecache = remotecache;
+    index
+        .configs
+        .retain(|app_id, _| index.remotecache.contains_key(app_id));
+
+    tracing::debug!(
+        "remotecache.vdf: 沿用索引 {} 个, 重新解析 {} 个",
+        games.len() - reparsed,
+        reparsed
+    );
+
     // 汇总输出有云存档的 app 信息，格式: App {id}({count})
     if !app_file_counts.is_empty() {
         let summary: Vec<String> = app_file_counts
@@ -343,21 +466,21 @@ pub fn scan_cloud_games(steam_path: &Path, user_id: &str) -> Result<Vec<CloudGam
 // 扫描结果
 pub struct ScanResult {
     pub games: Vec<CloudGameInfo>,
+    pub dynamic_collections: Vec<String>,
     pub vdf_count: usize,
     pub cdp_count: usize,
 }
 
 // 获取并合并游戏列表（包括 CDP 数据）
 pub fn fetch_and_merge_games(steam_path: PathBuf, user_id: String) -> Result<ScanResult> {
-    let mut games = scan_cloud_games(&steam_path, &user_id)?;
+    let mut index = GameIndex::load(&user_id);
+    let mut games = scan_cloud_games(&steam_path, &user_id, &mut index)?;
     let vdf_count = games.len();
 
     let mut cdp_count = 0;
     let mut cdp_order = std::collections::HashMap::new();
-    if crate::cdp_client::CdpClient::is_cdp_running()
-        && let Ok(mut client) =
-            crate::cdp_client::CdpClient::connect_for(crate::cdp_client::CdpTarget::GameList)
-        && let Ok(cdp_games) = client.fetch_game_list()
+    if crate::web_remotestorage::is_available()
+        && let Ok(cdp_games) = crate::web_remotestorage::fetch_game_list()
     {
         cdp_count = cdp_games.len();
         let map: std::collections::HashMap<u32, usize> = games
@@ -425,9 +548,82 @@ pub fn fetch_and_merge_games(steam_path: PathBuf, user_id: String) -> Result<Sca
         }
     });
 
+    index.games = games.clone();
+    if let Err(e) = index.save() {
+        tracing::warn!("保存游戏库索引失败: {}", e);
+    }
+
     Ok(ScanResult {
         games,
+        dynamic_collections: index.dynamic_collections,
         vdf_count,
         cdp_count,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REMOTECACHE: &str = "\"480\"\n{\n\t\"ChangeNumber\"\t\"3\"\n\t\"save1.dat\"\n\t{\n\t\t\"root\"\t\"0\"\n\t\t\"size\"\t\"100\"\n\t}\n\t\"save2.dat\"\n\t{\n\t\t\"root\"\t\"0\"\n\t\t\"size\"\t\"50\"\n\t}\n}\n";
+
+    #[test]
+    fn rescan_reuses_unchanged_index_entries() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let steam = temp.path();
+        let app_dir = steam.join("userdata").join("1").join("480");
+        fs::create_dir_all(&app_dir).unwrap();
+        fs::create_dir_all(steam.join("steamapps")).unwrap();
+        fs::write(app_dir.join("remotecache.vdf"), REMOTECACHE).unwrap();
+        fs::write(
+            steam.join("steamapps").join("appmanifest_480.acf"),
+            "\"AppState\"\n{\n\t\"appid\"\t\"480\"\n\t\"name\"\t\"Spacewar\"\n\t\"installdir\"\t\"Spacewar\"\n}\n",
+        )
+        .unwrap();
+
+        let mut index = GameIndex::new("1");
+        let games = scan_cloud_games(steam, "1", &mut index).unwrap();
+        assert_eq!((games[0].file_count, games[0].total_size), (2, 150));
+        assert_eq!(games[0].game_name.as_deref(), Some("Spacewar"));
+        assert_eq!(index.manifests.len(), 1);
+
+        // 修改时间未变时不重新解析，直接使用索引中的值
+        index.remotecache.get_mut(&480).unwrap().file_count = 99;
+        let games = scan_cloud_games(steam, "1", &mut index).unwrap();
+        assert_eq!(games[0].file_count, 99);
+
+        // 修改时间变化后重新解析
+        index.remotecache.get_mut(&480).unwrap().mtime = 0;
+        let games = scan_cloud_games(steam, "1", &mut index).unwrap();
+        assert_eq!(games[0].file_count, 2);
+
+        // 删除的 app 从索引中移除
+        fs::remove_file(app_dir.join("remotecache.vdf")).unwrap();
+        let games = scan_cloud_games(steam, "1", &mut index).unwrap();
+        assert!(games.is_empty());
+        assert!(index.remotecache.is_empty());
+    }
+
+    #[test]
+    fn collection_filter_matches_games() {
+        let game = CloudGameInfo {
+            app_id: 480,
+            file_count: 1,
+            total_size: 0,
+            last_played: None,
+            playtime: None,
+            game_name: None,
+            is_installed: false,
+            install_dir: None,
+            categories: vec!["RPG".to_string()],
+            is_favorite: true,
+            is_hidden: false,
+        };
+        assert!(CollectionFilter::All.matches(&game));
+        assert!(CollectionFilter::Favorites.matches(&game));
+        assert!(!CollectionFilter::Hidden.matches(&game));
+        assert!(CollectionFilter::Collection("RPG".to_string()).matches(&game));
+        assert!(!CollectionFilter::Collection("Co-op".to_string()).matches(&game));
+        assert_eq!(collection_names(&[game.clone(), game]), vec!["RPG"]);
+    }
+}
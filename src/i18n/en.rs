@@ -519,6 +519,13 @@ pub fn error_no_files_selected() -> &'static str {
     "Please select files to operate"
 }
 
+pub fn error_download_target_collision(names: &str) -> String {
+    format!(
+        "These files have the same name under different roots and would overwrite each other: {}\nSet mirror_root_layout = true under [download] in the config file to keep each root in its own folder",
+        names
+    )
+}
+
 pub fn error_not_connected() -> &'static str {
     "Not connected to Steam"
 }
@@ -1039,7 +1046,7 @@ pub fn symlink_experimental_title() -> &'static str {
 }
 
 pub fn symlink_experimental_desc() -> &'static str {
-    "Files in the directory are auto-synced after symlink creation. Use the cloud upload button to manually sync new files."
+    "Files in the directory are auto-synced after symlink creation. Use the cloud upload button to upload changed files manually."
 }
 
 pub fn symlink_sync_files() -> &'static str {
@@ -1070,10 +1077,6 @@ pub fn symlink_sync_scan_failed() -> &'static str {
     "Failed to scan directory"
 }
 
-pub fn files() -> &'static str {
-    "files"
-}
-
 pub fn appinfo_tab_local_ufs() -> &'static str {
     "Local UFS Config"
 }
@@ -1568,3 +1571,770 @@ pub fn theme_dark() -> &'static str {
 pub fn theme_system() -> &'static str {
     "System"
 }
+
+pub fn ufs_export_config() -> &'static str {
+    "Export"
+}
+
+pub fn ufs_export_config_tooltip() -> &'static str {
+    "Export the current config as a shareable JSON/VDF file"
+}
+
+pub fn ufs_export_all_configs() -> &'static str {
+    "Export All"
+}
+
+pub fn ufs_export_all_configs_tooltip() -> &'static str {
+    "Export every saved custom config into one bundle"
+}
+
+pub fn ufs_import_config() -> &'static str {
+    "Import"
+}
+
+pub fn ufs_import_policy_label() -> &'static str {
+    "On conflict:"
+}
+
+pub fn ufs_import_policy_skip() -> &'static str {
+    "Keep local"
+}
+
+pub fn ufs_import_policy_overwrite() -> &'static str {
+    "Overwrite"
+}
+
+pub fn ufs_import_policy_merge() -> &'static str {
+    "Merge"
+}
+
+pub fn ufs_export_success(count: usize, path: &str) -> String {
+    format!(
+        "Exported {} config{} to {}",
+        count,
+        if count != 1 { "s" } else { "" },
+        path
+    )
+}
+
+pub fn ufs_export_error(error: &str) -> String {
+    format!("Export failed: {}", error)
+}
+
+pub fn ufs_import_success(
+    added: usize,
+    overwritten: usize,
+    merged: usize,
+    skipped: usize,
+) -> String {
+    format!(
+        "Imported: {} added, {} overwritten, {} merged, {} skipped",
+        added, overwritten, merged, skipped
+    )
+}
+
+pub fn ufs_import_error(error: &str) -> String {
+    format!("Import failed: {}", error)
+}
+
+pub fn ufs_validation_title() -> &'static str {
+    "Validation"
+}
+
+pub fn ufs_validation_ok() -> &'static str {
+    "No problems found"
+}
+
+pub fn ufs_validation_summary(errors: usize, warnings: usize) -> String {
+    format!("{} error(s), {} warning(s)", errors, warnings)
+}
+
+pub fn ufs_validation_blocked(errors: usize) -> String {
+    format!(
+        "Injection blocked: the config has {} error(s), fix them first",
+        errors
+    )
+}
+
+pub fn ufs_issue_unknown_root(index: usize, root: &str) -> String {
+    format!("Savefile #{}: unknown root \"{}\"", index, root)
+}
+
+pub fn ufs_issue_empty_pattern(index: usize) -> String {
+    format!("Savefile #{}: pattern is empty", index)
+}
+
+pub fn ufs_issue_absolute_path(index: usize, path: &str) -> String {
+    format!(
+        "Savefile #{}: path must be relative, got \"{}\"",
+        index, path
+    )
+}
+
+pub fn ufs_issue_parent_segment(index: usize, path: &str) -> String {
+    format!(
+        "Savefile #{}: path must not contain '..' (\"{}\")",
+        index, path
+    )
+}
+
+pub fn ufs_issue_unknown_platform(index: usize, platform: &str) -> String {
+    format!(
+        "Savefile #{}: platform \"{}\" is not in the known oslist",
+        index, platform
+    )
+}
+
+pub fn ufs_issue_overlapping_savefiles(first: usize, second: usize) -> String {
+    format!(
+        "Savefiles #{} and #{} match overlapping files",
+        first, second
+    )
+}
+
+pub fn ufs_issue_unknown_override_root(index: usize, root: &str) -> String {
+    format!(
+        "Root override #{}: unknown original root \"{}\"",
+        index, root
+    )
+}
+
+pub fn ufs_issue_invalid_new_root(index: usize, root: &str) -> String {
+    format!(
+        "Root override #{}: \"{}\" is not a valid new root",
+        index, root
+    )
+}
+
+pub fn ufs_issue_unknown_override_os(index: usize, os: &str) -> String {
+    format!("Root override #{}: unknown target OS \"{}\"", index, os)
+}
+
+pub fn ufs_issue_duplicate_override(first: usize, second: usize) -> String {
+    format!(
+        "Root overrides #{} and #{} target the same root, OS and comparison",
+        first, second
+    )
+}
+
+pub fn appinfo_tab_full_tree() -> &'static str {
+    "Full AppInfo"
+}
+
+pub fn appinfo_search_placeholder() -> &'static str {
+    "Search keys or values..."
+}
+
+pub fn appinfo_show_as_json() -> &'static str {
+    "JSON"
+}
+
+pub fn appinfo_copy_output() -> &'static str {
+    "Copy"
+}
+
+pub fn appinfo_full_loading() -> &'static str {
+    "Reading appinfo.vdf..."
+}
+
+pub fn appinfo_full_summary(version: u32, change_number: u32, info_state: u32) -> String {
+    format!(
+        "appinfo V{} · change #{} · state {}",
+        version, change_number, info_state
+    )
+}
+
+pub fn appinfo_full_no_match() -> &'static str {
+    "No matching keys"
+}
+
+pub fn appinfo_full_load_error(error: &str) -> String {
+    format!("Failed to read appinfo: {}", error)
+}
+
+pub fn error_appinfo_unsupported_version(found: &str, supported: &str) -> String {
+    format!(
+        "This Steam client uses appinfo.vdf format {}, which is not supported yet (supported: {}). Please update Steam Cloud File Manager.",
+        found, supported
+    )
+}
+
+pub fn upload_limit_none() -> &'static str {
+    "no limit"
+}
+
+pub fn upload_projection_bytes(current: &str, projected: &str, limit: &str) -> String {
+    format!(
+        "Storage: {} now → {} after upload (quota: {})",
+        current, projected, limit
+    )
+}
+
+pub fn upload_projection_files(current: usize, projected: usize, limit: &str) -> String {
+    format!(
+        "Files: {} now → {} after upload (max: {})",
+        current, projected, limit
+    )
+}
+
+pub fn upload_projection_available(growth: &str, available: &str) -> String {
+    format!("Net growth: {} (Steam available: {})", growth, available)
+}
+
+pub fn upload_projection_overwrites(count: usize, size: &str) -> String {
+    format!("Overwrites {} existing cloud file(s) ({})", count, size)
+}
+
+pub fn quota_issue_exceeded(projected: &str, limit: &str) -> String {
+    format!(
+        "Usage after upload ({}) exceeds the quota ({})",
+        projected, limit
+    )
+}
+
+pub fn quota_issue_max_files(projected: usize, limit: u32) -> String {
+    format!(
+        "File count after upload ({}) exceeds the maximum ({})",
+        projected, limit
+    )
+}
+
+pub fn quota_issue_available(needed: &str, available: &str) -> String {
+    format!(
+        "Needs {} more space, but Steam only has {} available",
+        needed, available
+    )
+}
+
+pub fn quota_issue_near(projected: &str, limit: &str) -> String {
+    format!(
+        "Usage after upload ({}) is close to the quota ({})",
+        projected, limit
+    )
+}
+
+pub fn upload_blocked_by_quota() -> &'static str {
+    "Remove files until the upload fits within the limits"
+}
+
+pub fn upload_filters_header() -> &'static str {
+    "Filters and cloud path rules"
+}
+
+pub fn upload_skip_hidden() -> &'static str {
+    "Skip hidden files"
+}
+
+pub fn upload_skip_temp() -> &'static str {
+    "Skip temporary files"
+}
+
+pub fn upload_patterns_hint() -> &'static str {
+    "gitignore-style, one pattern per line: *.sav, saves/**, logs/, !keep.log. Applies to files inside added folders."
+}
+
+pub fn upload_include_patterns() -> &'static str {
+    "Include (empty = all)"
+}
+
+pub fn upload_exclude_patterns() -> &'static str {
+    "Exclude"
+}
+
+pub fn upload_path_rules() -> &'static str {
+    "Cloud path rules (applied in order):"
+}
+
+pub fn upload_rule_strip_prefix() -> &'static str {
+    "Strip prefix"
+}
+
+pub fn upload_rule_lowercase() -> &'static str {
+    "Lowercase"
+}
+
+pub fn upload_rule_replace_segment() -> &'static str {
+    "Replace segment"
+}
+
+pub fn upload_add_rule() -> &'static str {
+    "Add rule:"
+}
+
+pub fn upload_apply_filters() -> &'static str {
+    "Apply and preview"
+}
+
+pub fn upload_skipped_files(count: usize) -> String {
+    format!("{} file(s) skipped by filters", count)
+}
+
+pub fn upload_skip_reason_hidden() -> &'static str {
+    "hidden"
+}
+
+pub fn upload_skip_reason_temp() -> &'static str {
+    "temporary"
+}
+
+pub fn upload_skip_reason_excluded() -> &'static str {
+    "excluded"
+}
+
+pub fn upload_skip_reason_not_included() -> &'static str {
+    "not included"
+}
+
+pub fn upload_resume_title() -> &'static str {
+    "Unfinished Upload"
+}
+
+pub fn upload_resume_message(app_id: u32, pending: usize, total: usize) -> String {
+    format!(
+        "An upload for App {} was interrupted: {} of {} file(s) not uploaded.",
+        app_id, pending, total
+    )
+}
+
+pub fn upload_resume_updated(time: &str) -> String {
+    format!("Last updated: {}", time)
+}
+
+pub fn upload_resume_hint() -> &'static str {
+    "Resuming skips files whose cloud copy already matches by size and hash."
+}
+
+pub fn upload_resume_button() -> &'static str {
+    "Resume"
+}
+
+pub fn upload_resume_later() -> &'static str {
+    "Later"
+}
+
+pub fn upload_resume_discard() -> &'static str {
+    "Discard"
+}
+
+pub fn upload_resume_nothing_left() -> &'static str {
+    "None of the local files from the interrupted upload exist anymore; the session was discarded."
+}
+
+pub fn upload_skipped_unchanged(count: usize) -> String {
+    format!("{} unchanged file(s) skipped", count)
+}
+
+pub fn upload_quota_list_failed(error: &str) -> String {
+    format!(
+        "Could not read the cloud file list, so the quota was only checked against the appinfo limits: {}",
+        error
+    )
+}
+
+pub fn upload_verify_after_write() -> &'static str {
+    "Verify after upload"
+}
+
+pub fn upload_verify_after_write_hint() -> &'static str {
+    "Read each file back from Steam Cloud and compare its SHA-1 with the local file. Mismatches are reported as failed uploads."
+}
+
+pub fn download_bytes_progress(done: &str, total: &str) -> String {
+    format!("Downloaded {} / {}", done, total)
+}
+
+pub fn download_source_stats(cdp: usize, steam_api: usize, local: usize) -> String {
+    format!(
+        "Sources: CDP {} · Steam API {} · Local copy {}",
+        cdp, steam_api, local
+    )
+}
+
+pub fn download_local_copy_warning(count: usize) -> String {
+    format!(
+        "{} file(s) were copied from local saves and may be older than the cloud version",
+        count
+    )
+}
+
+pub fn remote_storage_provider_label() -> &'static str {
+    "Web cloud file list source"
+}
+
+pub fn remote_storage_provider_cdp() -> &'static str {
+    "Steam built-in browser (CEF debugging port)"
+}
+
+pub fn remote_storage_provider_web() -> &'static str {
+    "Direct web request with session cookie"
+}
+
+pub fn session_cookie_label() -> &'static str {
+    "steamLoginSecure cookie"
+}
+
+pub fn session_cookie_saved() -> &'static str {
+    "Cookie saved"
+}
+
+pub fn session_cookie_hint() -> &'static str {
+    "Copy the steamLoginSecure cookie from a browser logged in to store.steampowered.com. It grants access to your account, so keep the config file private. No Steam restart is needed."
+}
+
+pub fn web_no_data_error() -> &'static str {
+    "Failed to read the Steam Cloud web page!\n\nThe session cookie may have expired. Copy a fresh steamLoginSecure cookie into Settings → Advanced."
+}
+
+pub fn collection_filter_label() -> &'static str {
+    "Collection:"
+}
+
+pub fn collection_all() -> &'static str {
+    "All games"
+}
+
+pub fn collection_favorites() -> &'static str {
+    "Favorites"
+}
+
+pub fn collection_hidden() -> &'static str {
+    "Hidden"
+}
+
+pub fn collection_dynamic_unsupported(name: &str) -> String {
+    format!("{} (dynamic rules not supported)", name)
+}
+
+pub fn no_games_in_collection() -> &'static str {
+    "No games in this collection"
+}
+
+pub fn compare_users_button() -> &'static str {
+    "Compare saves"
+}
+
+pub fn user_compare_title() -> &'static str {
+    "Compare Saves Across Accounts"
+}
+
+pub fn user_compare_source() -> &'static str {
+    "From:"
+}
+
+pub fn user_compare_target() -> &'static str {
+    "To:"
+}
+
+pub fn user_compare_run() -> &'static str {
+    "Compare"
+}
+
+pub fn user_compare_swap() -> &'static str {
+    "Swap"
+}
+
+pub fn user_compare_same_user() -> &'static str {
+    "Choose two different accounts"
+}
+
+pub fn user_compare_empty() -> &'static str {
+    "Neither account has saves for this game"
+}
+
+pub fn user_compare_select_different() -> &'static str {
+    "Select all differences"
+}
+
+pub fn user_compare_col_source() -> &'static str {
+    "Source"
+}
+
+pub fn user_compare_col_target() -> &'static str {
+    "Target"
+}
+
+pub fn user_compare_col_status() -> &'static str {
+    "Status"
+}
+
+pub fn cross_status_only_source() -> &'static str {
+    "Only in source"
+}
+
+pub fn cross_status_only_target() -> &'static str {
+    "Only in target"
+}
+
+pub fn cross_status_identical() -> &'static str {
+    "Identical"
+}
+
+pub fn cross_status_different() -> &'static str {
+    "Different"
+}
+
+pub fn copy_target_local() -> &'static str {
+    "Write to the target account's local save folder"
+}
+
+pub fn copy_target_api() -> &'static str {
+    "Upload through Steam (target account is logged in)"
+}
+
+pub fn copy_target_api_hint() -> &'static str {
+    "Uploading through Steam requires the target account to be logged in and connected to this game, and only covers files in the Steam remote folder (root 0)"
+}
+
+pub fn user_compare_copy(count: usize) -> String {
+    format!("Copy {} file(s)", count)
+}
+
+pub fn user_compare_copy_result(copied: usize, failed: usize) -> String {
+    format!("Copied {} file(s), {} failed", copied, failed)
+}
+
+pub fn account_name_label(name: &str) -> String {
+    format!("Account: {}", name)
+}
+
+pub fn last_login_label(time: &str) -> String {
+    format!("Last login: {}", time)
+}
+
+pub fn user_most_recent() -> &'static str {
+    "Most recent"
+}
+
+pub fn user_remember_password() -> &'static str {
+    "Remembers password"
+}
+
+pub fn user_auto_login() -> &'static str {
+    "Auto login"
+}
+
+pub fn symlink_migrate() -> &'static str {
+    "Move the existing folder into the link target and create the link (a backup is kept)"
+}
+
+pub fn symlink_migrated(count: usize) -> String {
+    format!("Migrated {} file(s) and created the link", count)
+}
+
+pub fn symlink_migrate_failed() -> &'static str {
+    "Migration failed"
+}
+
+pub fn symlink_undo_migration() -> &'static str {
+    "Undo migration"
+}
+
+pub fn symlink_migration_undone() -> &'static str {
+    "Migration undone, the original folder is restored"
+}
+
+pub fn symlink_undo_failed() -> &'static str {
+    "Undo failed"
+}
+
+pub fn symlink_discard_backup() -> &'static str {
+    "Keep the migration and delete its backup"
+}
+
+pub fn symlink_backup_discarded() -> &'static str {
+    "Migration backup deleted"
+}
+
+pub fn symlink_health_title() -> &'static str {
+    "Symlink Health"
+}
+
+pub fn symlink_health_check_all() -> &'static str {
+    "Check links of all games"
+}
+
+pub fn symlink_health_no_configs() -> &'static str {
+    "No symlinks are configured"
+}
+
+pub fn symlink_health_all_ok(total: usize) -> String {
+    format!("All {} configured link(s) are healthy", total)
+}
+
+pub fn symlink_health_summary(issues: usize, total: usize) -> String {
+    format!("{} of {} link(s) need attention", issues, total)
+}
+
+pub fn symlink_health_show_all() -> &'static str {
+    "Show healthy links"
+}
+
+pub fn symlink_platform_mismatch(platform: &str) -> String {
+    format!("Configured on {}", platform)
+}
+
+pub fn symlink_repair_create() -> &'static str {
+    "Create link"
+}
+
+pub fn symlink_repair_recreate() -> &'static str {
+    "Recreate link"
+}
+
+pub fn symlink_repair_migrate() -> &'static str {
+    "Migrate folder"
+}
+
+pub fn symlink_repair_adopt() -> &'static str {
+    "Use on this machine"
+}
+
+pub fn symlink_repair_remove() -> &'static str {
+    "Remove config"
+}
+
+pub fn symlink_repair_done() -> &'static str {
+    "Repaired"
+}
+
+pub fn symlink_repair_failed() -> &'static str {
+    "Repair failed"
+}
+
+pub fn symlink_sync_remove_missing() -> &'static str {
+    "Remove cloud files deleted locally when syncing"
+}
+
+pub fn symlink_sync_list_failed() -> &'static str {
+    "Failed to list cloud files"
+}
+
+pub fn symlink_sync_up_to_date() -> &'static str {
+    "Cloud is up to date, nothing to upload"
+}
+
+pub fn symlink_sync_report(
+    uploaded: usize,
+    unchanged: usize,
+    cloud_newer: usize,
+    removed: usize,
+) -> String {
+    format!(
+        "Uploaded {}, skipped {} unchanged, skipped {} newer in cloud, removed {}",
+        uploaded, unchanged, cloud_newer, removed
+    )
+}
+
+pub fn symlink_sync_cloud_only_hint(count: usize) -> String {
+    format!(
+        "{} cloud files no longer exist locally; enable removal to delete them",
+        count
+    )
+}
+
+pub fn symlink_export() -> &'static str {
+    "Export"
+}
+
+pub fn symlink_import() -> &'static str {
+    "Import"
+}
+
+pub fn symlink_export_hint() -> &'static str {
+    "Export this game's links; paths under known folders are stored portably"
+}
+
+pub fn symlink_portable_hint() -> &'static str {
+    "Stored relative to a known folder, usable on other machines"
+}
+
+pub fn symlink_exported(count: usize) -> String {
+    format!("Exported {} link configs", count)
+}
+
+pub fn symlink_imported(added: usize, skipped: usize) -> String {
+    format!(
+        "Imported {} link configs, skipped {} existing",
+        added, skipped
+    )
+}
+
+pub fn symlink_export_failed() -> &'static str {
+    "Export failed"
+}
+
+pub fn symlink_import_failed() -> &'static str {
+    "Import failed"
+}
+
+pub fn game_profile_button() -> &'static str {
+    "Game Profile"
+}
+
+pub fn game_profile_title(game_name: &str) -> String {
+    format!("Profile - {}", game_name)
+}
+
+pub fn game_profile_hint() -> &'static str {
+    "Settings here override the global preferences for this game only and are applied automatically on connect. Leave a field empty to keep the global behaviour."
+}
+
+pub fn game_profile_download_dir() -> &'static str {
+    "Download folder:"
+}
+
+pub fn game_profile_ask_each_time() -> &'static str {
+    "Ask each time"
+}
+
+pub fn game_profile_upload_root() -> &'static str {
+    "Default upload root:"
+}
+
+pub fn game_profile_upload_root_hint() -> &'static str {
+    "Cloud folder that new uploads are placed under"
+}
+
+pub fn game_profile_custom_filter() -> &'static str {
+    "Use game-specific upload filters"
+}
+
+pub fn game_profile_custom_filter_hint() -> &'static str {
+    "Changes made in the upload dialog are saved to this profile instead of the global settings"
+}
+
+pub fn game_profile_backup_interval() -> &'static str {
+    "Automatic backup every:"
+}
+
+pub fn game_profile_backup_interval_hint() -> &'static str {
+    "Back up all cloud files after connecting once this many hours have passed. 0 turns it off."
+}
+
+pub fn game_profile_last_backup(time: &str) -> String {
+    format!("Last automatic backup: {}", time)
+}
+
+pub fn game_profile_symlink_defaults() -> &'static str {
+    "Defaults for new symlinks"
+}
+
+pub fn game_profile_save() -> &'static str {
+    "Save"
+}
+
+pub fn game_profile_reset() -> &'static str {
+    "Reset"
+}
+
+pub fn game_profile_reset_hint() -> &'static str {
+    "Clear every override; saving removes the profile"
+}
+
+pub fn game_profile_saved() -> &'static str {
+    "Game profile saved"
+}
+
+pub fn game_profile_save_failed(err: &str) -> String {
+    format!("Failed to save game profile: {}", err)
+}
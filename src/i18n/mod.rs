@@ -999,6 +999,13 @@ impl I18n {
         }
     }
 
+    pub fn error_download_target_collision(&self, names: &str) -> String {
+        match self.lang {
+            Language::English => en::error_download_target_collision(names),
+            Language::Chinese => zh::error_download_target_collision(names),
+        }
+    }
+
     pub fn error_not_connected(&self) -> &'static str {
         match self.lang {
             Language::English => en::error_not_connected(),
@@ -1969,13 +1976,6 @@ impl I18n {
         }
     }
 
-    pub fn files(&self) -> &'static str {
-        match self.lang {
-            Language::English => en::files(),
-            Language::Chinese => zh::files(),
-        }
-    }
-
     // ========== AppInfo 对话框和 UFS 配置管理 ==========
 
     pub fn appinfo_tab_local_ufs(&self) -> &'static str {
@@ -2769,4 +2769,1249 @@ impl I18n {
             Language::Chinese => zh::theme_system(),
         }
     }
+
+    // ========== UFS 配置分享包 ==========
+
+    pub fn ufs_export_config(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::ufs_export_config(),
+            Language::Chinese => zh::ufs_export_config(),
+        }
+    }
+
+    pub fn ufs_export_config_tooltip(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::ufs_export_config_tooltip(),
+            Language::Chinese => zh::ufs_export_config_tooltip(),
+        }
+    }
+
+    pub fn ufs_export_all_configs(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::ufs_export_all_configs(),
+            Language::Chinese => zh::ufs_export_all_configs(),
+        }
+    }
+
+    pub fn ufs_export_all_configs_tooltip(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::ufs_export_all_configs_tooltip(),
+            Language::Chinese => zh::ufs_export_all_configs_tooltip(),
+        }
+    }
+
+    pub fn ufs_import_config(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::ufs_import_config(),
+            Language::Chinese => zh::ufs_import_config(),
+        }
+    }
+
+    pub fn ufs_import_policy_label(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::ufs_import_policy_label(),
+            Language::Chinese => zh::ufs_import_policy_label(),
+        }
+    }
+
+    pub fn ufs_import_policy_skip(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::ufs_import_policy_skip(),
+            Language::Chinese => zh::ufs_import_policy_skip(),
+        }
+    }
+
+    pub fn ufs_import_policy_overwrite(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::ufs_import_policy_overwrite(),
+            Language::Chinese => zh::ufs_import_policy_overwrite(),
+        }
+    }
+
+    pub fn ufs_import_policy_merge(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::ufs_import_policy_merge(),
+            Language::Chinese => zh::ufs_import_policy_merge(),
+        }
+    }
+
+    pub fn ufs_export_success(&self, count: usize, path: &str) -> String {
+        match self.lang {
+            Language::English => en::ufs_export_success(count, path),
+            Language::Chinese => zh::ufs_export_success(count, path),
+        }
+    }
+
+    pub fn ufs_export_error(&self, error: &str) -> String {
+        match self.lang {
+            Language::English => en::ufs_export_error(error),
+            Language::Chinese => zh::ufs_export_error(error),
+        }
+    }
+
+    pub fn ufs_import_success(
+        &self,
+        added: usize,
+        overwritten: usize,
+        merged: usize,
+        skipped: usize,
+    ) -> String {
+        match self.lang {
+            Language::English => en::ufs_import_success(added, overwritten, merged, skipped),
+            Language::Chinese => zh::ufs_import_success(added, overwritten, merged, skipped),
+        }
+    }
+
+    pub fn ufs_import_error(&self, error: &str) -> String {
+        match self.lang {
+            Language::English => en::ufs_import_error(error),
+            Language::Chinese => zh::ufs_import_error(error),
+        }
+    }
+
+    // ========== UFS 配置校验 ==========
+
+    pub fn ufs_validation_title(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::ufs_validation_title(),
+            Language::Chinese => zh::ufs_validation_title(),
+        }
+    }
+
+    pub fn ufs_validation_ok(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::ufs_validation_ok(),
+            Language::Chinese => zh::ufs_validation_ok(),
+        }
+    }
+
+    pub fn ufs_validation_summary(&self, errors: usize, warnings: usize) -> String {
+        match self.lang {
+            Language::English => en::ufs_validation_summary(errors, warnings),
+            Language::Chinese => zh::ufs_validation_summary(errors, warnings),
+        }
+    }
+
+    pub fn ufs_validation_blocked(&self, errors: usize) -> String {
+        match self.lang {
+            Language::English => en::ufs_validation_blocked(errors),
+            Language::Chinese => zh::ufs_validation_blocked(errors),
+        }
+    }
+
+    pub fn ufs_issue_unknown_root(&self, index: usize, root: &str) -> String {
+        match self.lang {
+            Language::English => en::ufs_issue_unknown_root(index, root),
+            Language::Chinese => zh::ufs_issue_unknown_root(index, root),
+        }
+    }
+
+    pub fn ufs_issue_empty_pattern(&self, index: usize) -> String {
+        match self.lang {
+            Language::English => en::ufs_issue_empty_pattern(index),
+            Language::Chinese => zh::ufs_issue_empty_pattern(index),
+        }
+    }
+
+    pub fn ufs_issue_absolute_path(&self, index: usize, path: &str) -> String {
+        match self.lang {
+            Language::English => en::ufs_issue_absolute_path(index, path),
+            Language::Chinese => zh::ufs_issue_absolute_path(index, path),
+        }
+    }
+
+    pub fn ufs_issue_parent_segment(&self, index: usize, path: &str) -> String {
+        match self.lang {
+            Language::English => en::ufs_issue_parent_segment(index, path),
+            Language::Chinese => zh::ufs_issue_parent_segment(index, path),
+        }
+    }
+
+    pub fn ufs_issue_unknown_platform(&self, index: usize, platform: &str) -> String {
+        match self.lang {
+            Language::English => en::ufs_issue_unknown_platform(index, platform),
+            Language::Chinese => zh::ufs_issue_unknown_platform(index, platform),
+        }
+    }
+
+    pub fn ufs_issue_overlapping_savefiles(&self, first: usize, second: usize) -> String {
+        match self.lang {
+            Language::English => en::ufs_issue_overlapping_savefiles(first, second),
+            Language::Chinese => zh::ufs_issue_overlapping_savefiles(first, second),
+        }
+    }
+
+    pub fn ufs_issue_unknown_override_root(&self, index: usize, root: &str) -> String {
+        match self.lang {
+            Language::English => en::ufs_issue_unknown_override_root(index, root),
+            Language::Chinese => zh::ufs_issue_unknown_override_root(index, root),
+        }
+    }
+
+    pub fn ufs_issue_invalid_new_root(&self, index: usize, root: &str) -> String {
+        match self.lang {
+            Language::English => en::ufs_issue_invalid_new_root(index, root),
+            Language::Chinese => zh::ufs_issue_invalid_new_root(index, root),
+        }
+    }
+
+    pub fn ufs_issue_unknown_override_os(&self, index: usize, os: &str) -> String {
+        match self.lang {
+            Language::English => en::ufs_issue_unknown_override_os(index, os),
+            Language::Chinese => zh::ufs_issue_unknown_override_os(index, os),
+        }
+    }
+
+    pub fn ufs_issue_duplicate_override(&self, first: usize, second: usize) -> String {
+        match self.lang {
+            Language::English => en::ufs_issue_duplicate_override(first, second),
+            Language::Chinese => zh::ufs_issue_duplicate_override(first, second),
+        }
+    }
+
+    // ========== AppInfo 完整检查器 ==========
+
+    pub fn appinfo_tab_full_tree(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::appinfo_tab_full_tree(),
+            Language::Chinese => zh::appinfo_tab_full_tree(),
+        }
+    }
+
+    pub fn appinfo_search_placeholder(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::appinfo_search_placeholder(),
+            Language::Chinese => zh::appinfo_search_placeholder(),
+        }
+    }
+
+    pub fn appinfo_show_as_json(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::appinfo_show_as_json(),
+            Language::Chinese => zh::appinfo_show_as_json(),
+        }
+    }
+
+    pub fn appinfo_copy_output(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::appinfo_copy_output(),
+            Language::Chinese => zh::appinfo_copy_output(),
+        }
+    }
+
+    pub fn appinfo_full_loading(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::appinfo_full_loading(),
+            Language::Chinese => zh::appinfo_full_loading(),
+        }
+    }
+
+    pub fn appinfo_full_summary(
+        &self,
+        version: u32,
+        change_number: u32,
+        info_state: u32,
+    ) -> String {
+        match self.lang {
+            Language::English => en::appinfo_full_summary(version, change_number, info_state),
+            Language::Chinese => zh::appinfo_full_summary(version, change_number, info_state),
+        }
+    }
+
+    pub fn appinfo_full_no_match(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::appinfo_full_no_match(),
+            Language::Chinese => zh::appinfo_full_no_match(),
+        }
+    }
+
+    pub fn appinfo_full_load_error(&self, error: &str) -> String {
+        match self.lang {
+            Language::English => en::appinfo_full_load_error(error),
+            Language::Chinese => zh::appinfo_full_load_error(error),
+        }
+    }
+
+    // ========== appinfo.vdf 版本 ==========
+
+    pub fn error_appinfo_unsupported_version(&self, found: &str, supported: &str) -> String {
+        match self.lang {
+            Language::English => en::error_appinfo_unsupported_version(found, supported),
+            Language::Chinese => zh::error_appinfo_unsupported_version(found, supported),
+        }
+    }
+
+    // ========== 上传配额预测 ==========
+
+    pub fn upload_limit_none(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_limit_none(),
+            Language::Chinese => zh::upload_limit_none(),
+        }
+    }
+
+    pub fn upload_projection_bytes(&self, current: &str, projected: &str, limit: &str) -> String {
+        match self.lang {
+            Language::English => en::upload_projection_bytes(current, projected, limit),
+            Language::Chinese => zh::upload_projection_bytes(current, projected, limit),
+        }
+    }
+
+    pub fn upload_projection_files(&self, current: usize, projected: usize, limit: &str) -> String {
+        match self.lang {
+            Language::English => en::upload_projection_files(current, projected, limit),
+            Language::Chinese => zh::upload_projection_files(current, projected, limit),
+        }
+    }
+
+    pub fn upload_projection_available(&self, growth: &str, available: &str) -> String {
+        match self.lang {
+            Language::English => en::upload_projection_available(growth, available),
+            Language::Chinese => zh::upload_projection_available(growth, available),
+        }
+    }
+
+    pub fn upload_projection_overwrites(&self, count: usize, size: &str) -> String {
+        match self.lang {
+            Language::English => en::upload_projection_overwrites(count, size),
+            Language::Chinese => zh::upload_projection_overwrites(count, size),
+        }
+    }
+
+    pub fn quota_issue_exceeded(&self, projected: &str, limit: &str) -> String {
+        match self.lang {
+            Language::English => en::quota_issue_exceeded(projected, limit),
+            Language::Chinese => zh::quota_issue_exceeded(projected, limit),
+        }
+    }
+
+    pub fn quota_issue_max_files(&self, projected: usize, limit: u32) -> String {
+        match self.lang {
+            Language::English => en::quota_issue_max_files(projected, limit),
+            Language::Chinese => zh::quota_issue_max_files(projected, limit),
+        }
+    }
+
+    pub fn quota_issue_available(&self, needed: &str, available: &str) -> String {
+        match self.lang {
+            Language::English => en::quota_issue_available(needed, available),
+            Language::Chinese => zh::quota_issue_available(needed, available),
+        }
+    }
+
+    pub fn quota_issue_near(&self, projected: &str, limit: &str) -> String {
+        match self.lang {
+            Language::English => en::quota_issue_near(projected, limit),
+            Language::Chinese => zh::quota_issue_near(projected, limit),
+        }
+    }
+
+    pub fn upload_blocked_by_quota(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_blocked_by_quota(),
+            Language::Chinese => zh::upload_blocked_by_quota(),
+        }
+    }
+
+    // ========== 上传过滤规则 ==========
+
+    pub fn upload_filters_header(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_filters_header(),
+            Language::Chinese => zh::upload_filters_header(),
+        }
+    }
+
+    pub fn upload_skip_hidden(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_skip_hidden(),
+            Language::Chinese => zh::upload_skip_hidden(),
+        }
+    }
+
+    pub fn upload_skip_temp(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_skip_temp(),
+            Language::Chinese => zh::upload_skip_temp(),
+        }
+    }
+
+    pub fn upload_patterns_hint(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_patterns_hint(),
+            Language::Chinese => zh::upload_patterns_hint(),
+        }
+    }
+
+    pub fn upload_include_patterns(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_include_patterns(),
+            Language::Chinese => zh::upload_include_patterns(),
+        }
+    }
+
+    pub fn upload_exclude_patterns(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_exclude_patterns(),
+            Language::Chinese => zh::upload_exclude_patterns(),
+        }
+    }
+
+    pub fn upload_path_rules(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_path_rules(),
+            Language::Chinese => zh::upload_path_rules(),
+        }
+    }
+
+    pub fn upload_rule_strip_prefix(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_rule_strip_prefix(),
+            Language::Chinese => zh::upload_rule_strip_prefix(),
+        }
+    }
+
+    pub fn upload_rule_lowercase(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_rule_lowercase(),
+            Language::Chinese => zh::upload_rule_lowercase(),
+        }
+    }
+
+    pub fn upload_rule_replace_segment(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_rule_replace_segment(),
+            Language::Chinese => zh::upload_rule_replace_segment(),
+        }
+    }
+
+    pub fn upload_add_rule(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_add_rule(),
+            Language::Chinese => zh::upload_add_rule(),
+        }
+    }
+
+    pub fn upload_apply_filters(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_apply_filters(),
+            Language::Chinese => zh::upload_apply_filters(),
+        }
+    }
+
+    pub fn upload_skipped_files(&self, count: usize) -> String {
+        match self.lang {
+            Language::English => en::upload_skipped_files(count),
+            Language::Chinese => zh::upload_skipped_files(count),
+        }
+    }
+
+    pub fn upload_skip_reason_hidden(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_skip_reason_hidden(),
+            Language::Chinese => zh::upload_skip_reason_hidden(),
+        }
+    }
+
+    pub fn upload_skip_reason_temp(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_skip_reason_temp(),
+            Language::Chinese => zh::upload_skip_reason_temp(),
+        }
+    }
+
+    pub fn upload_skip_reason_excluded(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_skip_reason_excluded(),
+            Language::Chinese => zh::upload_skip_reason_excluded(),
+        }
+    }
+
+    pub fn upload_skip_reason_not_included(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_skip_reason_not_included(),
+            Language::Chinese => zh::upload_skip_reason_not_included(),
+        }
+    }
+
+    // ========== 上传续传 ==========
+
+    pub fn upload_resume_title(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_resume_title(),
+            Language::Chinese => zh::upload_resume_title(),
+        }
+    }
+
+    pub fn upload_resume_message(&self, app_id: u32, pending: usize, total: usize) -> String {
+        match self.lang {
+            Language::English => en::upload_resume_message(app_id, pending, total),
+            Language::Chinese => zh::upload_resume_message(app_id, pending, total),
+        }
+    }
+
+    pub fn upload_resume_updated(&self, time: &str) -> String {
+        match self.lang {
+            Language::English => en::upload_resume_updated(time),
+            Language::Chinese => zh::upload_resume_updated(time),
+        }
+    }
+
+    pub fn upload_resume_hint(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_resume_hint(),
+            Language::Chinese => zh::upload_resume_hint(),
+        }
+    }
+
+    pub fn upload_resume_button(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_resume_button(),
+            Language::Chinese => zh::upload_resume_button(),
+        }
+    }
+
+    pub fn upload_resume_later(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_resume_later(),
+            Language::Chinese => zh::upload_resume_later(),
+        }
+    }
+
+    pub fn upload_resume_discard(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_resume_discard(),
+            Language::Chinese => zh::upload_resume_discard(),
+        }
+    }
+
+    pub fn upload_resume_nothing_left(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_resume_nothing_left(),
+            Language::Chinese => zh::upload_resume_nothing_left(),
+        }
+    }
+
+    pub fn upload_skipped_unchanged(&self, count: usize) -> String {
+        match self.lang {
+            Language::English => en::upload_skipped_unchanged(count),
+            Language::Chinese => zh::upload_skipped_unchanged(count),
+        }
+    }
+
+    pub fn upload_quota_list_failed(&self, error: &str) -> String {
+        match self.lang {
+            Language::English => en::upload_quota_list_failed(error),
+            Language::Chinese => zh::upload_quota_list_failed(error),
+        }
+    }
+
+    // ========== 上传校验 ==========
+
+    pub fn upload_verify_after_write(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_verify_after_write(),
+            Language::Chinese => zh::upload_verify_after_write(),
+        }
+    }
+
+    pub fn upload_verify_after_write_hint(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::upload_verify_after_write_hint(),
+            Language::Chinese => zh::upload_verify_after_write_hint(),
+        }
+    }
+
+    // ========== 并发下载 ==========
+
+    pub fn download_bytes_progress(&self, done: &str, total: &str) -> String {
+        match self.lang {
+            Language::English => en::download_bytes_progress(done, total),
+            Language::Chinese => zh::download_bytes_progress(done, total),
+        }
+    }
+
+    // ========== 下载来源 ==========
+
+    pub fn download_source_stats(&self, cdp: usize, steam_api: usize, local: usize) -> String {
+        match self.lang {
+            Language::English => en::download_source_stats(cdp, steam_api, local),
+            Language::Chinese => zh::download_source_stats(cdp, steam_api, local),
+        }
+    }
+
+    pub fn download_local_copy_warning(&self, count: usize) -> String {
+        match self.lang {
+            Language::English => en::download_local_copy_warning(count),
+            Language::Chinese => zh::download_local_copy_warning(count),
+        }
+    }
+
+    // ========== 网页版云存储读取方式 ==========
+
+    pub fn remote_storage_provider_label(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::remote_storage_provider_label(),
+            Language::Chinese => zh::remote_storage_provider_label(),
+        }
+    }
+
+    pub fn remote_storage_provider_cdp(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::remote_storage_provider_cdp(),
+            Language::Chinese => zh::remote_storage_provider_cdp(),
+        }
+    }
+
+    pub fn remote_storage_provider_web(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::remote_storage_provider_web(),
+            Language::Chinese => zh::remote_storage_provider_web(),
+        }
+    }
+
+    pub fn session_cookie_label(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::session_cookie_label(),
+            Language::Chinese => zh::session_cookie_label(),
+        }
+    }
+
+    pub fn session_cookie_saved(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::session_cookie_saved(),
+            Language::Chinese => zh::session_cookie_saved(),
+        }
+    }
+
+    pub fn session_cookie_hint(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::session_cookie_hint(),
+            Language::Chinese => zh::session_cookie_hint(),
+        }
+    }
+
+    pub fn web_no_data_error(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::web_no_data_error(),
+            Language::Chinese => zh::web_no_data_error(),
+        }
+    }
+
+    // ========== 收藏夹筛选 ==========
+
+    pub fn collection_filter_label(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::collection_filter_label(),
+            Language::Chinese => zh::collection_filter_label(),
+        }
+    }
+
+    pub fn collection_all(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::collection_all(),
+            Language::Chinese => zh::collection_all(),
+        }
+    }
+
+    pub fn collection_favorites(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::collection_favorites(),
+            Language::Chinese => zh::collection_favorites(),
+        }
+    }
+
+    pub fn collection_hidden(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::collection_hidden(),
+            Language::Chinese => zh::collection_hidden(),
+        }
+    }
+
+    pub fn collection_dynamic_unsupported(&self, name: &str) -> String {
+        match self.lang {
+            Language::English => en::collection_dynamic_unsupported(name),
+            Language::Chinese => zh::collection_dynamic_unsupported(name),
+        }
+    }
+
+    pub fn no_games_in_collection(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::no_games_in_collection(),
+            Language::Chinese => zh::no_games_in_collection(),
+        }
+    }
+
+    // ========== 跨账号存档对比 ==========
+
+    pub fn compare_users_button(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::compare_users_button(),
+            Language::Chinese => zh::compare_users_button(),
+        }
+    }
+
+    pub fn user_compare_title(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::user_compare_title(),
+            Language::Chinese => zh::user_compare_title(),
+        }
+    }
+
+    pub fn user_compare_source(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::user_compare_source(),
+            Language::Chinese => zh::user_compare_source(),
+        }
+    }
+
+    pub fn user_compare_target(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::user_compare_target(),
+            Language::Chinese => zh::user_compare_target(),
+        }
+    }
+
+    pub fn user_compare_run(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::user_compare_run(),
+            Language::Chinese => zh::user_compare_run(),
+        }
+    }
+
+    pub fn user_compare_swap(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::user_compare_swap(),
+            Language::Chinese => zh::user_compare_swap(),
+        }
+    }
+
+    pub fn user_compare_same_user(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::user_compare_same_user(),
+            Language::Chinese => zh::user_compare_same_user(),
+        }
+    }
+
+    pub fn user_compare_empty(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::user_compare_empty(),
+            Language::Chinese => zh::user_compare_empty(),
+        }
+    }
+
+    pub fn user_compare_select_different(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::user_compare_select_different(),
+            Language::Chinese => zh::user_compare_select_different(),
+        }
+    }
+
+    pub fn user_compare_col_source(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::user_compare_col_source(),
+            Language::Chinese => zh::user_compare_col_source(),
+        }
+    }
+
+    pub fn user_compare_col_target(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::user_compare_col_target(),
+            Language::Chinese => zh::user_compare_col_target(),
+        }
+    }
+
+    pub fn user_compare_col_status(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::user_compare_col_status(),
+            Language::Chinese => zh::user_compare_col_status(),
+        }
+    }
+
+    pub fn cross_status_only_source(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::cross_status_only_source(),
+            Language::Chinese => zh::cross_status_only_source(),
+        }
+    }
+
+    pub fn cross_status_only_target(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::cross_status_only_target(),
+            Language::Chinese => zh::cross_status_only_target(),
+        }
+    }
+
+    pub fn cross_status_identical(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::cross_status_identical(),
+            Language::Chinese => zh::cross_status_identical(),
+        }
+    }
+
+    pub fn cross_status_different(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::cross_status_different(),
+            Language::Chinese => zh::cross_status_different(),
+        }
+    }
+
+    pub fn copy_target_local(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::copy_target_local(),
+            Language::Chinese => zh::copy_target_local(),
+        }
+    }
+
+    pub fn copy_target_api(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::copy_target_api(),
+            Language::Chinese => zh::copy_target_api(),
+        }
+    }
+
+    pub fn copy_target_api_hint(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::copy_target_api_hint(),
+            Language::Chinese => zh::copy_target_api_hint(),
+        }
+    }
+
+    pub fn user_compare_copy(&self, count: usize) -> String {
+        match self.lang {
+            Language::English => en::user_compare_copy(count),
+            Language::Chinese => zh::user_compare_copy(count),
+        }
+    }
+
+    pub fn user_compare_copy_result(&self, copied: usize, failed: usize) -> String {
+        match self.lang {
+            Language::English => en::user_compare_copy_result(copied, failed),
+            Language::Chinese => zh::user_compare_copy_result(copied, failed),
+        }
+    }
+
+    // ========== 账号信息 ==========
+
+    pub fn account_name_label(&self, name: &str) -> String {
+        match self.lang {
+            Language::English => en::account_name_label(name),
+            Language::Chinese => zh::account_name_label(name),
+        }
+    }
+
+    pub fn last_login_label(&self, time: &str) -> String {
+        match self.lang {
+            Language::English => en::last_login_label(time),
+            Language::Chinese => zh::last_login_label(time),
+        }
+    }
+
+    pub fn user_most_recent(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::user_most_recent(),
+            Language::Chinese => zh::user_most_recent(),
+        }
+    }
+
+    pub fn user_remember_password(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::user_remember_password(),
+            Language::Chinese => zh::user_remember_password(),
+        }
+    }
+
+    pub fn user_auto_login(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::user_auto_login(),
+            Language::Chinese => zh::user_auto_login(),
+        }
+    }
+
+    // ========== 软链接迁移 ==========
+
+    pub fn symlink_migrate(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_migrate(),
+            Language::Chinese => zh::symlink_migrate(),
+        }
+    }
+
+    pub fn symlink_migrated(&self, count: usize) -> String {
+        match self.lang {
+            Language::English => en::symlink_migrated(count),
+            Language::Chinese => zh::symlink_migrated(count),
+        }
+    }
+
+    pub fn symlink_migrate_failed(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_migrate_failed(),
+            Language::Chinese => zh::symlink_migrate_failed(),
+        }
+    }
+
+    pub fn symlink_undo_migration(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_undo_migration(),
+            Language::Chinese => zh::symlink_undo_migration(),
+        }
+    }
+
+    pub fn symlink_migration_undone(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_migration_undone(),
+            Language::Chinese => zh::symlink_migration_undone(),
+        }
+    }
+
+    pub fn symlink_undo_failed(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_undo_failed(),
+            Language::Chinese => zh::symlink_undo_failed(),
+        }
+    }
+
+    pub fn symlink_discard_backup(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_discard_backup(),
+            Language::Chinese => zh::symlink_discard_backup(),
+        }
+    }
+
+    pub fn symlink_backup_discarded(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_backup_discarded(),
+            Language::Chinese => zh::symlink_backup_discarded(),
+        }
+    }
+
+    // ========== 软链接健康检查 ==========
+
+    pub fn symlink_health_title(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_health_title(),
+            Language::Chinese => zh::symlink_health_title(),
+        }
+    }
+
+    pub fn symlink_health_check_all(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_health_check_all(),
+            Language::Chinese => zh::symlink_health_check_all(),
+        }
+    }
+
+    pub fn symlink_health_no_configs(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_health_no_configs(),
+            Language::Chinese => zh::symlink_health_no_configs(),
+        }
+    }
+
+    pub fn symlink_health_all_ok(&self, total: usize) -> String {
+        match self.lang {
+            Language::English => en::symlink_health_all_ok(total),
+            Language::Chinese => zh::symlink_health_all_ok(total),
+        }
+    }
+
+    pub fn symlink_health_summary(&self, issues: usize, total: usize) -> String {
+        match self.lang {
+            Language::English => en::symlink_health_summary(issues, total),
+            Language::Chinese => zh::symlink_health_summary(issues, total),
+        }
+    }
+
+    pub fn symlink_health_show_all(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_health_show_all(),
+            Language::Chinese => zh::symlink_health_show_all(),
+        }
+    }
+
+    pub fn symlink_platform_mismatch(&self, platform: &str) -> String {
+        match self.lang {
+            Language::English => en::symlink_platform_mismatch(platform),
+            Language::Chinese => zh::symlink_platform_mismatch(platform),
+        }
+    }
+
+    pub fn symlink_repair_create(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_repair_create(),
+            Language::Chinese => zh::symlink_repair_create(),
+        }
+    }
+
+    pub fn symlink_repair_recreate(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_repair_recreate(),
+            Language::Chinese => zh::symlink_repair_recreate(),
+        }
+    }
+
+    pub fn symlink_repair_migrate(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_repair_migrate(),
+            Language::Chinese => zh::symlink_repair_migrate(),
+        }
+    }
+
+    pub fn symlink_repair_adopt(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_repair_adopt(),
+            Language::Chinese => zh::symlink_repair_adopt(),
+        }
+    }
+
+    pub fn symlink_repair_remove(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_repair_remove(),
+            Language::Chinese => zh::symlink_repair_remove(),
+        }
+    }
+
+    pub fn symlink_repair_done(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_repair_done(),
+            Language::Chinese => zh::symlink_repair_done(),
+        }
+    }
+
+    pub fn symlink_repair_failed(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_repair_failed(),
+            Language::Chinese => zh::symlink_repair_failed(),
+        }
+    }
+
+    // ========== 软链接增量同步 ==========
+
+    pub fn symlink_sync_remove_missing(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_sync_remove_missing(),
+            Language::Chinese => zh::symlink_sync_remove_missing(),
+        }
+    }
+
+    pub fn symlink_sync_list_failed(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_sync_list_failed(),
+            Language::Chinese => zh::symlink_sync_list_failed(),
+        }
+    }
+
+    pub fn symlink_sync_up_to_date(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_sync_up_to_date(),
+            Language::Chinese => zh::symlink_sync_up_to_date(),
+        }
+    }
+
+    pub fn symlink_sync_report(
+        &self,
+        uploaded: usize,
+        unchanged: usize,
+        cloud_newer: usize,
+        removed: usize,
+    ) -> String {
+        match self.lang {
+            Language::English => en::symlink_sync_report(uploaded, unchanged, cloud_newer, removed),
+            Language::Chinese => zh::symlink_sync_report(uploaded, unchanged, cloud_newer, removed),
+        }
+    }
+
+    pub fn symlink_sync_cloud_only_hint(&self, count: usize) -> String {
+        match self.lang {
+            Language::English => en::symlink_sync_cloud_only_hint(count),
+            Language::Chinese => zh::symlink_sync_cloud_only_hint(count),
+        }
+    }
+
+    // ========== 软链接配置分享 ==========
+
+    pub fn symlink_export(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_export(),
+            Language::Chinese => zh::symlink_export(),
+        }
+    }
+
+    pub fn symlink_import(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_import(),
+            Language::Chinese => zh::symlink_import(),
+        }
+    }
+
+    pub fn symlink_export_hint(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_export_hint(),
+            Language::Chinese => zh::symlink_export_hint(),
+        }
+    }
+
+    pub fn symlink_portable_hint(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_portable_hint(),
+            Language::Chinese => zh::symlink_portable_hint(),
+        }
+    }
+
+    pub fn symlink_exported(&self, count: usize) -> String {
+        match self.lang {
+            Language::English => en::symlink_exported(count),
+            Language::Chinese => zh::symlink_exported(count),
+        }
+    }
+
+    pub fn symlink_imported(&self, added: usize, skipped: usize) -> String {
+        match self.lang {
+            Language::English => en::symlink_imported(added, skipped),
+            Language::Chinese => zh::symlink_imported(added, skipped),
+        }
+    }
+
+    pub fn symlink_export_failed(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_export_failed(),
+            Language::Chinese => zh::symlink_export_failed(),
+        }
+    }
+
+    pub fn symlink_import_failed(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::symlink_import_failed(),
+            Language::Chinese => zh::symlink_import_failed(),
+        }
+    }
+
+    // ========== 游戏配置档 ==========
+
+    pub fn game_profile_button(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::game_profile_button(),
+            Language::Chinese => zh::game_profile_button(),
+        }
+    }
+
+    pub fn game_profile_title(&self, game_name: &str) -> String {
+        match self.lang {
+            Language::English => en::game_profile_title(game_name),
+            Language::Chinese => zh::game_profile_title(game_name),
+        }
+    }
+
+    pub fn game_profile_hint(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::game_profile_hint(),
+            Language::Chinese => zh::game_profile_hint(),
+        }
+    }
+
+    pub fn game_profile_download_dir(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::game_profile_download_dir(),
+            Language::Chinese => zh::game_profile_download_dir(),
+        }
+    }
+
+    pub fn game_profile_ask_each_time(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::game_profile_ask_each_time(),
+            Language::Chinese => zh::game_profile_ask_each_time(),
+        }
+    }
+
+    pub fn game_profile_upload_root(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::game_profile_upload_root(),
+            Language::Chinese => zh::game_profile_upload_root(),
+        }
+    }
+
+    pub fn game_profile_upload_root_hint(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::game_profile_upload_root_hint(),
+            Language::Chinese => zh::game_profile_upload_root_hint(),
+        }
+    }
+
+    pub fn game_profile_custom_filter(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::game_profile_custom_filter(),
+            Language::Chinese => zh::game_profile_custom_filter(),
+        }
+    }
+
+    pub fn game_profile_custom_filter_hint(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::game_profile_custom_filter_hint(),
+            Language::Chinese => zh::game_profile_custom_filter_hint(),
+        }
+    }
+
+    pub fn game_profile_backup_interval(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::game_profile_backup_interval(),
+            Language::Chinese => zh::game_profile_backup_interval(),
+        }
+    }
+
+    pub fn game_profile_backup_interval_hint(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::game_profile_backup_interval_hint(),
+            Language::Chinese => zh::game_profile_backup_interval_hint(),
+        }
+    }
+
+    pub fn game_profile_last_backup(&self, time: &str) -> String {
+        match self.lang {
+            Language::English => en::game_profile_last_backup(time),
+            Language::Chinese => zh::game_profile_last_backup(time),
+        }
+    }
+
+    pub fn game_profile_symlink_defaults(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::game_profile_symlink_defaults(),
+            Language::Chinese => zh::game_profile_symlink_defaults(),
+        }
+    }
+
+    pub fn game_profile_save(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::game_profile_save(),
+            Language::Chinese => zh::game_profile_save(),
+        }
+    }
+
+    pub fn game_profile_reset(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::game_profile_reset(),
+            Language::Chinese => zh::game_profile_reset(),
+        }
+    }
+
+    pub fn game_profile_reset_hint(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::game_profile_reset_hint(),
+            Language::Chinese => zh::game_profile_reset_hint(),
+        }
+    }
+
+    pub fn game_profile_saved(&self) -> &'static str {
+        match self.lang {
+            Language::English => en::game_profile_saved(),
+            Language::Chinese => zh::game_profile_saved(),
+        }
+    }
+
+    pub fn game_profile_save_failed(&self, err: &str) -> String {
+        match self.lang {
+            Language::English => en::game_profile_save_failed(err),
+            Language::Chinese => zh::game_profile_save_failed(err),
+        }
+    }
 }
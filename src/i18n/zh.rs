@@ -502,6 +502,13 @@ pub fn error_no_files_selected() -> &'static str {
     "请选择要操作的文件"
 }
 
+pub fn error_download_target_collision(names: &str) -> String {
+    format!(
+        "以下文件在不同 root 下同名，下载到同一目录会互相覆盖: {}\n请在配置文件 [download] 中设置 mirror_root_layout = true，按 root 分目录保存",
+        names
+    )
+}
+
 pub fn error_not_connected() -> &'static str {
     "未连接到 Steam"
 }
@@ -1018,7 +1025,7 @@ pub fn symlink_experimental_title() -> &'static str {
 }
 
 pub fn symlink_experimental_desc() -> &'static str {
-    "创建软链接后会自动同步目录下的文件到云端。点击云端上传按钮可手动同步新增文件。"
+    "创建软链接后会自动同步目录下的文件到云端。点击云端上传按钮可手动同步有变化的文件。"
 }
 
 pub fn symlink_sync_files() -> &'static str {
@@ -1049,10 +1056,6 @@ pub fn symlink_sync_scan_failed() -> &'static str {
     "扫描目录失败"
 }
 
-pub fn files() -> &'static str {
-    "个文件"
-}
-
 pub fn appinfo_tab_local_ufs() -> &'static str {
     "本地 UFS 配置"
 }
@@ -1505,3 +1508,735 @@ pub fn theme_dark() -> &'static str {
 pub fn theme_system() -> &'static str {
     "跟随系统"
 }
+
+pub fn ufs_export_config() -> &'static str {
+    "导出"
+}
+
+pub fn ufs_export_config_tooltip() -> &'static str {
+    "将当前配置导出为可分享的 JSON/VDF 文件"
+}
+
+pub fn ufs_export_all_configs() -> &'static str {
+    "导出全部"
+}
+
+pub fn ufs_export_all_configs_tooltip() -> &'static str {
+    "将所有已保存的自定义配置导出到一个分享包"
+}
+
+pub fn ufs_import_config() -> &'static str {
+    "导入"
+}
+
+pub fn ufs_import_policy_label() -> &'static str {
+    "冲突时:"
+}
+
+pub fn ufs_import_policy_skip() -> &'static str {
+    "保留本地"
+}
+
+pub fn ufs_import_policy_overwrite() -> &'static str {
+    "覆盖"
+}
+
+pub fn ufs_import_policy_merge() -> &'static str {
+    "合并"
+}
+
+pub fn ufs_export_success(count: usize, path: &str) -> String {
+    format!("已导出 {} 个配置到 {}", count, path)
+}
+
+pub fn ufs_export_error(error: &str) -> String {
+    format!("导出失败: {}", error)
+}
+
+pub fn ufs_import_success(
+    added: usize,
+    overwritten: usize,
+    merged: usize,
+    skipped: usize,
+) -> String {
+    format!(
+        "导入完成: 新增 {}, 覆盖 {}, 合并 {}, 跳过 {}",
+        added, overwritten, merged, skipped
+    )
+}
+
+pub fn ufs_import_error(error: &str) -> String {
+    format!("导入失败: {}", error)
+}
+
+pub fn ufs_validation_title() -> &'static str {
+    "配置校验"
+}
+
+pub fn ufs_validation_ok() -> &'static str {
+    "未发现问题"
+}
+
+pub fn ufs_validation_summary(errors: usize, warnings: usize) -> String {
+    format!("{} 个错误，{} 个警告", errors, warnings)
+}
+
+pub fn ufs_validation_blocked(errors: usize) -> String {
+    format!("已阻止注入：配置中有 {} 个错误，请先修正", errors)
+}
+
+pub fn ufs_issue_unknown_root(index: usize, root: &str) -> String {
+    format!("存档规则 #{}：无法识别的 root \"{}\"", index, root)
+}
+
+pub fn ufs_issue_empty_pattern(index: usize) -> String {
+    format!("存档规则 #{}：匹配模式为空", index)
+}
+
+pub fn ufs_issue_absolute_path(index: usize, path: &str) -> String {
+    format!(
+        "存档规则 #{}：路径必须是相对路径，当前为 \"{}\"",
+        index, path
+    )
+}
+
+pub fn ufs_issue_parent_segment(index: usize, path: &str) -> String {
+    format!("存档规则 #{}：路径不能包含 '..'（\"{}\"）", index, path)
+}
+
+pub fn ufs_issue_unknown_platform(index: usize, platform: &str) -> String {
+    format!(
+        "存档规则 #{}：平台 \"{}\" 不在已知的 oslist 中",
+        index, platform
+    )
+}
+
+pub fn ufs_issue_overlapping_savefiles(first: usize, second: usize) -> String {
+    format!("存档规则 #{} 与 #{} 匹配的文件重叠", first, second)
+}
+
+pub fn ufs_issue_unknown_override_root(index: usize, root: &str) -> String {
+    format!("根覆盖 #{}：无法识别的原始 root \"{}\"", index, root)
+}
+
+pub fn ufs_issue_invalid_new_root(index: usize, root: &str) -> String {
+    format!("根覆盖 #{}：\"{}\" 不是有效的新 root", index, root)
+}
+
+pub fn ufs_issue_unknown_override_os(index: usize, os: &str) -> String {
+    format!("根覆盖 #{}：未知的目标系统 \"{}\"", index, os)
+}
+
+pub fn ufs_issue_duplicate_override(first: usize, second: usize) -> String {
+    format!(
+        "根覆盖 #{} 与 #{} 覆盖了同一个 root、系统和比较方式",
+        first, second
+    )
+}
+
+pub fn appinfo_tab_full_tree() -> &'static str {
+    "完整 AppInfo"
+}
+
+pub fn appinfo_search_placeholder() -> &'static str {
+    "搜索键或值..."
+}
+
+pub fn appinfo_show_as_json() -> &'static str {
+    "JSON"
+}
+
+pub fn appinfo_copy_output() -> &'static str {
+    "复制"
+}
+
+pub fn appinfo_full_loading() -> &'static str {
+    "正在读取 appinfo.vdf..."
+}
+
+pub fn appinfo_full_summary(version: u32, change_number: u32, info_state: u32) -> String {
+    format!(
+        "appinfo V{} · 变更号 #{} · 状态 {}",
+        version, change_number, info_state
+    )
+}
+
+pub fn appinfo_full_no_match() -> &'static str {
+    "没有匹配的键"
+}
+
+pub fn appinfo_full_load_error(error: &str) -> String {
+    format!("读取 appinfo 失败: {}", error)
+}
+
+pub fn error_appinfo_unsupported_version(found: &str, supported: &str) -> String {
+    format!(
+        "当前 Steam 客户端的 appinfo.vdf 格式为 {}，暂不支持（已支持: {}）。请更新 Steam Cloud File Manager。",
+        found, supported
+    )
+}
+
+pub fn upload_limit_none() -> &'static str {
+    "无限制"
+}
+
+pub fn upload_projection_bytes(current: &str, projected: &str, limit: &str) -> String {
+    format!(
+        "存储占用: 当前 {} → 上传后 {}（配额: {}）",
+        current, projected, limit
+    )
+}
+
+pub fn upload_projection_files(current: usize, projected: usize, limit: &str) -> String {
+    format!(
+        "文件数: 当前 {} → 上传后 {}（上限: {}）",
+        current, projected, limit
+    )
+}
+
+pub fn upload_projection_available(growth: &str, available: &str) -> String {
+    format!("净增长: {}（Steam 剩余可用: {}）", growth, available)
+}
+
+pub fn upload_projection_overwrites(count: usize, size: &str) -> String {
+    format!("将覆盖 {} 个已有云端文件（{}）", count, size)
+}
+
+pub fn quota_issue_exceeded(projected: &str, limit: &str) -> String {
+    format!("上传后占用 {} 超过配额 {}", projected, limit)
+}
+
+pub fn quota_issue_max_files(projected: usize, limit: u32) -> String {
+    format!("上传后文件数 {} 超过上限 {}", projected, limit)
+}
+
+pub fn quota_issue_available(needed: &str, available: &str) -> String {
+    format!("需要新增 {}，但 Steam 仅剩 {} 可用", needed, available)
+}
+
+pub fn quota_issue_near(projected: &str, limit: &str) -> String {
+    format!("上传后占用 {} 接近配额 {}", projected, limit)
+}
+
+pub fn upload_blocked_by_quota() -> &'static str {
+    "请移除部分文件，使上传不超过限制"
+}
+
+pub fn upload_filters_header() -> &'static str {
+    "过滤与云端路径规则"
+}
+
+pub fn upload_skip_hidden() -> &'static str {
+    "跳过隐藏文件"
+}
+
+pub fn upload_skip_temp() -> &'static str {
+    "跳过临时文件"
+}
+
+pub fn upload_patterns_hint() -> &'static str {
+    "gitignore 风格，每行一条: *.sav, saves/**, logs/, !keep.log。仅作用于所添加文件夹内的文件。"
+}
+
+pub fn upload_include_patterns() -> &'static str {
+    "包含（留空表示全部）"
+}
+
+pub fn upload_exclude_patterns() -> &'static str {
+    "排除"
+}
+
+pub fn upload_path_rules() -> &'static str {
+    "云端路径改写规则（按顺序应用）:"
+}
+
+pub fn upload_rule_strip_prefix() -> &'static str {
+    "去除前缀"
+}
+
+pub fn upload_rule_lowercase() -> &'static str {
+    "转为小写"
+}
+
+pub fn upload_rule_replace_segment() -> &'static str {
+    "替换路径段"
+}
+
+pub fn upload_add_rule() -> &'static str {
+    "添加规则:"
+}
+
+pub fn upload_apply_filters() -> &'static str {
+    "应用并预览"
+}
+
+pub fn upload_skipped_files(count: usize) -> String {
+    format!("已按规则跳过 {} 个文件", count)
+}
+
+pub fn upload_skip_reason_hidden() -> &'static str {
+    "隐藏文件"
+}
+
+pub fn upload_skip_reason_temp() -> &'static str {
+    "临时文件"
+}
+
+pub fn upload_skip_reason_excluded() -> &'static str {
+    "已排除"
+}
+
+pub fn upload_skip_reason_not_included() -> &'static str {
+    "未包含"
+}
+
+pub fn upload_resume_title() -> &'static str {
+    "未完成的上传"
+}
+
+pub fn upload_resume_message(app_id: u32, pending: usize, total: usize) -> String {
+    format!(
+        "App {} 的上传被中断：{} / {} 个文件尚未上传。",
+        app_id, pending, total
+    )
+}
+
+pub fn upload_resume_updated(time: &str) -> String {
+    format!("最后更新: {}", time)
+}
+
+pub fn upload_resume_hint() -> &'static str {
+    "续传时会跳过云端大小和哈希已一致的文件。"
+}
+
+pub fn upload_resume_button() -> &'static str {
+    "继续上传"
+}
+
+pub fn upload_resume_later() -> &'static str {
+    "稍后"
+}
+
+pub fn upload_resume_discard() -> &'static str {
+    "放弃"
+}
+
+pub fn upload_resume_nothing_left() -> &'static str {
+    "中断上传的本地文件均已不存在，已放弃该会话。"
+}
+
+pub fn upload_skipped_unchanged(count: usize) -> String {
+    format!("已跳过 {} 个未变化的文件", count)
+}
+
+pub fn upload_quota_list_failed(error: &str) -> String {
+    format!(
+        "未能读取云端文件列表，仅按 appinfo 限制检查了配额: {}",
+        error
+    )
+}
+
+pub fn upload_verify_after_write() -> &'static str {
+    "上传后校验"
+}
+
+pub fn upload_verify_after_write_hint() -> &'static str {
+    "从 Steam 云端读回每个文件并与本地 SHA-1 比对，不一致的文件会标记为上传失败。"
+}
+
+pub fn download_bytes_progress(done: &str, total: &str) -> String {
+    format!("已下载 {} / {}", done, total)
+}
+
+pub fn download_source_stats(cdp: usize, steam_api: usize, local: usize) -> String {
+    format!(
+        "来源: CDP {} · Steam API {} · 本地复制 {}",
+        cdp, steam_api, local
+    )
+}
+
+pub fn download_local_copy_warning(count: usize) -> String {
+    format!("{} 个文件来自本地存档复制，可能比云端版本旧", count)
+}
+
+pub fn remote_storage_provider_label() -> &'static str {
+    "网页版云存储读取方式"
+}
+
+pub fn remote_storage_provider_cdp() -> &'static str {
+    "Steam 内置浏览器（CEF 调试端口）"
+}
+
+pub fn remote_storage_provider_web() -> &'static str {
+    "使用会话 Cookie 直接请求网页"
+}
+
+pub fn session_cookie_label() -> &'static str {
+    "steamLoginSecure Cookie"
+}
+
+pub fn session_cookie_saved() -> &'static str {
+    "Cookie 已保存"
+}
+
+pub fn session_cookie_hint() -> &'static str {
+    "从已登录 store.steampowered.com 的浏览器中复制 steamLoginSecure Cookie。它等同于登录凭据，请勿分享配置文件。此方式无需重启 Steam。"
+}
+
+pub fn web_no_data_error() -> &'static str {
+    "读取网页版云存储失败！\n\n会话 Cookie 可能已失效，请在 设置 → 高级 中重新填写 steamLoginSecure Cookie。"
+}
+
+pub fn collection_filter_label() -> &'static str {
+    "收藏夹:"
+}
+
+pub fn collection_all() -> &'static str {
+    "全部游戏"
+}
+
+pub fn collection_favorites() -> &'static str {
+    "收藏"
+}
+
+pub fn collection_hidden() -> &'static str {
+    "已隐藏"
+}
+
+pub fn collection_dynamic_unsupported(name: &str) -> String {
+    format!("{}（动态规则不支持）", name)
+}
+
+pub fn no_games_in_collection() -> &'static str {
+    "该收藏夹中没有游戏"
+}
+
+pub fn compare_users_button() -> &'static str {
+    "对比存档"
+}
+
+pub fn user_compare_title() -> &'static str {
+    "跨账号存档对比"
+}
+
+pub fn user_compare_source() -> &'static str {
+    "源账号:"
+}
+
+pub fn user_compare_target() -> &'static str {
+    "目标账号:"
+}
+
+pub fn user_compare_run() -> &'static str {
+    "对比"
+}
+
+pub fn user_compare_swap() -> &'static str {
+    "交换"
+}
+
+pub fn user_compare_same_user() -> &'static str {
+    "请选择两个不同的账号"
+}
+
+pub fn user_compare_empty() -> &'static str {
+    "两个账号都没有该游戏的存档"
+}
+
+pub fn user_compare_select_different() -> &'static str {
+    "选中全部差异"
+}
+
+pub fn user_compare_col_source() -> &'static str {
+    "源账号"
+}
+
+pub fn user_compare_col_target() -> &'static str {
+    "目标账号"
+}
+
+pub fn user_compare_col_status() -> &'static str {
+    "状态"
+}
+
+pub fn cross_status_only_source() -> &'static str {
+    "仅源账号"
+}
+
+pub fn cross_status_only_target() -> &'static str {
+    "仅目标账号"
+}
+
+pub fn cross_status_identical() -> &'static str {
+    "相同"
+}
+
+pub fn cross_status_different() -> &'static str {
+    "不同"
+}
+
+pub fn copy_target_local() -> &'static str {
+    "写入目标账号的本地存档目录"
+}
+
+pub fn copy_target_api() -> &'static str {
+    "通过 Steam 上传（目标账号已登录）"
+}
+
+pub fn copy_target_api_hint() -> &'static str {
+    "通过 Steam 上传需要目标账号已登录并已连接到该游戏，且只支持 Steam remote 目录（root 0）中的文件"
+}
+
+pub fn user_compare_copy(count: usize) -> String {
+    format!("复制 {} 个文件", count)
+}
+
+pub fn user_compare_copy_result(copied: usize, failed: usize) -> String {
+    format!("已复制 {} 个文件，失败 {} 个", copied, failed)
+}
+
+pub fn account_name_label(name: &str) -> String {
+    format!("账号名: {}", name)
+}
+
+pub fn last_login_label(time: &str) -> String {
+    format!("上次登录: {}", time)
+}
+
+pub fn user_most_recent() -> &'static str {
+    "最近登录"
+}
+
+pub fn user_remember_password() -> &'static str {
+    "记住密码"
+}
+
+pub fn user_auto_login() -> &'static str {
+    "自动登录"
+}
+
+pub fn symlink_migrate() -> &'static str {
+    "将现有目录迁移到链接目标并创建链接（会保留备份）"
+}
+
+pub fn symlink_migrated(count: usize) -> String {
+    format!("已迁移 {} 个文件并创建链接", count)
+}
+
+pub fn symlink_migrate_failed() -> &'static str {
+    "迁移失败"
+}
+
+pub fn symlink_undo_migration() -> &'static str {
+    "撤销迁移"
+}
+
+pub fn symlink_migration_undone() -> &'static str {
+    "已撤销迁移，原目录已恢复"
+}
+
+pub fn symlink_undo_failed() -> &'static str {
+    "撤销失败"
+}
+
+pub fn symlink_discard_backup() -> &'static str {
+    "保留迁移并删除备份"
+}
+
+pub fn symlink_backup_discarded() -> &'static str {
+    "迁移备份已删除"
+}
+
+pub fn symlink_health_title() -> &'static str {
+    "软链接健康检查"
+}
+
+pub fn symlink_health_check_all() -> &'static str {
+    "检查所有游戏的软链接"
+}
+
+pub fn symlink_health_no_configs() -> &'static str {
+    "尚未配置任何软链接"
+}
+
+pub fn symlink_health_all_ok(total: usize) -> String {
+    format!("全部 {} 个软链接状态正常", total)
+}
+
+pub fn symlink_health_summary(issues: usize, total: usize) -> String {
+    format!("{} 个软链接需要处理（共 {} 个）", issues, total)
+}
+
+pub fn symlink_health_show_all() -> &'static str {
+    "显示正常的链接"
+}
+
+pub fn symlink_platform_mismatch(platform: &str) -> String {
+    format!("配置于 {} 平台", platform)
+}
+
+pub fn symlink_repair_create() -> &'static str {
+    "创建链接"
+}
+
+pub fn symlink_repair_recreate() -> &'static str {
+    "重建链接"
+}
+
+pub fn symlink_repair_migrate() -> &'static str {
+    "迁移目录"
+}
+
+pub fn symlink_repair_adopt() -> &'static str {
+    "在本机使用"
+}
+
+pub fn symlink_repair_remove() -> &'static str {
+    "删除配置"
+}
+
+pub fn symlink_repair_done() -> &'static str {
+    "已修复"
+}
+
+pub fn symlink_repair_failed() -> &'static str {
+    "修复失败"
+}
+
+pub fn symlink_sync_remove_missing() -> &'static str {
+    "同步时删除本地已不存在的云端文件"
+}
+
+pub fn symlink_sync_list_failed() -> &'static str {
+    "获取云端文件列表失败"
+}
+
+pub fn symlink_sync_up_to_date() -> &'static str {
+    "云端已是最新，无需上传"
+}
+
+pub fn symlink_sync_report(
+    uploaded: usize,
+    unchanged: usize,
+    cloud_newer: usize,
+    removed: usize,
+) -> String {
+    format!(
+        "上传 {} 个，未变化跳过 {} 个，云端较新跳过 {} 个，删除 {} 个",
+        uploaded, unchanged, cloud_newer, removed
+    )
+}
+
+pub fn symlink_sync_cloud_only_hint(count: usize) -> String {
+    format!(
+        "云端另有 {} 个文件本地已不存在，勾选删除选项可一并移除",
+        count
+    )
+}
+
+pub fn symlink_export() -> &'static str {
+    "导出"
+}
+
+pub fn symlink_import() -> &'static str {
+    "导入"
+}
+
+pub fn symlink_export_hint() -> &'static str {
+    "导出该游戏的软链接配置，位于系统目录下的路径会以可移植形式保存"
+}
+
+pub fn symlink_portable_hint() -> &'static str {
+    "已按系统目录保存，可在其他电脑上使用"
+}
+
+pub fn symlink_exported(count: usize) -> String {
+    format!("已导出 {} 个软链接配置", count)
+}
+
+pub fn symlink_imported(added: usize, skipped: usize) -> String {
+    format!(
+        "已导入 {} 个软链接配置，跳过 {} 个已存在的配置",
+        added, skipped
+    )
+}
+
+pub fn symlink_export_failed() -> &'static str {
+    "导出失败"
+}
+
+pub fn symlink_import_failed() -> &'static str {
+    "导入失败"
+}
+
+pub fn game_profile_button() -> &'static str {
+    "游戏配置档"
+}
+
+pub fn game_profile_title(game_name: &str) -> String {
+    format!("配置档 - {}", game_name)
+}
+
+pub fn game_profile_hint() -> &'static str {
+    "此处的设置仅对该游戏生效，连接时自动应用。留空则沿用全局设置。"
+}
+
+pub fn game_profile_download_dir() -> &'static str {
+    "下载目录:"
+}
+
+pub fn game_profile_ask_each_time() -> &'static str {
+    "每次询问"
+}
+
+pub fn game_profile_upload_root() -> &'static str {
+    "默认上传根目录:"
+}
+
+pub fn game_profile_upload_root_hint() -> &'static str {
+    "新上传文件放在云端的此目录下"
+}
+
+pub fn game_profile_custom_filter() -> &'static str {
+    "使用该游戏专用的上传过滤规则"
+}
+
+pub fn game_profile_custom_filter_hint() -> &'static str {
+    "在上传对话框中修改的规则会保存到此配置档，而不是全局设置"
+}
+
+pub fn game_profile_backup_interval() -> &'static str {
+    "自动备份间隔:"
+}
+
+pub fn game_profile_backup_interval_hint() -> &'static str {
+    "距上次备份超过该小时数时，连接后自动备份全部云端文件。0 表示关闭。"
+}
+
+pub fn game_profile_last_backup(time: &str) -> String {
+    format!("上次自动备份: {}", time)
+}
+
+pub fn game_profile_symlink_defaults() -> &'static str {
+    "新建软链接的默认值"
+}
+
+pub fn game_profile_save() -> &'static str {
+    "保存"
+}
+
+pub fn game_profile_reset() -> &'static str {
+    "重置"
+}
+
+pub fn game_profile_reset_hint() -> &'static str {
+    "清空所有覆盖项，保存后删除配置档"
+}
+
+pub fn game_profile_saved() -> &'static str {
+    "游戏配置档已保存"
+}
+
+pub fn game_profile_save_failed(err: &str) -> String {
+    format!("保存游戏配置档失败: {}", err)
+}
@@ -54,12 +54,14 @@ pub const ARROW_SYNC: &str = ph::ARROWS_CLOCKWISE;
 pub const GAME: &str = ph::GAME_CONTROLLER;
 pub const GEAR: &str = ph::GEAR;
 pub const WRENCH: &str = ph::WRENCH;
+pub const USER: &str = ph::USER_CIRCLE;
 
 // 其他
 pub const MAGNIFYING_GLASS: &str = ph::MAGNIFYING_GLASS;
 pub const QUESTION: &str = ph::QUESTION;
 pub const SLIDERS: &str = ph::SLIDERS;
 pub const EXPORT: &str = ph::EXPORT;
+pub const IMPORT: &str = ph::FILE_ARROW_DOWN;
 pub const SCISSORS: &str = ph::SCISSORS;
 pub const GLOBE: &str = ph::GLOBE;
 pub const ROCKET: &str = ph::ROCKET;
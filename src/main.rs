@@ -3,31 +3,44 @@
 mod app;
 mod app_handlers;
 mod app_state;
+mod appinfo_format;
+mod appinfo_inspector;
 mod appinfo_writer;
 mod async_handlers;
 mod backup;
 mod cdp_client;
+#[cfg(test)]
+mod cdp_test_server;
 mod config;
 mod conflict;
 mod downloader;
 mod file_manager;
 mod file_tree;
+mod game_index;
 mod game_scanner;
 mod i18n;
 mod icons;
 mod logger;
 mod path_resolver;
+mod remotestorage_html;
 mod steam_api;
+mod steam_collections;
 mod steam_process;
 mod steam_worker;
 mod symlink_manager;
+mod ufs_bundle;
 mod ufs_dump;
 mod ufs_text;
+mod ufs_validator;
 mod ui;
 mod update;
+mod upload_filter;
+mod upload_session;
+mod user_compare;
 mod user_manager;
 mod vdf_parser;
 mod version;
+mod web_remotestorage;
 
 use app::SteamCloudApp;
 use eframe::egui;
@@ -52,6 +65,21 @@ fn main() -> Result<(), eframe::Error> {
         }
     }
 
+    // 检查是否请求完整 appinfo 转储
+    if let Some(pos) = args.iter().position(|a| a == "--appinfo") {
+        if let Some(app_id_str) = args.get(pos + 1) {
+            let as_json = args.contains(&"--json".to_string());
+            if let Err(e) = appinfo_inspector::dump(app_id_str, as_json) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+            std::process::exit(0);
+        } else {
+            eprintln!("用法: {} --appinfo <app_id> [--json]", args[0]);
+            std::process::exit(1);
+        }
+    }
+
     // 初始化配置系统
     if let Err(e) = config::init_config() {
         eprintln!("配置初始化失败: {}", e);
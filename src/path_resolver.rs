@@ -480,6 +480,152 @@ pub fn resolve_root_base_path(
     }
 }
 
+// 可移植路径中使用的系统目录，按平台区分；路径同时位于多个目录下时取最深的一个。
+// 其他平台的 Root 在本机会映射到不同含义的目录（如 Linux 上 WinAppDataLocalLow 为 ~/.local/share），
+// 不能用来生成可移植路径
+const WINDOWS_PORTABLE_ROOTS: &[RootType] = &[
+    RootType::WinSavedGames,
+    RootType::WinAppDataLocalLow,
+    RootType::WinAppDataRoaming,
+    RootType::WinAppDataLocal,
+    RootType::WinMyDocuments,
+];
+const LINUX_PORTABLE_ROOTS: &[RootType] =
+    &[RootType::LinuxXdgDataHome, RootType::LinuxXdgConfigHome];
+const MACOS_PORTABLE_ROOTS: &[RootType] = &[RootType::MacAppSupport, RootType::MacCaches];
+
+// 指定平台（std::env::consts::OS 的取值）原生的可移植 Root，未知平台只使用 $HOME
+pub fn portable_roots_for(os: &str) -> &'static [RootType] {
+    match os {
+        "windows" => WINDOWS_PORTABLE_ROOTS,
+        "linux" => LINUX_PORTABLE_ROOTS,
+        "macos" => MACOS_PORTABLE_ROOTS,
+        _ => &[],
+    }
+}
+
+// 用户主目录：HOME 优先，Windows 回退到 USERPROFILE
+fn home_dir() -> Option<PathBuf> {
+    std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .ok()
+        .filter(|h| !h.is_empty())
+        .map(PathBuf::from)
+}
+
+// 把绝对路径转换为可移植形式：
+// "{WinSavedGames}/Game/Saves" 或 "$HOME/Games/Saves"，不在任何已知目录下时返回 None
+// 只使用当前平台原生的 Root，其余路径回退到 $HOME
+pub fn to_portable_path(path: &Path) -> Option<String> {
+    to_portable_path_with(
+        path,
+        portable_roots_for(std::env::consts::OS),
+        home_dir().as_deref(),
+        |root| resolve_root_base_path(root, Path::new(""), "", 0).ok(),
+    )
+}
+
+// 同上，候选 Root、主目录和各系统目录由调用方提供
+pub fn to_portable_path_with(
+    path: &Path,
+    roots: &[RootType],
+    home: Option<&Path>,
+    root_base: impl Fn(RootType) -> Option<PathBuf>,
+) -> Option<String> {
+    let mut best: Option<(usize, String, PathBuf)> = None;
+    for &root in roots {
+        let Some(base) = root_base(root) else {
+            continue;
+        };
+        if let Ok(rel) = path.strip_prefix(&base) {
+            let depth = base.components().count();
+            if best.as_ref().is_none_or(|(d, _, _)| depth > *d) {
+                best = Some((depth, format!("{{{}}}", root.to_name()), rel.to_path_buf()));
+            }
+        }
+    }
+    if best.is_none()
+        && let Some(home) = home
+        && let Ok(rel) = path.strip_prefix(home)
+    {
+        best = Some((0, "$HOME".to_string(), rel.to_path_buf()));
+    }
+
+    let (_, prefix, rel) = best?;
+    let rel: Vec<String> = rel
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect();
+    if rel.is_empty() {
+        Some(prefix)
+    } else {
+        Some(format!("{}/{}", prefix, rel.join("/")))
+    }
+}
+
+// 是否为可移植形式（以 {Root}、$VAR、${VAR} 或 %VAR% 开头）
+pub fn is_portable_path(value: &str) -> bool {
+    split_portable_prefix(value).is_some()
+}
+
+// 拆分可移植路径的前缀和剩余部分
+fn split_portable_prefix(value: &str) -> Option<(&str, &str)> {
+    let (prefix, rest) = if let Some(rest) = value.strip_prefix('{') {
+        let end = rest.find('}')?;
+        (&value[..end + 2], &rest[end + 1..])
+    } else if let Some(rest) = value.strip_prefix("${") {
+        let end = rest.find('}')?;
+        (&value[..end + 3], &rest[end + 1..])
+    } else if let Some(rest) = value.strip_prefix('%') {
+        let end = rest.find('%')?;
+        (&value[..end + 2], &rest[end + 1..])
+    } else if let Some(rest) = value.strip_prefix('$') {
+        let end = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        (&value[..end + 1], &rest[end..])
+    } else {
+        return None;
+    };
+    if !(rest.is_empty() || rest.starts_with('/') || rest.starts_with('\\')) {
+        return None;
+    }
+    let name = prefix.trim_matches(|c| matches!(c, '{' | '}' | '$' | '%'));
+    (!name.is_empty()).then_some((prefix, rest))
+}
+
+// 展开可移植路径为当前机器上的绝对路径，普通路径原样返回
+pub fn expand_portable_path(value: &str) -> Result<PathBuf> {
+    let Some((prefix, rest)) = split_portable_prefix(value) else {
+        return Ok(PathBuf::from(value));
+    };
+
+    let base = if prefix.starts_with('{') {
+        let name = &prefix[1..prefix.len() - 1];
+        let root = WINDOWS_PORTABLE_ROOTS
+            .iter()
+            .chain(LINUX_PORTABLE_ROOTS)
+            .chain(MACOS_PORTABLE_ROOTS)
+            .copied()
+            .find(|r| r.to_name().eq_ignore_ascii_case(name))
+            .or_else(|| RootType::from_name(name))
+            .ok_or_else(|| anyhow!("未知的 Root 类型: {}", name))?;
+        resolve_root_base_path(root, Path::new(""), "", 0)?
+    } else {
+        let name = prefix.trim_matches(|c| matches!(c, '{' | '}' | '$' | '%'));
+        match std::env::var(name) {
+            Ok(v) if !v.is_empty() => PathBuf::from(v),
+            _ if name == "HOME" => home_dir().ok_or_else(|| anyhow!("无法获取用户主目录"))?,
+            _ => return Err(anyhow!("环境变量未设置: {}", name)),
+        }
+    };
+
+    Ok(rest
+        .split(['/', '\\'])
+        .filter(|part| !part.is_empty())
+        .fold(base, |path, part| path.join(part)))
+}
+
 // 解析云文件的完整路径
 pub fn resolve_cloud_file_path(
     root: u32,
@@ -1236,3 +1382,121 @@ fn pattern_matches(filename: &str, pattern: &str) -> bool {
     // 精确匹配
     filename == pattern
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 按平台模拟 resolve_root_base_path 的结果，包括其他平台 Root 在本机上的映射
+    fn fake_root_base(os: &str, home: &Path, root: RootType) -> Option<PathBuf> {
+        let path = match (os, root) {
+            ("windows", RootType::WinSavedGames) => home.join("Saved Games"),
+            ("windows", RootType::WinAppDataLocalLow) => home.join("AppData").join("LocalLow"),
+            ("windows", RootType::WinAppDataRoaming | RootType::LinuxXdgConfigHome) => {
+                home.join("AppData").join("Roaming")
+            }
+            ("windows", RootType::WinAppDataLocal | RootType::LinuxXdgDataHome) => {
+                home.join("AppData").join("Local")
+            }
+            ("windows", RootType::WinMyDocuments) => home.join("Documents"),
+            ("linux", RootType::WinAppDataLocal | RootType::WinAppDataLocalLow) => {
+                home.join(".local").join("share")
+            }
+            ("linux", RootType::LinuxXdgDataHome) => home.join(".local").join("share"),
+            ("linux", RootType::WinAppDataRoaming | RootType::LinuxXdgConfigHome) => {
+                home.join(".config")
+            }
+            ("linux", RootType::MacCaches) => home.join(".cache"),
+            ("macos", RootType::WinAppDataLocal) => home.to_path_buf(),
+            ("macos", RootType::WinAppDataLocalLow | RootType::MacCaches) => {
+                home.join("Library").join("Caches")
+            }
+            ("macos", RootType::WinAppDataRoaming | RootType::MacAppSupport) => {
+                home.join("Library").join("Application Support")
+            }
+            (_, RootType::WinMyDocuments) => home.join("Documents"),
+            _ => return None,
+        };
+        Some(path)
+    }
+
+    fn portable(os: &str, home: &Path, path: &Path) -> Option<String> {
+        to_portable_path_with(path, portable_roots_for(os), Some(home), |root| {
+            fake_root_base(os, home, root)
+        })
+    }
+
+    #[test]
+    fn windows_paths_use_windows_roots() {
+        let home = PathBuf::from("/Users/player");
+        let cases = [
+            (
+                home.join("Saved Games").join("Game"),
+                "{WinSavedGames}/Game",
+            ),
+            (
+                home.join("AppData").join("LocalLow").join("Studio"),
+                "{WinAppDataLocalLow}/Studio",
+            ),
+            (
+                home.join("AppData").join("Roaming").join("Game"),
+                "{WinAppDataRoaming}/Game",
+            ),
+            (
+                home.join("AppData").join("Local").join("Game"),
+                "{WinAppDataLocal}/Game",
+            ),
+            (
+                home.join("Documents").join("My Games"),
+                "{WinMyDocuments}/My Games",
+            ),
+            (home.join("Games"), "$HOME/Games"),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(portable("windows", &home, &path).as_deref(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn linux_paths_never_use_windows_roots() {
+        let home = PathBuf::from("/home/player");
+        let cases = [
+            (
+                home.join(".local").join("share").join("Game"),
+                "{LinuxXdgDataHome}/Game",
+            ),
+            (
+                home.join(".config").join("Game"),
+                "{LinuxXdgConfigHome}/Game",
+            ),
+            (home.join(".cache").join("Game"), "$HOME/.cache/Game"),
+            (home.join("Documents").join("Game"), "$HOME/Documents/Game"),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(portable("linux", &home, &path).as_deref(), Some(expected));
+        }
+        assert_eq!(portable("linux", &home, Path::new("/srv/saves")), None);
+    }
+
+    #[test]
+    fn macos_paths_never_use_windows_roots() {
+        let home = PathBuf::from("/Users/player");
+        let cases = [
+            (
+                home.join("Library")
+                    .join("Application Support")
+                    .join("Game"),
+                "{MacAppSupport}/Game",
+            ),
+            (
+                home.join("Library").join("Caches").join("Game"),
+                "{MacCaches}/Game",
+            ),
+            (home.join("Games"), "$HOME/Games"),
+            (home.join("Documents").join("Game"), "$HOME/Documents/Game"),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(portable("macos", &home, &path).as_deref(), Some(expected));
+        }
+    }
+}
@@ -0,0 +1,369 @@
+// 网页版云存储（store.steampowered.com/account/remotestorage）页面解析
+// CDP 和 HTTP 两种方式读取的是同一份页面，表格行结构和转换逻辑在这里共用
+
+use crate::game_scanner::CloudGameInfo;
+use crate::steam_api::CloudFile;
+use chrono::{Datelike, Local};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+pub const GAME_LIST_URL: &str = "https://store.steampowered.com/account/remotestorage";
+// 文件列表每页条数，翻页参数为 index=
+pub const FILES_PER_PAGE: usize = 50;
+
+// 文件列表页 URL
+pub fn file_page_url(app_id: u32, index: usize) -> String {
+    if index > 0 {
+        format!(
+            "https://store.steampowered.com/account/remotestorageapp/?appid={}&index={}",
+            app_id, index
+        )
+    } else {
+        format!(
+            "https://store.steampowered.com/account/remotestorageapp/?appid={}",
+            app_id
+        )
+    }
+}
+
+// 游戏列表页的一行：游戏名, 文件数, 大小, 链接
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameRow {
+    pub app_id: u64,
+    pub game_name: String,
+    pub file_count: u64,
+    pub total_size_str: String,
+}
+
+// 文件列表页的一行：文件夹, 文件名, 大小, 时间, 下载链接
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileRow {
+    pub folder: String,
+    pub name: String,
+    pub size_str: String,
+    pub time_str: String,
+    pub url: String,
+}
+
+impl GameRow {
+    pub fn to_game_info(&self) -> Option<CloudGameInfo> {
+        let app_id = self.app_id as u32;
+        if app_id == 0 {
+            return None;
+        }
+
+        let game_name = Some(self.game_name.trim().to_string()).filter(|s| !s.is_empty());
+        let total_size = crate::file_manager::parse_size(&self.total_size_str);
+        if total_size == 0 && !self.total_size_str.trim().is_empty() {
+            tracing::warn!(
+                "App {} ({:?}) 大小解析为0: Raw='{}'",
+                app_id,
+                game_name,
+                self.total_size_str
+            );
+        }
+
+        Some(CloudGameInfo {
+            app_id,
+            file_count: self.file_count as usize,
+            total_size,
+            last_played: None,
+            playtime: None,
+            game_name,
+            is_installed: false,
+            install_dir: None,
+            categories: Vec::new(),
+            is_favorite: false,
+            is_hidden: false,
+        })
+    }
+}
+
+impl FileRow {
+    pub fn to_cloud_file(&self) -> CloudFile {
+        // 存储 URL 到 root_description，格式: CDP:<URL>|<原始FOLDER>
+        let root_description = if !self.url.is_empty() {
+            format!("CDP:{}|{}", self.url, self.folder)
+        } else {
+            self.folder.clone()
+        };
+
+        CloudFile {
+            name: self.name.clone(),
+            size: crate::file_manager::parse_size(&self.size_str),
+            timestamp: parse_steam_time(&self.time_str).unwrap_or_else(Local::now),
+            is_persisted: true,
+            exists: true,
+            root: 0,
+            root_description,
+        }
+    }
+}
+
+// 页面中是否有数据表格
+pub fn has_account_table(html: &str) -> bool {
+    html.contains("class=\"accountTable")
+}
+
+// 是否有指向 index=N 的翻页链接
+pub fn has_index_link(html: &str, index: usize) -> bool {
+    let needle = format!("index={}", index);
+    links(html).iter().any(|href| href.contains(&needle))
+}
+
+pub fn parse_game_rows(html: &str) -> Vec<GameRow> {
+    let appid_re = Regex::new(r"appid=(\d+)").unwrap();
+    table_rows(html)
+        .into_iter()
+        .filter(|(cells, _)| cells.len() >= 3)
+        .filter_map(|(cells, links)| {
+            let app_id = links
+                .iter()
+                .find_map(|href| appid_re.captures(href).map(|c| c[1].to_string()))?
+                .parse()
+                .ok()?;
+            Some(GameRow {
+                app_id,
+                game_name: cells[0].clone(),
+                file_count: parse_int(&cells[1]),
+                total_size_str: cells[2].clone(),
+            })
+        })
+        .collect()
+}
+
+pub fn parse_file_rows(html: &str) -> Vec<FileRow> {
+    table_rows(html)
+        .into_iter()
+        .filter(|(cells, _)| cells.len() >= 4 && !cells[1].is_empty())
+        .map(|(cells, links)| FileRow {
+            folder: cells[0].clone(),
+            name: cells[1].clone(),
+            size_str: cells[2].clone(),
+            time_str: cells[3].clone(),
+            // 下载链接可能是 "ugc" 也可能是 "filedownload"
+            url: links
+                .into_iter()
+                .find(|href| {
+                    href.contains("ugc")
+                        || href.contains("filedownload")
+                        || href.contains("steamusercontent")
+                })
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+// accountTable 中每行的 (单元格文本, 行内链接)
+fn table_rows(html: &str) -> Vec<(Vec<String>, Vec<String>)> {
+    let table = Regex::new(r#"(?s)<table[^>]*class="accountTable[^"]*"[^>]*>(.*?)</table>"#)
+        .unwrap()
+        .captures(html)
+        .map(|c| c[1].to_string())
+        .unwrap_or_default();
+    let row_re = Regex::new(r"(?s)<tr[^>]*>(.*?)</tr>").unwrap();
+    let cell_re = Regex::new(r"(?s)<td[^>]*>(.*?)</td>").unwrap();
+    row_re
+        .captures_iter(&table)
+        .map(|row| {
+            let cells = cell_re
+                .captures_iter(&row[1])
+                .map(|c| text_content(&c[1]))
+                .collect();
+            (cells, links(&row[1]))
+        })
+        .collect()
+}
+
+fn links(html: &str) -> Vec<String> {
+    Regex::new(r#"<a[^>]*href="([^"]*)""#)
+        .unwrap()
+        .captures_iter(html)
+        .map(|c| decode_entities(&c[1]))
+        .collect()
+}
+
+fn text_content(html: &str) -> String {
+    let stripped = Regex::new(r"<[^>]*>").unwrap().replace_all(html, "");
+    decode_entities(stripped.trim())
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&nbsp;", "\u{a0}")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+// 与 JS 的 parseInt 一致：取开头的数字，无法解析时为 0
+fn parse_int(s: &str) -> u64 {
+    let digits: String = s
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().unwrap_or(0)
+}
+
+pub fn parse_steam_time(s: &str) -> Option<chrono::DateTime<Local>> {
+    use chrono::TimeZone;
+    // Example English: "23 Nov, 2025 @ 5:30pm" or "23 Nov @ 5:30pm"
+    // Example Chinese: "2021 年 3 月 6 日 下午 8:04"
+
+    let s = s.trim();
+    if s.is_empty() {
+        return None;
+    }
+
+    // 中文格式解析
+    if s.contains('年') && s.contains('月') && s.contains('日') {
+        let s_clean = s
+            .replace("年", " ")
+            .replace("月", " ")
+            .replace("日", " ")
+            .replace(":", " ");
+        let parts: Vec<&str> = s_clean.split_whitespace().collect();
+
+        // "2021 3 6 下午 8 04" -> ["2021", "3", "6", "下午", "8", "04"]
+        if parts.len() >= 6 {
+            let year = parts[0].parse::<i32>().ok()?;
+            let month = parts[1].parse::<u32>().ok()?;
+            let day = parts[2].parse::<u32>().ok()?;
+            let ampm = parts[3];
+            let mut hour = parts[4].parse::<u32>().ok()?;
+            let minute = parts[5].parse::<u32>().ok()?;
+
+            if (ampm == "下午" || ampm.to_lowercase() == "pm") && hour < 12 {
+                hour += 12;
+            }
+            if (ampm == "上午" || ampm.to_lowercase() == "am") && hour == 12 {
+                hour = 0;
+            }
+
+            return Local
+                .with_ymd_and_hms(year, month, day, hour, minute, 0)
+                .single();
+        }
+    }
+
+    // 英文格式解析
+    let s_clean = s.replace(" @", "").replace(",", "");
+    let parts: Vec<&str> = s_clean.split_whitespace().collect();
+    if parts.len() < 3 {
+        return None;
+    }
+
+    let day = parts[0].parse::<u32>().ok()?;
+    let month_str = parts[1];
+    let month = match month_str.to_lowercase().as_str() {
+        "jan" => 1,
+        "feb" => 2,
+        "mar" => 3,
+        "apr" => 4,
+        "may" => 5,
+        "jun" => 6,
+        "jul" => 7,
+        "aug" => 8,
+        "sep" => 9,
+        "oct" => 10,
+        "nov" => 11,
+        "dec" => 12,
+        _ => return None,
+    };
+
+    let mut year = Local::now().year();
+    let time_str;
+
+    if parts.len() == 4 {
+        year = parts[2].parse::<i32>().ok()?;
+        time_str = parts[3];
+    } else {
+        time_str = parts[2];
+    }
+
+    let time_len = time_str.len();
+    if time_len < 3 {
+        return None;
+    }
+    let ampm = &time_str[time_len - 2..].to_lowercase();
+    let time_val = &time_str[..time_len - 2];
+    let time_parts: Vec<&str> = time_val.split(':').collect();
+    let mut hour = time_parts[0].parse::<u32>().ok()?;
+    let minute = if time_parts.len() > 1 {
+        time_parts[1].parse::<u32>().ok()?
+    } else {
+        0
+    };
+
+    if ampm == "pm" && hour < 12 {
+        hour += 12;
+    }
+    if ampm == "am" && hour == 12 {
+        hour = 0;
+    }
+
+    Local
+        .with_ymd_and_hms(year, month, day, hour, minute, 0)
+        .single()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Timelike};
+
+    #[test]
+    fn parses_steam_times() {
+        let t = parse_steam_time("23 Nov, 2025 @ 5:30pm").unwrap();
+        assert_eq!(t, Local.with_ymd_and_hms(2025, 11, 23, 17, 30, 0).unwrap());
+
+        // 当年的文件不显示年份
+        let t = parse_steam_time("2 Feb @ 12:00pm").unwrap();
+        assert_eq!((t.year(), t.month(), t.day()), (Local::now().year(), 2, 2));
+        assert_eq!(t.hour(), 12);
+
+        let t = parse_steam_time("1 Jan, 2024 @ 12:05am").unwrap();
+        assert_eq!((t.hour(), t.minute()), (0, 5));
+
+        let t = parse_steam_time("2021 年 3 月 6 日 上午 12:30").unwrap();
+        assert_eq!((t.hour(), t.minute()), (0, 30));
+
+        assert!(parse_steam_time("").is_none());
+        assert!(parse_steam_time("yesterday").is_none());
+        assert!(parse_steam_time("31 Foo, 2024 @ 1:00pm").is_none());
+    }
+
+    #[test]
+    fn parses_recorded_pages() {
+        let html = include_str!("../tests/fixtures/cdp/remotestorage.html");
+        assert!(has_account_table(html));
+        let games: Vec<CloudGameInfo> = parse_game_rows(html)
+            .iter()
+            .filter_map(GameRow::to_game_info)
+            .collect();
+        assert_eq!(games.len(), 3);
+        assert_eq!(games[1].file_count, 12);
+
+        let html = include_str!("../tests/fixtures/cdp/remotestorageapp_480_page1.html");
+        let files: Vec<CloudFile> = parse_file_rows(html)
+            .iter()
+            .map(FileRow::to_cloud_file)
+            .collect();
+        assert_eq!(files.len(), 3);
+        assert_eq!(files[1].size, 2048);
+        assert!(
+            files[0]
+                .root_description
+                .starts_with("CDP:https://steamusercontent")
+        );
+        assert!(has_index_link(html, FILES_PER_PAGE));
+        assert!(!has_index_link(html, FILES_PER_PAGE * 2));
+
+        // 未登录时返回的页面没有数据表格
+        assert!(!has_account_table(
+            "<html><body><div class=\"login\"></div></body></html>"
+        ));
+    }
+}
@@ -156,6 +156,32 @@ impl SteamCloudManager {
         Ok(client.remote_storage().is_cloud_enabled_for_app())
     }
 
+    // 获取账户在当前 app 的云存储配额 (总量, 可用)
+    pub fn get_quota(&self) -> Result<(u64, u64)> {
+        let client = self.client.lock().unwrap();
+        if client.is_none() {
+            return Err(anyhow!("Steam客户端未连接"));
+        }
+
+        let mut total: u64 = 0;
+        let mut available: u64 = 0;
+        // steamworks crate 未封装 GetQuota，直接调用 flat API
+        let ok = unsafe {
+            let remote_storage = steamworks_sys::SteamAPI_SteamRemoteStorage_v016();
+            !remote_storage.is_null()
+                && steamworks_sys::SteamAPI_ISteamRemoteStorage_GetQuota(
+                    remote_storage,
+                    &mut total,
+                    &mut available,
+                )
+        };
+
+        if !ok {
+            return Err(anyhow!("无法获取云存储配额"));
+        }
+        Ok((total, available))
+    }
+
     pub fn set_cloud_enabled_for_app(&self, enabled: bool) -> Result<()> {
         let client = self.client.lock().unwrap();
         let client = client
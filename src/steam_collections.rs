@@ -0,0 +1,234 @@
+// Steam 收藏夹（新版库）解析
+// 新版客户端不再写 sharedconfig.vdf 的 tags，而是把收藏夹存放在
+// userdata/<uid>/config/cloudstorage/cloud-storage-namespace-<n>.json 中，
+// 每条记录为 [key, {key, timestamp, value, version, is_deleted}]，
+// 收藏夹的 key 为 "user-collections.<id>"，value 是收藏夹定义的 JSON 字符串
+
+use crate::game_index::file_mtime;
+use crate::game_scanner::GameCategory;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+const COLLECTION_KEY_PREFIX: &str = "user-collections.";
+// 内置收藏夹的 id
+pub const FAVORITE_COLLECTION_ID: &str = "favorite";
+pub const HIDDEN_COLLECTION_ID: &str = "hidden";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SteamCollection {
+    pub id: String,
+    pub name: String,
+    // 手动加入且未被移除的 app
+    pub apps: Vec<u32>,
+    // 动态收藏夹由 filterSpec 规则匹配，规则依赖商店数据无法在本地求值，
+    // 这里只取手动加入的 app，并在收藏夹筛选中标记为不支持
+    pub is_dynamic: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct NamespaceEntry {
+    value: Option<String>,
+    #[serde(default)]
+    is_deleted: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct CollectionValue {
+    id: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    added: Vec<u32>,
+    #[serde(default)]
+    removed: Vec<u32>,
+    #[serde(rename = "filterSpec")]
+    filter_spec: Option<serde_json::Value>,
+}
+
+pub fn cloudstorage_dir(steam_path: &Path, user_id: &str) -> PathBuf {
+    steam_path
+        .join("userdata")
+        .join(user_id)
+        .join("config")
+        .join("cloudstorage")
+}
+
+// cloud-storage-namespace-<n>.json，不含 .modified.json 等未同步的副本
+fn namespace_files(steam_path: &Path, user_id: &str) -> Vec<PathBuf> {
+    let entries = match std::fs::read_dir(cloudstorage_dir(steam_path, user_id)) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+    let mut files: Vec<PathBuf> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .and_then(|n| n.strip_prefix("cloud-storage-namespace-"))
+                .and_then(|n| n.strip_suffix(".json"))
+                .is_some_and(|n| n.chars().all(|c| c.is_ascii_digit()))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+// 所有 namespace 文件中最新的修改时间，供游戏库索引判断是否需要重新解析
+pub fn latest_mtime(steam_path: &Path, user_id: &str) -> Option<u64> {
+    namespace_files(steam_path, user_id)
+        .iter()
+        .filter_map(|path| file_mtime(path))
+        .max()
+}
+
+// 解析单个 namespace 文件内容中的收藏夹
+pub fn parse_namespace(content: &str) -> Vec<SteamCollection> {
+    let entries: Vec<(String, NamespaceEntry)> = match serde_json::from_str(content) {
+        Ok(entries) => entries,
+        Err(e) => {
+            tracing::warn!("cloudstorage 文件解析失败: {}", e);
+            return Vec::new();
+        }
+    };
+
+    entries
+        .into_iter()
+        .filter(|(key, entry)| key.starts_with(COLLECTION_KEY_PREFIX) && !entry.is_deleted)
+        .filter_map(|(key, entry)| {
+            let value = entry.value?;
+            match serde_json::from_str::<CollectionValue>(&value) {
+                Ok(value) => Some(value),
+                Err(e) => {
+                    tracing::debug!("收藏夹 {} 解析失败: {}", key, e);
+                    None
+                }
+            }
+        })
+        .map(|value| {
+            let removed: HashSet<u32> = value.removed.into_iter().collect();
+            let mut seen = HashSet::new();
+            let apps: Vec<u32> = value
+                .added
+                .into_iter()
+                .filter(|app_id| !removed.contains(app_id) && seen.insert(*app_id))
+                .collect();
+            SteamCollection {
+                name: if value.name.is_empty() {
+                    value.id.clone()
+                } else {
+                    value.name
+                },
+                id: value.id,
+                apps,
+                is_dynamic: value.filter_spec.is_some(),
+            }
+        })
+        .collect()
+}
+
+// 读取用户的全部收藏夹
+pub fn load_collections(steam_path: &Path, user_id: &str) -> Vec<SteamCollection> {
+    let collections: Vec<SteamCollection> = namespace_files(steam_path, user_id)
+        .iter()
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .flat_map(|content| parse_namespace(&content))
+        .collect();
+    let dynamic = collections.iter().filter(|c| c.is_dynamic).count();
+    tracing::debug!(
+        "读取到 {} 个收藏夹（其中动态收藏夹 {} 个）",
+        collections.len(),
+        dynamic
+    );
+    collections
+}
+
+// 动态收藏夹名称（排序去重），规则无法在本地求值，供筛选下拉框单独列出
+pub fn dynamic_collection_names(collections: &[SteamCollection]) -> Vec<String> {
+    let mut names: Vec<String> = collections
+        .iter()
+        .filter(|c| c.is_dynamic)
+        .map(|c| c.name.clone())
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+// 合并到 sharedconfig.vdf 的分类中：收藏夹名称作为标签，内置收藏夹对应收藏/隐藏标记
+pub fn merge_into_categories(
+    collections: &[SteamCollection],
+    categories: &mut HashMap<u32, GameCategory>,
+) {
+    for collection in collections {
+        for &app_id in &collection.apps {
+            let category = categories.entry(app_id).or_insert_with(|| GameCategory {
+                app_id,
+                tags: Vec::new(),
+                is_favorite: false,
+                is_hidden: false,
+            });
+            match collection.id.as_str() {
+                FAVORITE_COLLECTION_ID => category.is_favorite = true,
+                HIDDEN_COLLECTION_ID => category.is_hidden = true,
+                _ => {
+                    if !category.tags.contains(&collection.name) {
+                        category.tags.push(collection.name.clone());
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAMESPACE: &str = r#"[
+        ["user-collections.uc-rpg", {"key": "user-collections.uc-rpg", "timestamp": 1700000000,
+            "value": "{\"id\":\"uc-rpg\",\"name\":\"RPG\",\"added\":[480,570,730],\"removed\":[570]}",
+            "version": "12"}],
+        ["user-collections.uc-dyn", {"key": "user-collections.uc-dyn", "timestamp": 1700000001,
+            "value": "{\"id\":\"uc-dyn\",\"name\":\"Co-op\",\"added\":[],\"removed\":[],\"filterSpec\":{\"nFormatVersion\":2,\"strSearchText\":\"\",\"filterGroups\":[]}}",
+            "version": "3"}],
+        ["user-collections.favorite", {"key": "user-collections.favorite", "timestamp": 1700000002,
+            "value": "{\"id\":\"favorite\",\"added\":[730]}", "version": "5"}],
+        ["user-collections.uc-old", {"key": "user-collections.uc-old", "timestamp": 1700000003,
+            "is_deleted": true, "version": "7"}],
+        ["showcases.1", {"key": "showcases.1", "timestamp": 1700000004, "value": "{}", "version": "1"}]
+    ]"#;
+
+    #[test]
+    fn parses_collections_and_merges_into_categories() {
+        let collections = parse_namespace(NAMESPACE);
+        assert_eq!(collections.len(), 3);
+        assert_eq!(collections[0].name, "RPG");
+        assert_eq!(collections[0].apps, vec![480, 730]);
+        assert!(!collections[0].is_dynamic);
+        assert!(collections[1].is_dynamic);
+        assert_eq!(collections[2].name, "favorite");
+        // 没有手动加入 app 的动态收藏夹仍然保留
+        assert!(collections[1].apps.is_empty());
+        assert_eq!(dynamic_collection_names(&collections), vec!["Co-op"]);
+
+        let mut categories = HashMap::new();
+        categories.insert(
+            480,
+            GameCategory {
+                app_id: 480,
+                tags: vec!["RPG".to_string(), "Legacy".to_string()],
+                is_favorite: false,
+                is_hidden: false,
+            },
+        );
+        merge_into_categories(&collections, &mut categories);
+        assert_eq!(categories[&480].tags, vec!["RPG", "Legacy"]);
+        assert_eq!(categories[&730].tags, vec!["RPG"]);
+        assert!(categories[&730].is_favorite);
+        assert!(!categories.contains_key(&570));
+
+        assert!(parse_namespace("not json").is_empty());
+    }
+}
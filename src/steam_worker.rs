@@ -24,6 +24,7 @@ pub enum WorkerRequest {
     IsCloudEnabledForApp,
     SetCloudEnabledForApp { enabled: bool },
     SyncCloudFiles,
+    GetQuota,
     Ping,
     Exit,
 }
@@ -302,6 +303,14 @@ impl SteamWorkerManager {
         Ok(total_size)
     }
 
+    // 获取 Steam 实时配额 (总量, 可用)
+    pub fn get_quota(&mut self) -> Result<(u64, u64)> {
+        self.request(&WorkerRequest::GetQuota, |r| match r {
+            WorkerResponse::Quota { total, available } => Ok((total, available)),
+            other => Err(anyhow!("意外响应: {:?}", other)),
+        })
+    }
+
     pub fn read_file(&mut self, filename: &str) -> Result<Vec<u8>> {
         self.request(
             &WorkerRequest::ReadFile {
@@ -535,5 +544,12 @@ fn handle_worker_request(
                 message: e.to_string(),
             },
         },
+
+        WorkerRequest::GetQuota => match manager.get_quota() {
+            Ok((total, available)) => WorkerResponse::Quota { total, available },
+            Err(e) => WorkerResponse::Error {
+                message: e.to_string(),
+            },
+        },
     }
 }
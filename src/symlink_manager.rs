@@ -1,5 +1,6 @@
 use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -22,6 +23,23 @@ impl LinkDirection {
             LinkDirection::LocalToRemote => "remote(源) → 本地目录(链接)",
         }
     }
+
+    // 配置文件中的写法：remote_to_local / local_to_remote
+    pub fn as_config_str(&self) -> &'static str {
+        match self {
+            LinkDirection::RemoteToLocal => "remote_to_local",
+            LinkDirection::LocalToRemote => "local_to_remote",
+        }
+    }
+
+    // 无法识别的值按默认方向处理
+    pub fn from_config_str(value: &str) -> Self {
+        if value == "local_to_remote" {
+            LinkDirection::LocalToRemote
+        } else {
+            LinkDirection::RemoteToLocal
+        }
+    }
 }
 
 // 软链接状态
@@ -57,6 +75,48 @@ impl LinkStatus {
     }
 }
 
+// 修复操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepairAction {
+    // 链接未创建：创建
+    CreateLink,
+    // 链接断开：删除后重建
+    RecreateLink,
+    // 链接位置是普通目录：迁移为链接
+    MigrateFolder,
+    // 配置来自其他平台：确认路径可用后标记为当前平台
+    AdoptPlatform,
+    RemoveConfig,
+}
+
+// 单个软链接配置的健康检查结果
+#[derive(Debug, Clone)]
+pub struct LinkHealth {
+    pub config: SymlinkConfig,
+    pub status: LinkStatus,
+    // 配置创建时的平台与当前平台不同，路径多半不可用
+    pub platform_mismatch: bool,
+}
+
+impl LinkHealth {
+    pub fn is_healthy(&self) -> bool {
+        self.status == LinkStatus::Valid && !self.platform_mismatch
+    }
+
+    // 可用的修复操作，其他平台的配置需先确认再操作链接
+    pub fn repair_actions(&self) -> Vec<RepairAction> {
+        if self.platform_mismatch {
+            return vec![RepairAction::AdoptPlatform, RepairAction::RemoveConfig];
+        }
+        match self.status {
+            LinkStatus::Valid => Vec::new(),
+            LinkStatus::NotExists => vec![RepairAction::CreateLink, RepairAction::RemoveConfig],
+            LinkStatus::Broken => vec![RepairAction::RecreateLink, RepairAction::RemoveConfig],
+            LinkStatus::Conflict => vec![RepairAction::MigrateFolder, RepairAction::RemoveConfig],
+        }
+    }
+}
+
 // 软链接配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SymlinkConfig {
@@ -66,7 +126,7 @@ pub struct SymlinkConfig {
     pub app_id: u32,
     // 链接方向
     pub direction: LinkDirection,
-    // 用户自定义的本地路径
+    // 用户自定义的本地路径（已展开为当前机器上的绝对路径）
     pub local_path: PathBuf,
     // remote 目录下的子文件夹名
     pub remote_subfolder: String,
@@ -77,6 +137,9 @@ pub struct SymlinkConfig {
     // 备注
     #[serde(default)]
     pub note: String,
+    // 本地路径位于已知系统目录下，以可移植形式保存，不受平台限制
+    #[serde(default)]
+    pub portable: bool,
 }
 
 impl SymlinkConfig {
@@ -87,6 +150,9 @@ impl SymlinkConfig {
         local_path: PathBuf,
         remote_subfolder: String,
     ) -> Self {
+        // 允许直接输入 {Root}/... 或 $HOME/... 形式
+        let local_path = expand_local_path(&local_path.to_string_lossy());
+        let portable = crate::path_resolver::to_portable_path(&local_path).is_some();
         Self {
             id: uuid::Uuid::new_v4().to_string(),
             app_id,
@@ -96,6 +162,45 @@ impl SymlinkConfig {
             platform: get_current_platform().to_string(),
             created_at: chrono::Utc::now().timestamp(),
             note: String::new(),
+            portable,
+        }
+    }
+
+    // 读取配置项，可移植路径在此展开
+    pub fn from_entry(entry: crate::config::SymlinkConfigEntry) -> Self {
+        let raw = entry.local_path.to_string_lossy().to_string();
+        let portable = crate::path_resolver::is_portable_path(&raw);
+        Self {
+            id: entry.id,
+            app_id: entry.app_id,
+            direction: LinkDirection::from_config_str(&entry.direction),
+            local_path: expand_local_path(&raw),
+            remote_subfolder: entry.remote_subfolder,
+            platform: entry.platform,
+            created_at: entry.created_at,
+            note: entry.note,
+            portable,
+        }
+    }
+
+    // 转换为配置项，可移植的路径写成 {Root}/... 或 $HOME/... 形式
+    pub fn into_entry(self) -> crate::config::SymlinkConfigEntry {
+        let local_path = if self.portable {
+            crate::path_resolver::to_portable_path(&self.local_path)
+                .map(PathBuf::from)
+                .unwrap_or(self.local_path)
+        } else {
+            self.local_path
+        };
+        crate::config::SymlinkConfigEntry {
+            id: self.id,
+            app_id: self.app_id,
+            direction: self.direction.as_config_str().to_string(),
+            local_path,
+            remote_subfolder: self.remote_subfolder,
+            platform: self.platform,
+            created_at: self.created_at,
+            note: self.note,
         }
     }
 
@@ -116,10 +221,209 @@ impl SymlinkConfig {
     }
 }
 
+// 软链接配置分享包格式版本，结构变化时递增
+pub const SYMLINK_BUNDLE_FORMAT_VERSION: u32 = 1;
+
+// 单个游戏的软链接配置分享包，本地路径尽量以可移植形式保存
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymlinkBundle {
+    pub format_version: u32,
+    pub app_id: u32,
+    #[serde(default)]
+    pub game_name: Option<String>,
+    #[serde(default)]
+    pub exported_at: i64,
+    pub symlinks: Vec<crate::config::SymlinkConfigEntry>,
+}
+
+impl SymlinkBundle {
+    pub fn from_configs(
+        app_id: u32,
+        game_name: Option<String>,
+        configs: Vec<SymlinkConfig>,
+    ) -> Self {
+        Self {
+            format_version: SYMLINK_BUNDLE_FORMAT_VERSION,
+            app_id,
+            game_name,
+            exported_at: chrono::Utc::now().timestamp(),
+            symlinks: configs
+                .into_iter()
+                .map(|mut config| {
+                    config.portable =
+                        crate::path_resolver::to_portable_path(&config.local_path).is_some();
+                    config.into_entry()
+                })
+                .collect(),
+        }
+    }
+
+    pub fn from_text(text: &str) -> Result<Self> {
+        let bundle: SymlinkBundle =
+            serde_json::from_str(text).map_err(|e| anyhow!("分享包解析失败: {}", e))?;
+        if bundle.format_version == 0 || bundle.format_version > SYMLINK_BUNDLE_FORMAT_VERSION {
+            return Err(anyhow!(
+                "不支持的分享包版本: {} (当前支持: {})",
+                bundle.format_version,
+                SYMLINK_BUNDLE_FORMAT_VERSION
+            ));
+        }
+        Ok(bundle)
+    }
+
+    // 转换为目标游戏的新配置，与已有配置方向和 remote 子目录都相同的项跳过
+    // 返回 (新配置, 跳过数量)
+    pub fn to_configs(
+        &self,
+        app_id: u32,
+        existing: &[SymlinkConfig],
+    ) -> (Vec<SymlinkConfig>, usize) {
+        let mut configs: Vec<SymlinkConfig> = Vec::new();
+        let mut skipped = 0;
+        for entry in &self.symlinks {
+            let mut config = SymlinkConfig::from_entry(entry.clone());
+            let duplicate = existing.iter().chain(configs.iter()).any(|c| {
+                c.direction == config.direction && c.remote_subfolder == config.remote_subfolder
+            });
+            if duplicate {
+                skipped += 1;
+                continue;
+            }
+            config.id = uuid::Uuid::new_v4().to_string();
+            config.app_id = app_id;
+            config.created_at = chrono::Utc::now().timestamp();
+            if config.portable {
+                config.platform = get_current_platform().to_string();
+            }
+            configs.push(config);
+        }
+        (configs, skipped)
+    }
+}
+
+// 迁移记录文件名，与备份数据放在同一目录
+const MIGRATION_RECORD_FILE: &str = "migration.json";
+
+// 把已有存档目录迁移为软链接的记录，用于撤销
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationRecord {
+    pub config_id: String,
+    pub app_id: u32,
+    pub link_path: PathBuf,
+    pub target_path: PathBuf,
+    // 备份目录，原目录内容位于其中的 data/ 下
+    pub backup_dir: PathBuf,
+    // 迁移前目标目录是否已存在，不存在时撤销会删除空的目标目录
+    pub target_existed: bool,
+    // 迁移时新写入目标目录的文件（相对路径）
+    pub moved_files: Vec<PathBuf>,
+    pub migrated_at: i64,
+}
+
+impl MigrationRecord {
+    fn backup_data_dir(&self) -> PathBuf {
+        self.backup_dir.join("data")
+    }
+
+    fn save(&self) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(self.backup_dir.join(MIGRATION_RECORD_FILE), content)
+            .map_err(|e| anyhow!("写入迁移记录失败: {}", e))
+    }
+}
+
+// 云端已有文件的状态，sha 来自 remotecache.vdf，没有记录时为 None
+#[derive(Debug, Clone)]
+pub struct CloudFileState {
+    pub name: String,
+    pub size: u64,
+    pub timestamp: i64,
+    pub sha: Option<String>,
+}
+
+// 同步前与云端列表对比的结果
+#[derive(Debug, Default)]
+pub struct SyncPlan {
+    // 需要上传的文件 (云端路径, 本地路径)
+    pub upload: Vec<(String, PathBuf)>,
+    // 与云端一致，跳过
+    pub unchanged: Vec<String>,
+    // 云端版本比本地新，跳过以免覆盖
+    pub cloud_newer: Vec<String>,
+    // 云端存在但本地已删除的文件
+    pub cloud_only: Vec<String>,
+}
+
+// 对比本地扫描结果和云端列表，只有云端前缀下的文件参与比较
+pub fn plan_sync_files(
+    local_files: &[(String, PathBuf, u64)],
+    cloud_files: &[CloudFileState],
+    prefix: &str,
+) -> SyncPlan {
+    let cloud: HashMap<&str, &CloudFileState> = cloud_files
+        .iter()
+        .filter(|f| in_cloud_prefix(&f.name, prefix))
+        .map(|f| (f.name.as_str(), f))
+        .collect();
+    let mut plan = SyncPlan::default();
+
+    for (cloud_path, local_path, size) in local_files {
+        let Some(remote) = cloud.get(cloud_path.as_str()) else {
+            plan.upload.push((cloud_path.clone(), local_path.clone()));
+            continue;
+        };
+        let local_mtime = crate::game_index::file_mtime(local_path)
+            .map(|ms| (ms / 1000) as i64)
+            .unwrap_or(0);
+
+        if *size == remote.size {
+            let same = match remote.sha.as_deref().filter(|sha| !sha.is_empty()) {
+                Some(sha) => fs::read(local_path)
+                    .map(|data| crate::file_manager::sha1_hex(&data).eq_ignore_ascii_case(sha))
+                    .unwrap_or(false),
+                // 没有 sha 时，云端写入时间不早于本地修改时间即视为同一版本
+                None => remote.timestamp >= local_mtime,
+            };
+            if same {
+                plan.unchanged.push(cloud_path.clone());
+                continue;
+            }
+        }
+
+        if remote.timestamp > local_mtime {
+            plan.cloud_newer.push(cloud_path.clone());
+        } else {
+            plan.upload.push((cloud_path.clone(), local_path.clone()));
+        }
+    }
+
+    let local: HashSet<&str> = local_files
+        .iter()
+        .map(|(name, _, _)| name.as_str())
+        .collect();
+    plan.cloud_only = cloud
+        .keys()
+        .filter(|name| !local.contains(*name))
+        .map(|name| name.to_string())
+        .collect();
+    plan.cloud_only.sort();
+
+    plan
+}
+
+fn in_cloud_prefix(name: &str, prefix: &str) -> bool {
+    prefix.is_empty()
+        || name
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
 // 软链接管理器
 pub struct SymlinkManager {
     steam_path: PathBuf,
     user_id: String,
+    // 迁移备份目录，默认为配置目录下的 symlink_backups
+    backup_root: Option<PathBuf>,
 }
 
 impl SymlinkManager {
@@ -128,9 +432,24 @@ impl SymlinkManager {
         Ok(Self {
             steam_path,
             user_id,
+            backup_root: None,
         })
     }
 
+    // 测试中把备份放到临时目录
+    #[cfg(test)]
+    pub fn with_backup_root(mut self, backup_root: PathBuf) -> Self {
+        self.backup_root = Some(backup_root);
+        self
+    }
+
+    fn backup_root(&self) -> Result<PathBuf> {
+        match &self.backup_root {
+            Some(root) => Ok(root.clone()),
+            None => Ok(crate::config::get_config_dir()?.join("symlink_backups")),
+        }
+    }
+
     // 获取游戏的 remote 目录
     pub fn get_remote_dir(&self, app_id: u32) -> PathBuf {
         self.steam_path
@@ -245,20 +564,12 @@ impl SymlinkManager {
 
     // 添加配置
     pub fn add_config(&self, config: SymlinkConfig) -> Result<()> {
-        let entry = crate::config::SymlinkConfigEntry {
-            id: config.id,
-            app_id: config.app_id,
-            direction: match config.direction {
-                LinkDirection::LocalToRemote => "local_to_remote".to_string(),
-                LinkDirection::RemoteToLocal => "remote_to_local".to_string(),
-            },
-            local_path: config.local_path,
-            remote_subfolder: config.remote_subfolder,
-            platform: config.platform,
-            created_at: config.created_at,
-            note: config.note,
-        };
-        crate::config::add_symlink_config(entry)
+        crate::config::add_symlink_config(config.into_entry())
+    }
+
+    // 更新配置
+    pub fn update_config(&self, config: SymlinkConfig) -> Result<()> {
+        crate::config::update_symlink_config(config.into_entry())
     }
 
     // 删除配置
@@ -269,23 +580,59 @@ impl SymlinkManager {
     // 获取指定游戏的配置
     pub fn get_configs_for_app(&self, app_id: u32) -> Result<Vec<SymlinkConfig>> {
         let configs = crate::config::get_symlink_configs_for_app(app_id);
-        Ok(configs
+        Ok(configs.into_iter().map(SymlinkConfig::from_entry).collect())
+    }
+
+    // 获取所有游戏的配置
+    pub fn get_all_configs(&self) -> Vec<SymlinkConfig> {
+        crate::config::get_symlink_configs()
             .into_iter()
-            .map(|entry| SymlinkConfig {
-                id: entry.id,
-                app_id: entry.app_id,
-                direction: if entry.direction == "local_to_remote" {
-                    LinkDirection::LocalToRemote
-                } else {
-                    LinkDirection::RemoteToLocal
-                },
-                local_path: entry.local_path,
-                remote_subfolder: entry.remote_subfolder,
-                platform: entry.platform,
-                created_at: entry.created_at,
-                note: entry.note,
+            .map(SymlinkConfig::from_entry)
+            .collect()
+    }
+
+    // 检查所有已配置软链接的状态
+    pub fn check_health(&self) -> Vec<LinkHealth> {
+        let current_platform = get_current_platform();
+        self.get_all_configs()
+            .into_iter()
+            .map(|config| LinkHealth {
+                status: self.verify_symlink(&config),
+                platform_mismatch: !config.portable && config.platform != current_platform,
+                config,
             })
-            .collect())
+            .collect()
+    }
+
+    // 重建断开的链接：删除旧链接，目标目录不存在时创建空目录
+    pub fn recreate_symlink(&self, config: &SymlinkConfig) -> Result<()> {
+        let remote_dir = self.get_remote_dir(config.app_id);
+        let link_path = config.get_link_path(&remote_dir);
+        let target_path = config.get_target_path(&remote_dir);
+
+        if let Ok(metadata) = link_path.symlink_metadata()
+            && metadata.file_type().is_symlink()
+        {
+            remove_symlink_platform(&link_path)?;
+        }
+        fs::create_dir_all(&target_path)
+            .map_err(|e| anyhow!("创建目标目录失败 {:?}: {}", target_path, e))?;
+        self.create_symlink(config)
+    }
+
+    // 执行修复操作
+    pub fn repair(&self, config: &SymlinkConfig, action: RepairAction) -> Result<()> {
+        match action {
+            RepairAction::CreateLink => self.create_symlink(config),
+            RepairAction::RecreateLink => self.recreate_symlink(config),
+            RepairAction::MigrateFolder => self.migrate_to_symlink(config).map(|_| ()),
+            RepairAction::AdoptPlatform => {
+                let mut config = config.clone();
+                config.platform = get_current_platform().to_string();
+                self.update_config(config)
+            }
+            RepairAction::RemoveConfig => self.remove_config(&config.id),
+        }
     }
 
     // 扫描软链接目录中的所有文件
@@ -313,6 +660,250 @@ impl SymlinkManager {
         self.scan_directory(scan_dir, &cloud_prefix)
     }
 
+    // 导出指定游戏的软链接配置
+    pub fn export_configs(
+        &self,
+        app_id: u32,
+        game_name: Option<String>,
+        path: &Path,
+    ) -> Result<usize> {
+        let bundle =
+            SymlinkBundle::from_configs(app_id, game_name, self.get_configs_for_app(app_id)?);
+        let content = serde_json::to_string_pretty(&bundle)?;
+        fs::write(path, content).map_err(|e| anyhow!("写入文件失败: {}", e))?;
+        tracing::info!(
+            "已导出 {} 个软链接配置到 {}",
+            bundle.symlinks.len(),
+            path.display()
+        );
+        Ok(bundle.symlinks.len())
+    }
+
+    // 从分享包导入软链接配置到指定游戏，返回 (导入数量, 跳过数量)
+    pub fn import_configs(&self, app_id: u32, path: &Path) -> Result<(usize, usize)> {
+        let text = fs::read_to_string(path).map_err(|e| anyhow!("读取文件失败: {}", e))?;
+        let bundle = SymlinkBundle::from_text(&text)?;
+        let (configs, skipped) = bundle.to_configs(app_id, &self.get_configs_for_app(app_id)?);
+        let added = configs.len();
+        for config in configs {
+            self.add_config(config)?;
+        }
+        tracing::info!("已导入 {} 个软链接配置，跳过 {} 个", added, skipped);
+        Ok((added, skipped))
+    }
+
+    // 扫描配置下的文件并与云端列表对比，云端 sha 从 remotecache.vdf 补全
+    pub fn plan_sync(
+        &self,
+        config: &SymlinkConfig,
+        mut cloud_files: Vec<CloudFileState>,
+    ) -> Result<SyncPlan> {
+        let local_files = self.scan_symlink_files(config)?;
+
+        let parser = crate::vdf_parser::VdfParser::with_user_id(
+            self.steam_path.clone(),
+            self.user_id.clone(),
+        );
+        let shas: HashMap<String, String> = parser
+            .parse_remotecache(config.app_id)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|entry| entry.root == 0)
+            .map(|entry| (entry.filename, entry.sha))
+            .collect();
+        for file in &mut cloud_files {
+            if file.sha.is_none() {
+                file.sha = shas.get(&file.name).cloned();
+            }
+        }
+
+        Ok(plan_sync_files(
+            &local_files,
+            &cloud_files,
+            &config.remote_subfolder,
+        ))
+    }
+
+    // 把链接位置上已有的普通目录迁移为软链接：
+    // 备份原目录 → 文件移入目标目录 → 删除原目录 → 创建链接 → 验证，任一步失败都会回滚
+    pub fn migrate_to_symlink(&self, config: &SymlinkConfig) -> Result<MigrationRecord> {
+        let remote_dir = self.get_remote_dir(config.app_id);
+        let link_path = config.get_link_path(&remote_dir);
+        let target_path = config.get_target_path(&remote_dir);
+
+        match link_path.symlink_metadata() {
+            Ok(metadata) if metadata.is_dir() => {}
+            _ => return Err(anyhow!("链接位置不是已有的目录: {:?}", link_path)),
+        }
+
+        let files = list_relative_files(&link_path)?;
+
+        // 预检查：目标目录中已有内容不同的同名文件时不做任何修改
+        let conflicts: Vec<String> = files
+            .iter()
+            .filter(|rel| {
+                let dest = target_path.join(rel);
+                dest.exists() && !same_content(&link_path.join(rel), &dest)
+            })
+            .map(|rel| rel.to_string_lossy().to_string())
+            .collect();
+        if !conflicts.is_empty() {
+            return Err(anyhow!(
+                "目标目录中已有 {} 个内容不同的同名文件: {}",
+                conflicts.len(),
+                conflicts.join(", ")
+            ));
+        }
+
+        let migrated_at = chrono::Utc::now().timestamp();
+        let backup_dir = self
+            .backup_root()?
+            .join(format!("{}_{}", config.id, migrated_at));
+        let mut record = MigrationRecord {
+            config_id: config.id.clone(),
+            app_id: config.app_id,
+            link_path: link_path.clone(),
+            target_path: target_path.clone(),
+            backup_dir,
+            target_existed: target_path.exists(),
+            moved_files: Vec::new(),
+            migrated_at,
+        };
+
+        copy_dir_all(&link_path, &record.backup_data_dir())
+            .map_err(|e| anyhow!("备份原目录失败: {}", e))?;
+        record.save()?;
+        tracing::info!(
+            "已备份 {:?} 到 {:?} ({} 个文件)",
+            link_path,
+            record.backup_dir,
+            files.len()
+        );
+
+        if let Err(e) = self.move_into_link(config, &files, &mut record) {
+            tracing::error!("迁移失败，开始回滚: {}", e);
+            // 回滚时保留备份，即使恢复看起来成功也能再核对
+            return match self.rollback_migration(&record) {
+                Ok(()) => Err(anyhow!(
+                    "迁移失败，已回滚: {}，原数据备份保留在 {:?}",
+                    e,
+                    record.backup_data_dir()
+                )),
+                Err(undo_err) => Err(anyhow!(
+                    "迁移失败: {}，回滚失败: {}，原数据备份在 {:?}",
+                    e,
+                    undo_err,
+                    record.backup_data_dir()
+                )),
+            };
+        }
+
+        record.save()?;
+        tracing::info!("迁移完成: {:?} → {:?}", link_path, target_path);
+        Ok(record)
+    }
+
+    fn move_into_link(
+        &self,
+        config: &SymlinkConfig,
+        files: &[PathBuf],
+        record: &mut MigrationRecord,
+    ) -> Result<()> {
+        fs::create_dir_all(&record.target_path)?;
+        for rel in files {
+            let dest = record.target_path.join(rel);
+            // 预检查已确认同名文件内容一致
+            if dest.exists() {
+                continue;
+            }
+            if let Some(parent) = dest.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(record.link_path.join(rel), &dest)?;
+            record.moved_files.push(rel.clone());
+        }
+        record.save()?;
+
+        fs::remove_dir_all(&record.link_path).map_err(|e| anyhow!("删除原目录失败: {}", e))?;
+        create_symlink_platform(&record.target_path, &record.link_path)?;
+
+        match self.verify_symlink(config) {
+            LinkStatus::Valid => Ok(()),
+            status => Err(anyhow!("链接验证失败: {}", status.description())),
+        }
+    }
+
+    // 撤销迁移：删除链接，恢复原目录（迁移后经链接修改过的文件以目标目录中的最新内容为准），
+    // 移除迁移时写入目标目录的文件，最后删除备份
+    pub fn undo_migration(&self, record: &MigrationRecord) -> Result<()> {
+        self.rollback_migration(record)?;
+        fs::remove_dir_all(&record.backup_dir).map_err(|e| anyhow!("删除备份失败: {}", e))?;
+        tracing::info!("已撤销迁移: {:?}", record.link_path);
+        Ok(())
+    }
+
+    // 恢复原目录并移除迁移时写入目标目录的文件，备份保持不动
+    fn rollback_migration(&self, record: &MigrationRecord) -> Result<()> {
+        if let Ok(metadata) = record.link_path.symlink_metadata()
+            && metadata.file_type().is_symlink()
+        {
+            remove_symlink_platform(&record.link_path)?;
+        }
+
+        // 原目录可能完整、不存在，或在删除中途失败只剩一部分，统一把缺失的文件从备份补回
+        fs::create_dir_all(&record.link_path).map_err(|e| anyhow!("从备份恢复失败: {}", e))?;
+        let backup = record.backup_data_dir();
+        for rel in list_relative_files(&backup)? {
+            let dest = record.link_path.join(&rel);
+            if dest.exists() {
+                continue;
+            }
+            if let Some(parent) = dest.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(backup.join(&rel), &dest).map_err(|e| anyhow!("从备份恢复失败: {}", e))?;
+        }
+        for rel in &record.moved_files {
+            let current = record.target_path.join(rel);
+            if current.is_file() {
+                fs::copy(&current, record.link_path.join(rel))?;
+            }
+        }
+
+        for rel in &record.moved_files {
+            let file = record.target_path.join(rel);
+            if file.is_file() {
+                fs::remove_file(&file)?;
+            }
+            remove_empty_parents(&file, &record.target_path);
+        }
+        if !record.target_existed {
+            let _ = fs::remove_dir(&record.target_path);
+        }
+        tracing::info!(
+            "已恢复原目录 {:?}，备份位于 {:?}",
+            record.link_path,
+            record.backup_dir
+        );
+        Ok(())
+    }
+
+    // 查找配置最近一次可撤销的迁移
+    pub fn find_migration(&self, config_id: &str) -> Option<MigrationRecord> {
+        let entries = fs::read_dir(self.backup_root().ok()?).ok()?;
+        entries
+            .flatten()
+            .filter(|e| e.file_name().to_string_lossy().starts_with(config_id))
+            .filter_map(|e| fs::read_to_string(e.path().join(MIGRATION_RECORD_FILE)).ok())
+            .filter_map(|content| serde_json::from_str::<MigrationRecord>(&content).ok())
+            .max_by_key(|r| r.migrated_at)
+    }
+
+    // 确认迁移结果后删除备份，之后无法撤销
+    pub fn discard_migration_backup(&self, record: &MigrationRecord) -> Result<()> {
+        fs::remove_dir_all(&record.backup_dir).map_err(|e| anyhow!("删除备份失败: {}", e))
+    }
+
     // 扫描指定目录下的所有文件
     fn scan_directory(&self, dir: &Path, prefix: &str) -> Result<Vec<(String, PathBuf, u64)>> {
         use walkdir::WalkDir;
@@ -363,6 +954,14 @@ impl SymlinkManager {
     }
 }
 
+// 展开本地路径，失败时保留原文（链接状态会显示为不存在）
+fn expand_local_path(raw: &str) -> PathBuf {
+    crate::path_resolver::expand_portable_path(raw).unwrap_or_else(|e| {
+        tracing::warn!("无法展开软链接路径 {}: {}", raw, e);
+        PathBuf::from(raw)
+    })
+}
+
 // 获取当前平台名称
 fn get_current_platform() -> &'static str {
     #[cfg(target_os = "windows")]
@@ -383,6 +982,52 @@ fn get_current_platform() -> &'static str {
     }
 }
 
+// 目录下所有文件的相对路径（不跟随软链接）
+fn list_relative_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in walkdir::WalkDir::new(dir) {
+        let entry = entry?;
+        if !entry.file_type().is_dir() {
+            let rel = entry
+                .path()
+                .strip_prefix(dir)
+                .map_err(|e| anyhow!("无法计算相对路径: {}", e))?;
+            files.push(rel.to_path_buf());
+        }
+    }
+    Ok(files)
+}
+
+fn copy_dir_all(src: &Path, dst: &Path) -> Result<()> {
+    fs::create_dir_all(dst)?;
+    for rel in list_relative_files(src)? {
+        let dest = dst.join(&rel);
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(src.join(&rel), &dest)?;
+    }
+    Ok(())
+}
+
+fn same_content(a: &Path, b: &Path) -> bool {
+    match (fs::read(a), fs::read(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+// 删除 file 到 root 之间变空的目录
+fn remove_empty_parents(file: &Path, root: &Path) {
+    let mut dir = file.parent();
+    while let Some(d) = dir {
+        if d == root || !d.starts_with(root) || fs::remove_dir(d).is_err() {
+            break;
+        }
+        dir = d.parent();
+    }
+}
+
 // 跨平台创建软链接
 #[cfg(unix)]
 fn create_symlink_platform(target: &Path, link: &Path) -> Result<()> {
@@ -472,3 +1117,249 @@ fn generate_symlink_commands(target: &Path, link: &Path) -> Vec<String> {
         vec![format!("# 不支持的平台")]
     }
 }
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    struct Fixture {
+        root: tempfile::TempDir,
+        manager: SymlinkManager,
+        config: SymlinkConfig,
+    }
+
+    fn fixture() -> Fixture {
+        let root = tempfile::TempDir::new().unwrap();
+        let saves = root.path().join("game").join("Saves");
+        fs::create_dir_all(saves.join("slot1")).unwrap();
+        fs::write(saves.join("profile.sav"), b"profile").unwrap();
+        fs::write(saves.join("slot1").join("data.sav"), b"slot").unwrap();
+
+        let manager = SymlinkManager::new(root.path().join("steam"), "1".to_string())
+            .unwrap()
+            .with_backup_root(root.path().join("backups"));
+        let config = SymlinkConfig::new(
+            480,
+            LinkDirection::LocalToRemote,
+            saves,
+            "Saves".to_string(),
+        );
+        Fixture {
+            root,
+            manager,
+            config,
+        }
+    }
+
+    #[test]
+    fn migrates_existing_folder_and_undoes() {
+        let f = fixture();
+        let remote_saves = f.manager.get_remote_dir(480).join("Saves");
+        assert_eq!(f.manager.verify_symlink(&f.config), LinkStatus::Conflict);
+
+        let record = f.manager.migrate_to_symlink(&f.config).unwrap();
+        assert_eq!(f.manager.verify_symlink(&f.config), LinkStatus::Valid);
+        assert_eq!(record.moved_files.len(), 2);
+        assert_eq!(
+            fs::read(remote_saves.join("profile.sav")).unwrap(),
+            b"profile"
+        );
+        assert!(record.backup_dir.join("data").join("profile.sav").exists());
+
+        // 迁移后经链接写入的新内容在撤销时保留
+        fs::write(f.config.local_path.join("profile.sav"), b"updated").unwrap();
+
+        let found = f.manager.find_migration(&f.config.id).unwrap();
+        f.manager.undo_migration(&found).unwrap();
+        assert_eq!(f.manager.verify_symlink(&f.config), LinkStatus::Conflict);
+        assert_eq!(
+            fs::read(f.config.local_path.join("profile.sav")).unwrap(),
+            b"updated"
+        );
+        assert!(f.config.local_path.join("slot1").join("data.sav").exists());
+        assert!(!remote_saves.exists());
+        assert!(f.manager.find_migration(&f.config.id).is_none());
+    }
+
+    #[test]
+    fn rollback_restores_partially_removed_source() {
+        let f = fixture();
+        let remote_saves = f.manager.get_remote_dir(480).join("Saves");
+        let record = f.manager.migrate_to_symlink(&f.config).unwrap();
+
+        // 模拟删除原目录中途失败：链接尚未创建，原目录只剩一部分文件
+        fs::remove_file(&f.config.local_path).unwrap();
+        fs::create_dir_all(f.config.local_path.join("slot1")).unwrap();
+        fs::write(f.config.local_path.join("slot1").join("data.sav"), b"slot").unwrap();
+
+        f.manager.rollback_migration(&record).unwrap();
+        assert_eq!(
+            fs::read(f.config.local_path.join("profile.sav")).unwrap(),
+            b"profile"
+        );
+        assert_eq!(
+            fs::read(f.config.local_path.join("slot1").join("data.sav")).unwrap(),
+            b"slot"
+        );
+        assert!(!remote_saves.exists());
+        // 回滚不删除备份
+        assert!(record.backup_dir.join("data").join("profile.sav").exists());
+    }
+
+    #[test]
+    fn repairs_broken_links() {
+        let f = fixture();
+        let health = |status| LinkHealth {
+            config: f.config.clone(),
+            status,
+            platform_mismatch: false,
+        };
+        assert!(health(LinkStatus::Valid).repair_actions().is_empty());
+        assert_eq!(
+            health(LinkStatus::Broken).repair_actions()[0],
+            RepairAction::RecreateLink
+        );
+        let mut foreign = health(LinkStatus::Valid);
+        foreign.platform_mismatch = true;
+        assert!(!foreign.is_healthy());
+        assert_eq!(foreign.repair_actions()[0], RepairAction::AdoptPlatform);
+
+        // 链接指向的 remote 子目录被删除后重建
+        fs::remove_dir_all(&f.config.local_path).unwrap();
+        f.manager.create_symlink(&f.config).unwrap();
+        fs::remove_dir_all(f.manager.get_remote_dir(480).join("Saves")).unwrap();
+        assert_eq!(f.manager.verify_symlink(&f.config), LinkStatus::Broken);
+        f.manager
+            .repair(&f.config, RepairAction::RecreateLink)
+            .unwrap();
+        assert_eq!(f.manager.verify_symlink(&f.config), LinkStatus::Valid);
+    }
+
+    #[test]
+    fn refuses_to_overwrite_different_target_files() {
+        let f = fixture();
+        let remote_saves = f.manager.get_remote_dir(480).join("Saves");
+        fs::create_dir_all(&remote_saves).unwrap();
+        fs::write(remote_saves.join("profile.sav"), b"other").unwrap();
+
+        assert!(f.manager.migrate_to_symlink(&f.config).is_err());
+        // 未做任何修改
+        assert_eq!(f.manager.verify_symlink(&f.config), LinkStatus::Conflict);
+        assert_eq!(
+            fs::read(remote_saves.join("profile.sav")).unwrap(),
+            b"other"
+        );
+        assert!(!f.root.path().join("backups").exists());
+    }
+
+    #[test]
+    fn plans_sync_against_cloud_listing() {
+        let f = fixture();
+        // RemoteToLocal 扫描本地目录
+        let mut config = f.config.clone();
+        config.direction = LinkDirection::RemoteToLocal;
+        let saves = &config.local_path;
+        fs::write(saves.join("new.sav"), b"new").unwrap();
+        let local = f.manager.scan_symlink_files(&config).unwrap();
+        let local_mtime =
+            crate::game_index::file_mtime(&saves.join("profile.sav")).unwrap() as i64 / 1000;
+
+        let cloud = |name: &str, size: u64, timestamp: i64, sha: Option<&str>| CloudFileState {
+            name: name.to_string(),
+            size,
+            timestamp,
+            sha: sha.map(|s| s.to_string()),
+        };
+        let cloud_files = vec![
+            // 内容一致
+            cloud(
+                "Saves/profile.sav",
+                7,
+                local_mtime - 100,
+                Some(&crate::file_manager::sha1_hex(b"profile").to_uppercase()),
+            ),
+            // 云端较新且内容不同
+            cloud("Saves/slot1/data.sav", 10, local_mtime + 100, None),
+            // 本地已删除
+            cloud("Saves/old.sav", 1, 0, None),
+            // 不在该配置的前缀下
+            cloud("Saves2/other.sav", 1, 0, None),
+            cloud("other.sav", 1, 0, None),
+        ];
+
+        let plan = plan_sync_files(&local, &cloud_files, "Saves");
+        let uploads: Vec<&str> = plan.upload.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(uploads, vec!["Saves/new.sav"]);
+        assert_eq!(plan.unchanged, vec!["Saves/profile.sav"]);
+        assert_eq!(plan.cloud_newer, vec!["Saves/slot1/data.sav"]);
+        assert_eq!(plan.cloud_only, vec!["Saves/old.sav"]);
+
+        // 本地修改后大小变化、云端较旧时上传
+        fs::write(saves.join("profile.sav"), b"profile v2").unwrap();
+        let local = f.manager.scan_symlink_files(&config).unwrap();
+        let plan = plan_sync_files(&local, &cloud_files, "Saves");
+        assert!(plan.upload.iter().any(|(n, _)| n == "Saves/profile.sav"));
+    }
+
+    #[test]
+    fn exports_portable_bundles() {
+        let Some(home) = std::env::var("HOME").ok().filter(|h| !h.is_empty()) else {
+            return;
+        };
+        let saves = PathBuf::from(&home).join("scfm_portable").join("Saves");
+        let config = SymlinkConfig::new(
+            480,
+            LinkDirection::RemoteToLocal,
+            PathBuf::from("$HOME/scfm_portable/Saves"),
+            "Saves".to_string(),
+        );
+        assert_eq!(config.local_path, saves);
+        assert!(config.portable);
+        let absolute = SymlinkConfig::new(
+            480,
+            LinkDirection::RemoteToLocal,
+            PathBuf::from("/opt/scfm/Other"),
+            "Other".to_string(),
+        );
+        assert!(!absolute.portable);
+
+        let bundle = SymlinkBundle::from_configs(
+            480,
+            Some("Spacewar".to_string()),
+            vec![config.clone(), absolute],
+        );
+        let stored = bundle.symlinks[0].local_path.to_string_lossy().to_string();
+        assert!(
+            crate::path_resolver::is_portable_path(&stored),
+            "{}",
+            stored
+        );
+        assert_eq!(
+            bundle.symlinks[1].local_path,
+            PathBuf::from("/opt/scfm/Other")
+        );
+
+        let text = serde_json::to_string(&bundle).unwrap();
+        let bundle = SymlinkBundle::from_text(&text).unwrap();
+        let (imported, skipped) = bundle.to_configs(570, std::slice::from_ref(&config));
+        assert_eq!(skipped, 1);
+        assert_eq!(imported.len(), 1);
+        assert_eq!(imported[0].app_id, 570);
+        assert_eq!(imported[0].remote_subfolder, "Other");
+
+        let (imported, _) = bundle.to_configs(570, &[]);
+        assert_eq!(imported[0].local_path, saves);
+        assert!(imported[0].portable);
+        assert_ne!(imported[0].id, config.id);
+
+        // 读取时展开，保存时重新折叠
+        let entry = imported[0].clone().into_entry();
+        assert_eq!(entry.local_path.to_string_lossy(), stored);
+        assert_eq!(SymlinkConfig::from_entry(entry).local_path, saves);
+
+        assert!(
+            SymlinkBundle::from_text("{\"format_version\": 99, \"app_id\": 1, \"symlinks\": []}")
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,426 @@
+// UFS 配置分享包
+// 将一个或多个 UfsGameConfig 导出为 JSON / VDF 文件，并支持带冲突处理的导入
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::config::{RootOverrideEntry, SaveFileEntry, UfsGameConfig};
+use crate::ufs_text;
+
+// 分享包格式版本，结构变化时递增
+pub const UFS_BUNDLE_FORMAT_VERSION: u32 = 1;
+
+// 分享包中的单个游戏配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UfsBundleEntry {
+    #[serde(default)]
+    pub game_name: Option<String>,
+    #[serde(flatten)]
+    pub config: UfsGameConfig,
+}
+
+// 分享包
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UfsConfigBundle {
+    pub format_version: u32,
+    #[serde(default)]
+    pub exported_at: i64,
+    pub configs: Vec<UfsBundleEntry>,
+}
+
+// 分享包文件格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BundleFormat {
+    Json,
+    Vdf,
+}
+
+impl BundleFormat {
+    // 根据文件扩展名判断格式，默认 JSON
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("vdf") => BundleFormat::Vdf,
+            _ => BundleFormat::Json,
+        }
+    }
+}
+
+// 导入时与已有配置冲突的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImportConflictPolicy {
+    // 保留本地配置，跳过导入
+    #[default]
+    Skip,
+    // 用导入的配置覆盖本地配置
+    Overwrite,
+    // 合并：保留本地条目，追加本地没有的条目
+    Merge,
+}
+
+impl ImportConflictPolicy {
+    pub const fn all() -> &'static [ImportConflictPolicy] {
+        &[
+            ImportConflictPolicy::Skip,
+            ImportConflictPolicy::Overwrite,
+            ImportConflictPolicy::Merge,
+        ]
+    }
+}
+
+// 导入结果统计
+#[derive(Debug, Clone, Default)]
+pub struct ImportSummary {
+    pub added: usize,
+    pub overwritten: usize,
+    pub merged: usize,
+    pub skipped: usize,
+}
+
+impl UfsConfigBundle {
+    // 从配置列表构建分享包，name_of 用于查询游戏名称
+    pub fn from_configs<F>(configs: Vec<UfsGameConfig>, name_of: F) -> Self
+    where
+        F: Fn(u32) -> Option<String>,
+    {
+        Self {
+            format_version: UFS_BUNDLE_FORMAT_VERSION,
+            exported_at: chrono::Utc::now().timestamp(),
+            configs: configs
+                .into_iter()
+                .map(|config| UfsBundleEntry {
+                    game_name: name_of(config.app_id),
+                    config,
+                })
+                .collect(),
+        }
+    }
+
+    // 序列化为指定格式的文本
+    pub fn to_text(&self, format: BundleFormat) -> Result<String> {
+        match format {
+            BundleFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+            BundleFormat::Vdf => Ok(self.to_vdf_text()),
+        }
+    }
+
+    // 从文本解析分享包（自动识别 JSON / VDF）
+    pub fn from_text(text: &str) -> Result<Self> {
+        let bundle = if text.trim_start().starts_with('{') {
+            serde_json::from_str::<UfsConfigBundle>(text)?
+        } else {
+            Self::parse_vdf_text(text)?
+        };
+
+        if bundle.format_version == 0 || bundle.format_version > UFS_BUNDLE_FORMAT_VERSION {
+            return Err(anyhow!(
+                "不支持的分享包版本: {} (当前支持: {})",
+                bundle.format_version,
+                UFS_BUNDLE_FORMAT_VERSION
+            ));
+        }
+        Ok(bundle)
+    }
+
+    // 序列化为 VDF 文本，每个游戏的 ufs 节沿用 ufs_text 的格式
+    fn to_vdf_text(&self) -> String {
+        let mut lines = Vec::new();
+        lines.push("\"ufs_bundle\"".to_string());
+        lines.push("{".to_string());
+        lines.push(format!(
+            "    \"format_version\" \"{}\"",
+            self.format_version
+        ));
+        lines.push(format!("    \"exported_at\" \"{}\"", self.exported_at));
+        lines.push("    \"configs\"".to_string());
+        lines.push("    {".to_string());
+        for entry in &self.configs {
+            lines.push(format!("        \"{}\"", entry.config.app_id));
+            lines.push("        {".to_string());
+            if let Some(name) = &entry.game_name {
+                lines.push(format!(
+                    "            \"name\" \"{}\"",
+                    ufs_text::escape_vdf(name)
+                ));
+            }
+            let ufs = ufs_text::entries_to_ufs_text(
+                &entry.config.settings,
+                &entry.config.savefiles,
+                &entry.config.root_overrides,
+            );
+            for line in ufs.lines() {
+                lines.push(format!("            {}", line));
+            }
+            lines.push("        }".to_string());
+        }
+        lines.push("    }".to_string());
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    // 解析 VDF 文本格式的分享包
+    fn parse_vdf_text(text: &str) -> Result<Self> {
+        let lines: Vec<&str> = text.lines().map(|l| l.trim()).collect();
+        let mut format_version = 0;
+        let mut exported_at = 0;
+        let mut configs = Vec::new();
+
+        let mut i = 0;
+        while i < lines.len() {
+            let line = lines[i];
+
+            if let Some((key, value)) = ufs_text::extract_kv(line) {
+                match key {
+                    "format_version" => format_version = value.parse().unwrap_or(0),
+                    "exported_at" => exported_at = value.parse().unwrap_or(0),
+                    _ => {}
+                }
+                i += 1;
+                continue;
+            }
+
+            // 游戏节：数字 app_id 后跟 "{"
+            if let Some(app_id) = line
+                .strip_prefix('"')
+                .and_then(|l| l.strip_suffix('"'))
+                .and_then(|l| l.parse::<u32>().ok())
+                && lines.get(i + 1) == Some(&"{")
+            {
+                let end = find_block_end(&lines, i + 1);
+                let block = &lines[i + 2..end];
+
+                // 游戏名称在 ufs 节之前
+                let game_name = block
+                    .iter()
+                    .take_while(|l| **l != "\"ufs\"")
+                    .filter_map(|l| ufs_text::extract_escaped_kv(l))
+                    .find(|(key, _)| key == "name")
+                    .map(|(_, value)| value);
+
+                let (settings, savefiles, root_overrides) =
+                    ufs_text::parse_ufs_text(&block.join("\n"));
+                configs.push(UfsBundleEntry {
+                    game_name,
+                    config: UfsGameConfig {
+                        app_id,
+                        savefiles,
+                        root_overrides,
+                        settings,
+                    },
+                });
+                i = end + 1;
+                continue;
+            }
+
+            i += 1;
+        }
+
+        if format_version == 0 && configs.is_empty() {
+            return Err(anyhow!("无法识别的分享包格式"));
+        }
+
+        Ok(Self {
+            format_version,
+            exported_at,
+            configs,
+        })
+    }
+}
+
+// 找到从 open_idx 处 "{" 开始的节对应的 "}" 行号
+fn find_block_end(lines: &[&str], open_idx: usize) -> usize {
+    let mut depth = 0;
+    for (idx, line) in lines.iter().enumerate().skip(open_idx) {
+        match *line {
+            "{" => depth += 1,
+            "}" => {
+                depth -= 1;
+                if depth == 0 {
+                    return idx;
+                }
+            }
+            _ => {}
+        }
+    }
+    lines.len()
+}
+
+// 合并两个配置：保留 existing 的条目，追加 incoming 中不存在的条目
+pub fn merge_game_configs(existing: &UfsGameConfig, incoming: &UfsGameConfig) -> UfsGameConfig {
+    let mut merged = existing.clone();
+
+    for sf in &incoming.savefiles {
+        if !merged.savefiles.iter().any(|e| same_savefile(e, sf)) {
+            merged.savefiles.push(sf.clone());
+        }
+    }
+
+    for ro in &incoming.root_overrides {
+        if !merged.root_overrides.iter().any(|e| same_override(e, ro)) {
+            merged.root_overrides.push(ro.clone());
+        }
+    }
+
+    // 顶层设置：本地未设置的项使用导入值
+    let settings = &mut merged.settings;
+    settings.quota = settings.quota.or(incoming.settings.quota);
+    settings.maxnumfiles = settings.maxnumfiles.or(incoming.settings.maxnumfiles);
+    settings.hidecloudui = settings.hidecloudui.or(incoming.settings.hidecloudui);
+    for extra in &incoming.settings.extra {
+        if !settings.extra.iter().any(|e| e.key == extra.key) {
+            settings.extra.push(extra.clone());
+        }
+    }
+
+    merged
+}
+
+fn same_savefile(a: &SaveFileEntry, b: &SaveFileEntry) -> bool {
+    a.root == b.root && a.path == b.path && a.pattern == b.pattern
+}
+
+fn same_override(a: &RootOverrideEntry, b: &RootOverrideEntry) -> bool {
+    a.original_root == b.original_root && a.os == b.os
+}
+
+// 将分享包写入文件，格式由扩展名决定
+pub fn export_to_file(bundle: &UfsConfigBundle, path: &Path) -> Result<()> {
+    let text = bundle.to_text(BundleFormat::from_path(path))?;
+    std::fs::write(path, text)?;
+    tracing::info!(
+        "已导出 {} 个 UFS 配置到 {}",
+        bundle.configs.len(),
+        path.display()
+    );
+    Ok(())
+}
+
+// 从文件读取分享包
+pub fn read_from_file(path: &Path) -> Result<UfsConfigBundle> {
+    let text = std::fs::read_to_string(path)?;
+    UfsConfigBundle::from_text(&text)
+}
+
+// 导入分享包中的所有配置到本地配置目录
+pub fn import_bundle(
+    bundle: &UfsConfigBundle,
+    policy: ImportConflictPolicy,
+) -> Result<ImportSummary> {
+    let mut summary = ImportSummary::default();
+
+    for entry in &bundle.configs {
+        let incoming = &entry.config;
+        match crate::config::get_ufs_game_config(incoming.app_id) {
+            None => {
+                crate::config::save_ufs_game_config(incoming)?;
+                summary.added += 1;
+            }
+            Some(existing) => match policy {
+                ImportConflictPolicy::Skip => {
+                    tracing::info!("跳过已存在的 UFS 配置: {}", incoming.app_id);
+                    summary.skipped += 1;
+                }
+                ImportConflictPolicy::Overwrite => {
+                    crate::config::save_ufs_game_config(incoming)?;
+                    summary.overwritten += 1;
+                }
+                ImportConflictPolicy::Merge => {
+                    let merged = merge_game_configs(&existing, incoming);
+                    crate::config::save_ufs_game_config(&merged)?;
+                    summary.merged += 1;
+                }
+            },
+        }
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::UfsSettings;
+
+    fn sample_config() -> UfsGameConfig {
+        UfsGameConfig {
+            app_id: 48700,
+            savefiles: vec![SaveFileEntry {
+                root: "WinMyDocuments".to_string(),
+                path: "Mount&Blade Warband/Savegames".to_string(),
+                pattern: "*.sav".to_string(),
+                platforms: vec!["windows".to_string()],
+                recursive: true,
+                extra: Vec::new(),
+            }],
+            root_overrides: vec![RootOverrideEntry {
+                original_root: "WinMyDocuments".to_string(),
+                os: "macos".to_string(),
+                new_root: "MacAppSupport".to_string(),
+                ..Default::default()
+            }],
+            settings: UfsSettings {
+                quota: Some(1_000_000),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn roundtrip_json_and_vdf() {
+        let bundle = UfsConfigBundle::from_configs(vec![sample_config()], |_| {
+            Some("Mount & Blade: Warband".to_string())
+        });
+
+        for format in [BundleFormat::Json, BundleFormat::Vdf] {
+            let text = bundle.to_text(format).unwrap();
+            let parsed = UfsConfigBundle::from_text(&text).unwrap();
+
+            assert_eq!(parsed.format_version, UFS_BUNDLE_FORMAT_VERSION);
+            assert_eq!(parsed.configs.len(), 1);
+            let entry = &parsed.configs[0];
+            assert_eq!(entry.game_name.as_deref(), Some("Mount & Blade: Warband"));
+            assert_eq!(entry.config.app_id, 48700);
+            assert_eq!(entry.config.savefiles[0].pattern, "*.sav");
+            assert_eq!(entry.config.savefiles[0].platforms, vec!["windows"]);
+            assert_eq!(entry.config.root_overrides[0].new_root, "MacAppSupport");
+            assert_eq!(entry.config.settings.quota, Some(1_000_000));
+        }
+    }
+
+    #[test]
+    fn vdf_roundtrip_keeps_quotes_in_game_names() {
+        let name = r#"The "Quoted" Game \ Director's Cut"#;
+        let bundle =
+            UfsConfigBundle::from_configs(vec![sample_config()], |_| Some(name.to_string()));
+
+        let text = bundle.to_text(BundleFormat::Vdf).unwrap();
+        assert!(text.contains(r#""name" "The \"Quoted\" Game \\ Director's Cut""#));
+        let parsed = UfsConfigBundle::from_text(&text).unwrap();
+        assert_eq!(parsed.configs[0].game_name.as_deref(), Some(name));
+        assert_eq!(parsed.configs[0].config.savefiles.len(), 1);
+    }
+
+    #[test]
+    fn rejects_newer_format_version() {
+        let text = r#"{"format_version": 99, "configs": []}"#;
+        assert!(UfsConfigBundle::from_text(text).is_err());
+    }
+
+    #[test]
+    fn merge_appends_only_new_entries() {
+        let existing = sample_config();
+        let mut incoming = sample_config();
+        incoming.savefiles.push(SaveFileEntry {
+            root: "MacAppSupport".to_string(),
+            path: "Warband".to_string(),
+            pattern: "*".to_string(),
+            platforms: vec!["macos".to_string()],
+            recursive: true,
+            extra: Vec::new(),
+        });
+
+        let merged = merge_game_configs(&existing, &incoming);
+        assert_eq!(merged.savefiles.len(), 2);
+        assert_eq!(merged.root_overrides.len(), 1);
+    }
+}
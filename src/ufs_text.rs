@@ -1,13 +1,33 @@
 // UFS 配置文本 ↔ 编辑数据双向转换
 
-use crate::config::{PathTransform, RootOverrideEntry, SaveFileEntry};
+use crate::config::{
+    PathTransform, RootOverrideEntry, SaveFileEntry, UfsExtraKey, UfsExtraValue, UfsSettings,
+};
 
 // 将编辑数据序列化为 VDF 文本格式
-pub fn entries_to_ufs_text(savefiles: &[SaveFileEntry], overrides: &[RootOverrideEntry]) -> String {
+pub fn entries_to_ufs_text(
+    settings: &UfsSettings,
+    savefiles: &[SaveFileEntry],
+    overrides: &[RootOverrideEntry],
+) -> String {
     let mut lines = Vec::new();
     lines.push("\"ufs\"".to_string());
     lines.push("{".to_string());
 
+    // 顶层设置
+    if let Some(quota) = settings.quota {
+        lines.push(format!("    \"quota\" \"{}\"", quota));
+    }
+    if let Some(maxnumfiles) = settings.maxnumfiles {
+        lines.push(format!("    \"maxnumfiles\" \"{}\"", maxnumfiles));
+    }
+    if let Some(hidecloudui) = settings.hidecloudui {
+        lines.push(format!(
+            "    \"hidecloudui\" \"{}\"",
+            if hidecloudui { "1" } else { "0" }
+        ));
+    }
+
     // savefiles section
     if !savefiles.is_empty() {
         lines.push("    \"savefiles\"".to_string());
@@ -26,6 +46,7 @@ pub fn entries_to_ufs_text(savefiles: &[SaveFileEntry], overrides: &[RootOverrid
                 let oslist = platforms_to_oslist(&sf.platforms);
                 lines.push(format!("            \"platforms\" \"{}\"", oslist));
             }
+            push_extra_keys(&mut lines, &sf.extra, 3);
             lines.push("        }".to_string());
         }
         lines.push("    }".to_string());
@@ -40,7 +61,12 @@ pub fn entries_to_ufs_text(savefiles: &[SaveFileEntry], overrides: &[RootOverrid
             lines.push("        {".to_string());
             lines.push(format!("            \"root\" \"{}\"", ro.original_root));
             lines.push(format!("            \"os\" \"{}\"", ro.os));
-            lines.push("            \"oscompare\" \"=\"".to_string());
+            let oscompare = if ro.oscompare.is_empty() {
+                "="
+            } else {
+                ro.oscompare.as_str()
+            };
+            lines.push(format!("            \"oscompare\" \"{}\"", oscompare));
             lines.push(format!("            \"useinstead\" \"{}\"", ro.new_root));
             if !ro.path_transforms.is_empty() {
                 // pathtransforms 模式
@@ -60,40 +86,144 @@ pub fn entries_to_ufs_text(savefiles: &[SaveFileEntry], overrides: &[RootOverrid
             } else if !ro.add_path.is_empty() {
                 lines.push(format!("            \"addpath\" \"{}\"", ro.add_path));
             }
+            push_extra_keys(&mut lines, &ro.extra, 3);
             lines.push("        }".to_string());
         }
         lines.push("    }".to_string());
     }
 
+    // 未识别的顶层键放在最后
+    push_extra_keys(&mut lines, &settings.extra, 1);
+
     lines.push("}".to_string());
     lines.join("\n")
 }
 
+// 按原样输出未识别的键
+fn push_extra_keys(lines: &mut Vec<String>, extra: &[UfsExtraKey], indent: usize) {
+    let indent_str = "    ".repeat(indent);
+    for item in extra {
+        match &item.value {
+            UfsExtraValue::Section(children) => {
+                lines.push(format!("{}\"{}\"", indent_str, item.key));
+                lines.push(format!("{}{{", indent_str));
+                push_extra_keys(lines, children, indent + 1);
+                lines.push(format!("{}}}", indent_str));
+            }
+            value => {
+                let text = value.as_text().unwrap_or_default();
+                lines.push(format!("{}\"{}\" \"{}\"", indent_str, item.key, text));
+            }
+        }
+    }
+}
+
 // 从 VDF 文本解析编辑数据
-pub fn parse_ufs_text(text: &str) -> (Vec<SaveFileEntry>, Vec<RootOverrideEntry>) {
+pub fn parse_ufs_text(text: &str) -> (UfsSettings, Vec<SaveFileEntry>, Vec<RootOverrideEntry>) {
+    let mut settings = UfsSettings::default();
     let mut savefiles = Vec::new();
     let mut overrides = Vec::new();
 
     let lines: Vec<&str> = text.lines().collect();
-    let mut i = 0;
 
+    // 定位 "ufs" 节，找不到时把整段文本当作 ufs 节内容
+    let mut i = (0..lines.len())
+        .find(|&idx| section_key(&lines, idx) == Some("ufs"))
+        .map(|idx| idx + 2)
+        .unwrap_or(0);
     while i < lines.len() {
         let trimmed = lines[i].trim();
+        if trimmed == "}" {
+            break;
+        }
 
-        if trimmed == "\"savefiles\"" {
-            i += 1; // skip "{"
+        if let Some((key, value)) = extract_kv(trimmed) {
+            // 无法解析的数值按原文保留为未识别的键
+            let unparsed = match key {
+                "quota" => value.parse().map(|v| settings.quota = Some(v)).is_err(),
+                "maxnumfiles" => value
+                    .parse()
+                    .map(|v| settings.maxnumfiles = Some(v))
+                    .is_err(),
+                "hidecloudui" => {
+                    settings.hidecloudui = Some(value != "0");
+                    false
+                }
+                _ => true,
+            };
+            if unparsed {
+                settings.extra.push(UfsExtraKey {
+                    key: key.to_string(),
+                    value: UfsExtraValue::Value(value.to_string()),
+                });
+            }
             i += 1;
-            i = parse_savefiles_section(&lines, i, &mut savefiles);
-        } else if trimmed == "\"rootoverrides\"" {
-            i += 1; // skip "{"
+            continue;
+        }
+
+        if let Some(key) = section_key(&lines, i) {
+            match key {
+                "savefiles" => {
+                    i = parse_savefiles_section(&lines, i + 2, &mut savefiles);
+                }
+                "rootoverrides" => {
+                    i = parse_overrides_section(&lines, i + 2, &mut overrides);
+                }
+                _ => {
+                    let (children, next_i) = parse_extra_section(&lines, i + 2);
+                    settings.extra.push(UfsExtraKey {
+                        key: key.to_string(),
+                        value: UfsExtraValue::Section(children),
+                    });
+                    i = next_i;
+                }
+            }
+            continue;
+        }
+
+        i += 1;
+    }
+
+    (settings, savefiles, overrides)
+}
+
+// 若第 i 行是 "key" 且下一行是 "{"，返回节名
+fn section_key<'a>(lines: &[&'a str], i: usize) -> Option<&'a str> {
+    let trimmed = lines[i].trim();
+    let key = trimmed.strip_prefix('"')?.strip_suffix('"')?;
+    if key.contains('"') || lines.get(i + 1).map(|l| l.trim()) != Some("{") {
+        return None;
+    }
+    Some(key)
+}
+
+// 解析未识别的子节，返回子键列表和闭合 } 之后的行号
+fn parse_extra_section(lines: &[&str], mut i: usize) -> (Vec<UfsExtraKey>, usize) {
+    let mut children = Vec::new();
+    while i < lines.len() {
+        let trimmed = lines[i].trim();
+        if trimmed == "}" {
+            return (children, i + 1);
+        }
+
+        if let Some((key, value)) = extract_kv(trimmed) {
+            children.push(UfsExtraKey {
+                key: key.to_string(),
+                value: UfsExtraValue::Value(value.to_string()),
+            });
             i += 1;
-            i = parse_overrides_section(&lines, i, &mut overrides);
+        } else if let Some(key) = section_key(lines, i) {
+            let (sub, next_i) = parse_extra_section(lines, i + 2);
+            children.push(UfsExtraKey {
+                key: key.to_string(),
+                value: UfsExtraValue::Section(sub),
+            });
+            i = next_i;
         } else {
             i += 1;
         }
     }
-
-    (savefiles, overrides)
+    (children, i)
 }
 
 // 解析 savefiles 节内的所有条目
@@ -137,6 +267,7 @@ fn parse_savefile_entry(lines: &[&str], mut i: usize) -> (SaveFileEntry, usize)
         pattern: "*".to_string(),
         platforms: vec!["all".to_string()],
         recursive: true,
+        extra: Vec::new(),
     };
 
     while i < lines.len() {
@@ -154,8 +285,27 @@ fn parse_savefile_entry(lines: &[&str], mut i: usize) -> (SaveFileEntry, usize)
                 "platforms" => {
                     entry.platforms = oslist_to_platforms(value);
                 }
-                _ => {}
+                _ => entry.extra.push(UfsExtraKey {
+                    key: key.to_string(),
+                    value: UfsExtraValue::Value(value.to_string()),
+                }),
             }
+        } else if let Some(key) = section_key(lines, i) {
+            let (children, next_i) = parse_extra_section(lines, i + 2);
+            if key == "platforms" {
+                // appinfo 中的 platforms 为子节形式: "1" "Windows"
+                entry.platforms = children
+                    .into_iter()
+                    .filter_map(|c| c.value.as_text().map(|v| v.to_lowercase()))
+                    .collect();
+            } else {
+                entry.extra.push(UfsExtraKey {
+                    key: key.to_string(),
+                    value: UfsExtraValue::Section(children),
+                });
+            }
+            i = next_i;
+            continue;
         }
         i += 1;
     }
@@ -200,8 +350,10 @@ fn parse_override_entry(lines: &[&str], mut i: usize) -> (RootOverrideEntry, usi
         original_root: String::new(),
         os: String::new(),
         new_root: String::new(),
+        oscompare: "=".to_string(),
         add_path: String::new(),
         path_transforms: Vec::new(),
+        extra: Vec::new(),
     };
 
     while i < lines.len() {
@@ -223,11 +375,22 @@ fn parse_override_entry(lines: &[&str], mut i: usize) -> (RootOverrideEntry, usi
             match key {
                 "root" => entry.original_root = value.to_string(),
                 "os" => entry.os = value.to_string(),
+                "oscompare" => entry.oscompare = value.to_string(),
                 "useinstead" => entry.new_root = value.to_string(),
                 "addpath" => entry.add_path = value.to_string(),
-                // oscompare 忽略，固定为 "="
-                _ => {}
+                _ => entry.extra.push(UfsExtraKey {
+                    key: key.to_string(),
+                    value: UfsExtraValue::Value(value.to_string()),
+                }),
             }
+        } else if let Some(key) = section_key(lines, i) {
+            let (children, next_i) = parse_extra_section(lines, i + 2);
+            entry.extra.push(UfsExtraKey {
+                key: key.to_string(),
+                value: UfsExtraValue::Section(children),
+            });
+            i = next_i;
+            continue;
         }
         i += 1;
     }
@@ -282,7 +445,7 @@ fn parse_pathtransforms(
 }
 
 // 提取 "key" "value" 对
-fn extract_kv(line: &str) -> Option<(&str, &str)> {
+pub fn extract_kv(line: &str) -> Option<(&str, &str)> {
     let mut parts = line.split('"');
     parts.next()?; // 前导空白
     let key = parts.next()?;
@@ -291,6 +454,38 @@ fn extract_kv(line: &str) -> Option<(&str, &str)> {
     Some((key, value))
 }
 
+// 转义 VDF 字符串中的 " 和 \
+pub fn escape_vdf(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// 同 extract_kv，但识别 \" 和 \\ 转义，返回还原后的键值
+pub fn extract_escaped_kv(line: &str) -> Option<(String, String)> {
+    let mut chars = line.chars();
+    let mut tokens = Vec::with_capacity(2);
+    while tokens.len() < 2 {
+        chars.find(|&c| c == '"')?;
+        let mut token = String::new();
+        loop {
+            match chars.next()? {
+                '"' => break,
+                '\\' => match chars.next()? {
+                    c @ ('"' | '\\') => token.push(c),
+                    c => {
+                        token.push('\\');
+                        token.push(c);
+                    }
+                },
+                c => token.push(c),
+            }
+        }
+        tokens.push(token);
+    }
+    let value = tokens.pop()?;
+    let key = tokens.pop()?;
+    Some((key, value))
+}
+
 // 平台列表转 oslist 字符串 (Steam 格式)
 fn platforms_to_oslist(platforms: &[String]) -> String {
     platforms
@@ -327,17 +522,18 @@ mod tests {
             pattern: "*.sav".to_string(),
             platforms: vec!["all".to_string()],
             recursive: true,
+            extra: Vec::new(),
         }];
         let overrides = vec![RootOverrideEntry {
             original_root: "WinAppDataLocal".to_string(),
             os: "macos".to_string(),
             new_root: "MacAppSupport".to_string(),
             add_path: "saves".to_string(),
-            path_transforms: Vec::new(),
+            ..Default::default()
         }];
 
-        let text = entries_to_ufs_text(&savefiles, &overrides);
-        let (parsed_sf, parsed_ro) = parse_ufs_text(&text);
+        let text = entries_to_ufs_text(&UfsSettings::default(), &savefiles, &overrides);
+        let (_, parsed_sf, parsed_ro) = parse_ufs_text(&text);
 
         assert_eq!(parsed_sf.len(), 1);
         assert_eq!(parsed_sf[0].root, "MacAppSupport");
@@ -358,19 +554,114 @@ mod tests {
             original_root: "WinMyDocuments".to_string(),
             os: "macos".to_string(),
             new_root: "MacHome".to_string(),
-            add_path: String::new(),
             path_transforms: vec![PathTransform {
                 find: "My Games/TestGame".to_string(),
                 replace: "Library/TestGame".to_string(),
             }],
+            ..Default::default()
         }];
 
-        let text = entries_to_ufs_text(&[], &overrides);
-        let (_, parsed_ro) = parse_ufs_text(&text);
+        let text = entries_to_ufs_text(&UfsSettings::default(), &[], &overrides);
+        let (_, _, parsed_ro) = parse_ufs_text(&text);
 
         assert_eq!(parsed_ro.len(), 1);
         assert_eq!(parsed_ro[0].path_transforms.len(), 1);
         assert_eq!(parsed_ro[0].path_transforms[0].find, "My Games/TestGame");
         assert_eq!(parsed_ro[0].path_transforms[0].replace, "Library/TestGame");
     }
+
+    #[test]
+    fn roundtrip_settings_and_unknown_keys() {
+        let text = r#""ufs"
+{
+    "quota" "1048576"
+    "maxnumfiles" "100"
+    "hidecloudui" "1"
+    "savefiles"
+    {
+        "0"
+        {
+            "root" "WinAppDataLocal"
+            "path" "Game"
+            "pattern" "*.sav"
+            "platforms"
+            {
+                "1" "Windows"
+            }
+            "futureflag" "2"
+        }
+    }
+    "rootoverrides"
+    {
+        "0"
+        {
+            "root" "WinAppDataLocal"
+            "os" "linux"
+            "oscompare" "!="
+            "useinstead" "LinuxHome"
+        }
+    }
+    "newsection"
+    {
+        "nested" "value"
+    }
+}"#;
+
+        let (settings, savefiles, overrides) = parse_ufs_text(text);
+        assert_eq!(settings.quota, Some(1048576));
+        assert_eq!(settings.maxnumfiles, Some(100));
+        assert_eq!(settings.hidecloudui, Some(true));
+        assert_eq!(savefiles[0].platforms, vec!["windows".to_string()]);
+        assert_eq!(savefiles[0].extra[0].key, "futureflag");
+        assert_eq!(overrides[0].oscompare, "!=");
+
+        // 再次序列化后内容不变
+        let regenerated = entries_to_ufs_text(&settings, &savefiles, &overrides);
+        let (settings2, savefiles2, overrides2) = parse_ufs_text(&regenerated);
+        assert_eq!(settings2, settings);
+        assert_eq!(savefiles2[0].extra, savefiles[0].extra);
+        assert_eq!(overrides2[0].oscompare, "!=");
+        assert_eq!(
+            settings2.extra,
+            vec![UfsExtraKey {
+                key: "newsection".to_string(),
+                value: UfsExtraValue::Section(vec![UfsExtraKey {
+                    key: "nested".to_string(),
+                    value: UfsExtraValue::Value("value".to_string()),
+                }]),
+            }]
+        );
+    }
+
+    #[test]
+    fn keeps_unparsable_numbers_verbatim() {
+        let text = r#""ufs"
+{
+    "quota" "10MB"
+    "maxnumfiles" "-1"
+}"#;
+
+        let (settings, savefiles, overrides) = parse_ufs_text(text);
+        assert_eq!(settings.quota, None);
+        assert_eq!(settings.maxnumfiles, None);
+        assert_eq!(
+            settings.extra,
+            vec![
+                UfsExtraKey {
+                    key: "quota".to_string(),
+                    value: UfsExtraValue::Value("10MB".to_string()),
+                },
+                UfsExtraKey {
+                    key: "maxnumfiles".to_string(),
+                    value: UfsExtraValue::Value("-1".to_string()),
+                },
+            ]
+        );
+
+        let regenerated = entries_to_ufs_text(&settings, &savefiles, &overrides);
+        assert!(regenerated.contains(r#""quota" "10MB""#));
+        assert!(regenerated.contains(r#""maxnumfiles" "-1""#));
+        let (settings2, _, _) = parse_ufs_text(&regenerated);
+        assert_eq!(settings2, settings);
+    }
 }
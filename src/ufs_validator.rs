@@ -0,0 +1,443 @@
+// UFS 配置校验
+// 在保存/注入前检查 UfsGameConfig 中的常见错误，错误会阻止注入，警告仅提示
+
+use crate::config::{RootOverrideEntry, SaveFileEntry, UfsGameConfig};
+use crate::path_resolver::RootType;
+
+// Steam oslist 中可识别的平台名称
+pub const KNOWN_PLATFORMS: &[&str] = &["all", "windows", "macos", "linux"];
+
+// 问题严重程度
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UfsIssueSeverity {
+    Error,
+    Warning,
+}
+
+// 问题类型（索引均为 0 起始）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UfsIssueKind {
+    // savefile 的 root 无法识别
+    UnknownRoot { index: usize, root: String },
+    // savefile 的 pattern 为空
+    EmptyPattern { index: usize },
+    // savefile 的 path 是绝对路径
+    AbsolutePath { index: usize, path: String },
+    // savefile 的 path 包含 ".." 段
+    ParentDirSegment { index: usize, path: String },
+    // savefile 的平台不在 oslist 中
+    UnknownPlatform { index: usize, platform: String },
+    // 两个 savefile 匹配的文件可能重叠
+    OverlappingSavefiles { first: usize, second: usize },
+    // rootoverride 的原始 root 无法识别
+    UnknownOverrideRoot { index: usize, root: String },
+    // rootoverride 的 new_root 不是有效的 RootType
+    InvalidNewRoot { index: usize, root: String },
+    // rootoverride 的目标系统不在 oslist 中
+    UnknownOverrideOs { index: usize, os: String },
+    // 多个 rootoverride 覆盖同一个 root + os + oscompare（后者会被忽略，仅提示）
+    DuplicateOverrideTarget { first: usize, second: usize },
+}
+
+impl UfsIssueKind {
+    pub fn severity(&self) -> UfsIssueSeverity {
+        match self {
+            UfsIssueKind::UnknownRoot { .. }
+            | UfsIssueKind::EmptyPattern { .. }
+            | UfsIssueKind::AbsolutePath { .. }
+            | UfsIssueKind::ParentDirSegment { .. }
+            | UfsIssueKind::UnknownOverrideRoot { .. }
+            | UfsIssueKind::InvalidNewRoot { .. } => UfsIssueSeverity::Error,
+            UfsIssueKind::UnknownPlatform { .. }
+            | UfsIssueKind::OverlappingSavefiles { .. }
+            | UfsIssueKind::UnknownOverrideOs { .. }
+            | UfsIssueKind::DuplicateOverrideTarget { .. } => UfsIssueSeverity::Warning,
+        }
+    }
+
+    // 日志/错误信息使用的中文描述（界面展示走 i18n）
+    pub fn describe(&self) -> String {
+        match self {
+            UfsIssueKind::UnknownRoot { index, root } => {
+                format!("存档规则 #{} 的 root 无法识别: {}", index + 1, root)
+            }
+            UfsIssueKind::EmptyPattern { index } => {
+                format!("存档规则 #{} 的匹配模式为空", index + 1)
+            }
+            UfsIssueKind::AbsolutePath { index, path } => {
+                format!("存档规则 #{} 的路径不能是绝对路径: {}", index + 1, path)
+            }
+            UfsIssueKind::ParentDirSegment { index, path } => {
+                format!("存档规则 #{} 的路径不能包含 '..': {}", index + 1, path)
+            }
+            UfsIssueKind::UnknownPlatform { index, platform } => {
+                format!("存档规则 #{} 的平台未知: {}", index + 1, platform)
+            }
+            UfsIssueKind::OverlappingSavefiles { first, second } => {
+                format!("存档规则 #{} 与 #{} 匹配的文件重叠", first + 1, second + 1)
+            }
+            UfsIssueKind::UnknownOverrideRoot { index, root } => {
+                format!("根覆盖 #{} 的原始 root 无法识别: {}", index + 1, root)
+            }
+            UfsIssueKind::InvalidNewRoot { index, root } => {
+                format!("根覆盖 #{} 的新 root 无效: {}", index + 1, root)
+            }
+            UfsIssueKind::UnknownOverrideOs { index, os } => {
+                format!("根覆盖 #{} 的目标系统未知: {}", index + 1, os)
+            }
+            UfsIssueKind::DuplicateOverrideTarget { first, second } => {
+                format!(
+                    "根覆盖 #{} 与 #{} 覆盖了同一个 root、系统和比较方式",
+                    first + 1,
+                    second + 1
+                )
+            }
+        }
+    }
+}
+
+// 校验结果
+#[derive(Debug, Clone, Default)]
+pub struct UfsValidationReport {
+    pub issues: Vec<UfsIssueKind>,
+}
+
+impl UfsValidationReport {
+    pub fn error_count(&self) -> usize {
+        self.issues
+            .iter()
+            .filter(|i| i.severity() == UfsIssueSeverity::Error)
+            .count()
+    }
+
+    pub fn warning_count(&self) -> usize {
+        self.issues
+            .iter()
+            .filter(|i| i.severity() == UfsIssueSeverity::Warning)
+            .count()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.error_count() > 0
+    }
+
+    // 所有错误的中文描述，用于拼接错误信息
+    pub fn error_summary(&self) -> String {
+        self.issues
+            .iter()
+            .filter(|i| i.severity() == UfsIssueSeverity::Error)
+            .map(|i| i.describe())
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+// 校验完整的游戏 UFS 配置
+pub fn validate_game_config(config: &UfsGameConfig) -> UfsValidationReport {
+    let mut issues = Vec::new();
+
+    for (index, sf) in config.savefiles.iter().enumerate() {
+        if RootType::from_name(&sf.root).is_none() {
+            issues.push(UfsIssueKind::UnknownRoot {
+                index,
+                root: sf.root.clone(),
+            });
+        }
+        if sf.pattern.trim().is_empty() {
+            issues.push(UfsIssueKind::EmptyPattern { index });
+        }
+        if is_absolute_path(&sf.path) {
+            issues.push(UfsIssueKind::AbsolutePath {
+                index,
+                path: sf.path.clone(),
+            });
+        }
+        if has_parent_segment(&sf.path) {
+            issues.push(UfsIssueKind::ParentDirSegment {
+                index,
+                path: sf.path.clone(),
+            });
+        }
+        for platform in &sf.platforms {
+            if !is_known_platform(platform) {
+                issues.push(UfsIssueKind::UnknownPlatform {
+                    index,
+                    platform: platform.clone(),
+                });
+            }
+        }
+    }
+
+    for first in 0..config.savefiles.len() {
+        for second in first + 1..config.savefiles.len() {
+            if savefiles_overlap(&config.savefiles[first], &config.savefiles[second]) {
+                issues.push(UfsIssueKind::OverlappingSavefiles { first, second });
+            }
+        }
+    }
+
+    for (index, ro) in config.root_overrides.iter().enumerate() {
+        if RootType::from_name(&ro.original_root).is_none() {
+            issues.push(UfsIssueKind::UnknownOverrideRoot {
+                index,
+                root: ro.original_root.clone(),
+            });
+        }
+        if RootType::from_name(&ro.new_root).is_none() {
+            issues.push(UfsIssueKind::InvalidNewRoot {
+                index,
+                root: ro.new_root.clone(),
+            });
+        }
+        if !is_known_platform(&ro.os) || ro.os.eq_ignore_ascii_case("all") {
+            issues.push(UfsIssueKind::UnknownOverrideOs {
+                index,
+                os: ro.os.clone(),
+            });
+        }
+    }
+
+    for first in 0..config.root_overrides.len() {
+        for second in first + 1..config.root_overrides.len() {
+            let a = &config.root_overrides[first];
+            let b = &config.root_overrides[second];
+            // "=" 与 "!=" 作用于不同系统，是合法的一对
+            if same_root(&a.original_root, &b.original_root)
+                && a.os.eq_ignore_ascii_case(&b.os)
+                && oscompare_of(a) == oscompare_of(b)
+            {
+                issues.push(UfsIssueKind::DuplicateOverrideTarget { first, second });
+            }
+        }
+    }
+
+    UfsValidationReport { issues }
+}
+
+// 比较符为空时按 Steam 默认的 "=" 处理
+fn oscompare_of(entry: &RootOverrideEntry) -> &str {
+    match entry.oscompare.trim() {
+        "" => "=",
+        op => op,
+    }
+}
+
+fn is_known_platform(platform: &str) -> bool {
+    KNOWN_PLATFORMS
+        .iter()
+        .any(|p| p.eq_ignore_ascii_case(platform.trim()))
+}
+
+// 绝对路径：以 / 或 \ 开头，或带盘符（如 C:）
+fn is_absolute_path(path: &str) -> bool {
+    let path = path.trim();
+    if path.starts_with('/') || path.starts_with('\\') {
+        return true;
+    }
+    let bytes = path.as_bytes();
+    bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+fn has_parent_segment(path: &str) -> bool {
+    path.split(['/', '\\']).any(|seg| seg.trim() == "..")
+}
+
+// root 名称比较（别名统一到 RootType，无法识别时按名称比较）
+fn same_root(a: &str, b: &str) -> bool {
+    match (RootType::from_name(a), RootType::from_name(b)) {
+        (Some(ra), Some(rb)) => ra == rb,
+        _ => a.eq_ignore_ascii_case(b),
+    }
+}
+
+// 统一路径格式：小写、正斜杠、去掉首尾斜杠
+fn normalize_path(path: &str) -> String {
+    path.trim()
+        .replace('\\', "/")
+        .trim_matches('/')
+        .to_lowercase()
+}
+
+// 平台列表为空或包含 all 视为全平台
+fn platforms_intersect(a: &[String], b: &[String]) -> bool {
+    let is_all = |p: &[String]| p.is_empty() || p.iter().any(|s| s.eq_ignore_ascii_case("all"));
+    if is_all(a) || is_all(b) {
+        return true;
+    }
+    a.iter()
+        .any(|x| b.iter().any(|y| x.eq_ignore_ascii_case(y)))
+}
+
+// 判断两个 savefile 是否可能匹配到同一批文件
+fn savefiles_overlap(a: &SaveFileEntry, b: &SaveFileEntry) -> bool {
+    if !same_root(&a.root, &b.root) || !platforms_intersect(&a.platforms, &b.platforms) {
+        return false;
+    }
+
+    let pa = normalize_path(&a.path);
+    let pb = normalize_path(&b.path);
+    let patterns_overlap = |outer: &SaveFileEntry, inner: &SaveFileEntry| {
+        outer.pattern == "*" || outer.pattern.eq_ignore_ascii_case(&inner.pattern)
+    };
+
+    if pa == pb {
+        return patterns_overlap(a, b) || patterns_overlap(b, a);
+    }
+
+    // 一个路径是另一个的子目录，且外层递归匹配
+    let is_child =
+        |parent: &str, child: &str| parent.is_empty() || child.starts_with(&format!("{}/", parent));
+    (a.recursive && is_child(&pa, &pb) && patterns_overlap(a, b))
+        || (b.recursive && is_child(&pb, &pa) && patterns_overlap(b, a))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn savefile(root: &str, path: &str, pattern: &str) -> SaveFileEntry {
+        SaveFileEntry {
+            root: root.to_string(),
+            path: path.to_string(),
+            pattern: pattern.to_string(),
+            platforms: vec!["all".to_string()],
+            recursive: true,
+            extra: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn reports_savefile_errors() {
+        let config = UfsGameConfig {
+            app_id: 1,
+            savefiles: vec![
+                savefile("NotARoot", "saves", "*.sav"),
+                savefile("WinAppDataLocal", "/abs/saves", ""),
+                savefile("MacHome", "saves/../other", "*"),
+            ],
+            root_overrides: Vec::new(),
+            ..Default::default()
+        };
+
+        let report = validate_game_config(&config);
+        assert!(report.has_errors());
+        assert!(report.issues.contains(&UfsIssueKind::UnknownRoot {
+            index: 0,
+            root: "NotARoot".to_string()
+        }));
+        assert!(
+            report
+                .issues
+                .contains(&UfsIssueKind::EmptyPattern { index: 1 })
+        );
+        assert!(report.issues.contains(&UfsIssueKind::AbsolutePath {
+            index: 1,
+            path: "/abs/saves".to_string()
+        }));
+        assert!(report.issues.contains(&UfsIssueKind::ParentDirSegment {
+            index: 2,
+            path: "saves/../other".to_string()
+        }));
+    }
+
+    #[test]
+    fn reports_overlaps_and_duplicate_overrides() {
+        let mut mac_only = savefile("WinMyDocuments", "Game/Saves", "*.sav");
+        mac_only.platforms = vec!["MacOS".to_string(), "beos".to_string()];
+        let override_entry = RootOverrideEntry {
+            original_root: "WinMyDocuments".to_string(),
+            os: "macos".to_string(),
+            new_root: "MacDocuments".to_string(),
+            ..Default::default()
+        };
+
+        let config = UfsGameConfig {
+            app_id: 1,
+            savefiles: vec![savefile("WinMyDocuments", "Game", "*"), mac_only],
+            root_overrides: vec![
+                override_entry.clone(),
+                RootOverrideEntry {
+                    new_root: "Bogus".to_string(),
+                    ..override_entry
+                },
+            ],
+            ..Default::default()
+        };
+
+        let report = validate_game_config(&config);
+        assert!(report.issues.contains(&UfsIssueKind::OverlappingSavefiles {
+            first: 0,
+            second: 1
+        }));
+        assert!(report.issues.contains(&UfsIssueKind::UnknownPlatform {
+            index: 1,
+            platform: "beos".to_string()
+        }));
+        assert!(
+            report
+                .issues
+                .contains(&UfsIssueKind::DuplicateOverrideTarget {
+                    first: 0,
+                    second: 1
+                })
+        );
+        assert!(report.issues.contains(&UfsIssueKind::InvalidNewRoot {
+            index: 1,
+            root: "Bogus".to_string()
+        }));
+    }
+
+    #[test]
+    fn override_pair_with_opposite_oscompare_is_not_duplicate() {
+        let windows = RootOverrideEntry {
+            original_root: "WinAppDataLocal".to_string(),
+            os: "windows".to_string(),
+            new_root: "WinAppDataLocalLow".to_string(),
+            ..Default::default()
+        };
+        let others = RootOverrideEntry {
+            oscompare: "!=".to_string(),
+            new_root: "LinuxXdgDataHome".to_string(),
+            ..windows.clone()
+        };
+        let mut config = UfsGameConfig {
+            app_id: 1,
+            root_overrides: vec![windows.clone(), others],
+            ..Default::default()
+        };
+        assert!(validate_game_config(&config).issues.is_empty());
+
+        // 比较符相同才算重复，且只是警告，不阻止注入
+        config.root_overrides[1].oscompare = String::new();
+        let report = validate_game_config(&config);
+        assert_eq!(
+            report.issues,
+            vec![UfsIssueKind::DuplicateOverrideTarget {
+                first: 0,
+                second: 1
+            }]
+        );
+        assert!(!report.has_errors());
+        assert_eq!(report.warning_count(), 1);
+    }
+
+    #[test]
+    fn valid_config_has_no_issues() {
+        let config = UfsGameConfig {
+            app_id: 1,
+            savefiles: vec![
+                savefile("App Install Directory", "saves", "*.sav"),
+                savefile("WinAppDataLocal", "Game/Saves", "*.dat"),
+            ],
+            root_overrides: vec![RootOverrideEntry {
+                original_root: "WinAppDataLocal".to_string(),
+                os: "linux".to_string(),
+                new_root: "LinuxXdgDataHome".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert!(validate_game_config(&config).issues.is_empty());
+    }
+}
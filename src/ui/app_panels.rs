@@ -28,6 +28,7 @@ pub enum StatusPanelAction {
     ToggleCloudEnabled,
     ShowAppInfo(u32),
     ShowSymlinkManager(u32),
+    ShowGameProfile(u32),
 }
 
 // 状态面板的状态数据
@@ -69,6 +70,7 @@ pub enum BottomPanelEvent {
     CompareFiles,
     ShowAppInfo(u32),
     ShowSymlinkManager(u32),
+    ShowGameProfile(u32),
 }
 
 // 顶部面板渲染
@@ -291,6 +293,9 @@ pub fn render_bottom_panel(
         StatusPanelAction::ShowSymlinkManager(id) if event == BottomPanelEvent::None => {
             event = BottomPanelEvent::ShowSymlinkManager(id);
         }
+        StatusPanelAction::ShowGameProfile(id) if event == BottomPanelEvent::None => {
+            event = BottomPanelEvent::ShowGameProfile(id);
+        }
         _ => {}
     }
 
@@ -447,7 +452,7 @@ fn draw_complete_status_panel(
         draw_quota_info(ui, total, available, i18n);
     }
 
-    // 显示 appinfo.vdf 按钮 和 软链接管理按钮
+    // 显示 appinfo.vdf 按钮、软链接管理按钮和游戏配置档按钮
     if state.is_connected && state.app_id > 0 {
         ui.horizontal(|ui| {
             if ui.button(i18n.show_appinfo_vdf()).clicked() {
@@ -456,6 +461,12 @@ fn draw_complete_status_panel(
             if ui.button(i18n.symlink_title()).clicked() {
                 action = StatusPanelAction::ShowSymlinkManager(state.app_id);
             }
+            if ui
+                .button(format!("{} {}", icons::SLIDERS, i18n.game_profile_button()))
+                .clicked()
+            {
+                action = StatusPanelAction::ShowGameProfile(state.app_id);
+            }
         });
     }
 
@@ -1,11 +1,16 @@
+use crate::appinfo_inspector::{self, AppInfoDump, KvValue};
 use crate::config::{
-    PathTransform, RootOverrideEntry, SaveFileEntry, UfsGameConfig, get_ufs_game_config,
+    PathTransform, RootOverrideEntry, SaveFileEntry, UfsGameConfig, UfsSettings,
+    get_ufs_game_config,
 };
 use crate::i18n::I18n;
 use crate::icons;
 use crate::path_resolver::get_current_platform;
+use crate::ufs_bundle::ImportConflictPolicy;
 use crate::ufs_text;
+use crate::ufs_validator::{self, UfsIssueKind, UfsIssueSeverity, UfsValidationReport};
 use crate::vdf_parser::UfsConfig;
+use std::path::PathBuf;
 
 // Steamworks 后台支持的所有 Root 类型
 // 参考: https://partner.steamgames.com/doc/features/cloud
@@ -62,6 +67,10 @@ pub struct AppInfoDialog {
     // 表格编辑状态
     pub editing_savefiles: Vec<SaveFileEntry>,
     pub editing_overrides: Vec<RootOverrideEntry>,
+    // 顶层设置（quota / maxnumfiles 等）及未识别的键
+    pub editing_settings: UfsSettings,
+    // appinfo 中现有的顶层设置，用于判断编辑器删除了哪些键
+    pub original_settings: UfsSettings,
     pub edit_mode: EditMode,
 
     // 可编辑的 UFS 原始文本（与表格双向同步）
@@ -81,22 +90,45 @@ pub struct AppInfoDialog {
     pub inject_status_is_success: bool,
     pub game_config: Option<UfsGameConfig>,
     pub active_tab: usize,
+    // 导入分享包时的冲突处理方式
+    pub import_policy: ImportConflictPolicy,
+
+    // 完整 AppInfo 检查器（None 表示尚未加载）
+    pub full_info: Option<Result<AppInfoDump, String>>,
+    pub full_info_query: String,
+    pub full_info_as_json: bool,
+    // 过滤后的输出缓存，(query, as_json) 变化时重新生成
+    full_info_output: String,
+    full_info_output_key: Option<(String, bool)>,
 }
 
 impl AppInfoDialog {
     pub fn new(app_id: u32, config: UfsConfig) -> Self {
         let game_config = get_ufs_game_config(app_id);
+        let (original_settings, _, _) = ufs_text::parse_ufs_text(&config.raw_text);
 
-        // 初始化编辑表格
-        let (editing_savefiles, editing_overrides) = if let Some(ref gc) = game_config {
-            (gc.savefiles.clone(), gc.root_overrides.clone())
-        } else {
-            (Vec::new(), Vec::new())
-        };
+        // 初始化编辑表格；没有保存顶层设置时（包括旧版本保存的配置）沿用 appinfo 中的值
+        let (editing_settings, editing_savefiles, editing_overrides) =
+            if let Some(ref gc) = game_config {
+                (
+                    if gc.settings.is_empty() {
+                        original_settings.clone()
+                    } else {
+                        gc.settings.clone()
+                    },
+                    gc.savefiles.clone(),
+                    gc.root_overrides.clone(),
+                )
+            } else {
+                (original_settings.clone(), Vec::new(), Vec::new())
+            };
 
         // 从编辑数据生成初始 raw_text
-        let editing_raw_text =
-            ufs_text::entries_to_ufs_text(&editing_savefiles, &editing_overrides);
+        let editing_raw_text = ufs_text::entries_to_ufs_text(
+            &editing_settings,
+            &editing_savefiles,
+            &editing_overrides,
+        );
 
         // 根据当前平台选择默认 Root
         let default_root = match get_current_platform() {
@@ -111,6 +143,8 @@ impl AppInfoDialog {
             config,
             editing_savefiles,
             editing_overrides,
+            editing_settings,
+            original_settings,
             edit_mode: EditMode::None,
             editing_raw_text,
             temp_savefile: SaveFileEntry {
@@ -119,13 +153,16 @@ impl AppInfoDialog {
                 pattern: "*".to_string(),
                 platforms: vec!["all".to_string()],
                 recursive: true,
+                extra: Vec::new(),
             },
             temp_override: RootOverrideEntry {
                 original_root: "WinAppDataLocal".to_string(),
                 os: "macos".to_string(),
                 new_root: "MacAppSupport".to_string(),
+                oscompare: "=".to_string(),
                 add_path: String::new(),
                 path_transforms: Vec::new(),
+                extra: Vec::new(),
             },
             temp_use_path_transform: false,
             temp_path_transform_find: String::new(),
@@ -133,28 +170,66 @@ impl AppInfoDialog {
             inject_status_is_success: false,
             game_config,
             active_tab: 0,
+            import_policy: ImportConflictPolicy::default(),
+            full_info: None,
+            full_info_query: String::new(),
+            full_info_as_json: false,
+            full_info_output: String::new(),
+            full_info_output_key: None,
         }
     }
 
+    // 设置完整 AppInfo 加载结果，并使输出缓存失效
+    pub fn set_full_info(&mut self, info: Option<Result<AppInfoDump, String>>) {
+        self.full_info = info;
+        self.full_info_output_key = None;
+    }
+
+    // 按当前搜索词和输出格式生成检查器输出
+    fn update_full_info_output(&mut self) {
+        let key = (self.full_info_query.clone(), self.full_info_as_json);
+        if self.full_info_output_key.as_ref() == Some(&key) {
+            return;
+        }
+        if let Some(Ok(info)) = &self.full_info {
+            let nodes = appinfo_inspector::filter_nodes(&info.nodes, &self.full_info_query);
+            self.full_info_output = if nodes.is_empty() {
+                String::new()
+            } else if self.full_info_as_json {
+                serde_json::to_string_pretty(&KvValue::Section(nodes)).unwrap_or_default()
+            } else {
+                appinfo_inspector::nodes_to_text(&nodes)
+            };
+        }
+        self.full_info_output_key = Some(key);
+    }
+
     // 刷新已保存的配置
     pub fn refresh_saved_configs(&mut self) {
         self.game_config = get_ufs_game_config(self.app_id);
         if let Some(ref gc) = self.game_config {
             self.editing_savefiles = gc.savefiles.clone();
             self.editing_overrides = gc.root_overrides.clone();
+            if !gc.settings.is_empty() {
+                self.editing_settings = gc.settings.clone();
+            }
         }
         self.sync_raw_text_from_entries();
     }
 
     // 表格 → raw_text 同步
     pub fn sync_raw_text_from_entries(&mut self) {
-        self.editing_raw_text =
-            ufs_text::entries_to_ufs_text(&self.editing_savefiles, &self.editing_overrides);
+        self.editing_raw_text = ufs_text::entries_to_ufs_text(
+            &self.editing_settings,
+            &self.editing_savefiles,
+            &self.editing_overrides,
+        );
     }
 
     // raw_text → 表格同步
     pub fn sync_entries_from_raw_text(&mut self) {
-        let (savefiles, overrides) = ufs_text::parse_ufs_text(&self.editing_raw_text);
+        let (settings, savefiles, overrides) = ufs_text::parse_ufs_text(&self.editing_raw_text);
+        self.editing_settings = settings;
         self.editing_savefiles = savefiles;
         self.editing_overrides = overrides;
     }
@@ -165,6 +240,10 @@ impl AppInfoDialog {
             app_id: self.app_id,
             savefiles: self.editing_savefiles.clone(),
             root_overrides: self.editing_overrides.clone(),
+            settings: self
+                .editing_settings
+                .clone()
+                .with_removed_from(&self.original_settings),
         }
     }
 
@@ -181,11 +260,14 @@ impl AppInfoDialog {
 pub enum AppInfoDialogAction {
     None,
     Close,
-    InjectFullConfig, // 注入完整配置到 VDF
-    SaveGameConfig,   // 保存配置到文件
-    ClearGameConfig,  // 清空所有自定义配置
+    InjectFullConfig,                           // 注入完整配置到 VDF
+    SaveGameConfig,                             // 保存配置到文件
+    ClearGameConfig,                            // 清空所有自定义配置
+    ExportConfigs { path: PathBuf, all: bool }, // 导出分享包
+    ImportConfigs(PathBuf),                     // 导入分享包
     RestartSteam,
     RefreshConfig,
+    LoadFullAppInfo, // 读取完整 AppInfo 树
 }
 
 // 绘制 AppInfo 对话框
@@ -210,6 +292,7 @@ pub fn draw_appinfo_dialog(
             ui.horizontal(|ui| {
                 ui.selectable_value(&mut dialog.active_tab, 0, i18n.appinfo_tab_local_ufs());
                 ui.selectable_value(&mut dialog.active_tab, 1, i18n.appinfo_tab_custom_config());
+                ui.selectable_value(&mut dialog.active_tab, 2, i18n.appinfo_tab_full_tree());
             });
 
             ui.separator();
@@ -471,8 +554,10 @@ pub fn draw_appinfo_dialog(
                                     original_root: "WinAppDataLocal".to_string(),
                                     os: "macos".to_string(),
                                     new_root: "MacAppSupport".to_string(),
+                                    oscompare: "=".to_string(),
                                     add_path: String::new(),
                                     path_transforms: Vec::new(),
+                                    extra: Vec::new(),
                                 };
                                 dialog.temp_use_path_transform = false;
                             }
@@ -772,6 +857,11 @@ pub fn draw_appinfo_dialog(
                         }
                     });
 
+                    // 配置校验结果
+                    let report = ufs_validator::validate_game_config(&dialog.build_game_config());
+                    ui.separator();
+                    draw_validation_report(ui, &report, i18n);
+
                     // 保存和注入按钮
                     ui.separator();
                     ui.horizontal(|ui| {
@@ -785,7 +875,13 @@ pub fn draw_appinfo_dialog(
                             action = AppInfoDialogAction::SaveGameConfig;
                         }
                         if ui
-                            .add_enabled(has_changes, egui::Button::new(i18n.ufs_inject_to_vdf()))
+                            .add_enabled(
+                                has_changes && !report.has_errors(),
+                                egui::Button::new(i18n.ufs_inject_to_vdf()),
+                            )
+                            .on_disabled_hover_text(
+                                i18n.ufs_validation_blocked(report.error_count()),
+                            )
                             .clicked()
                         {
                             action = AppInfoDialogAction::InjectFullConfig;
@@ -810,11 +906,74 @@ pub fn draw_appinfo_dialog(
                             // 清空本地编辑状态
                             dialog.editing_savefiles.clear();
                             dialog.editing_overrides.clear();
+                            // 设置恢复为 appinfo 原有值，避免把原有键标记为删除
+                            dialog.editing_settings = dialog.original_settings.clone();
                             dialog.sync_raw_text_from_entries();
                             action = AppInfoDialogAction::ClearGameConfig;
                         }
                     });
 
+                    // 分享包导入/导出
+                    ui.horizontal(|ui| {
+                        let has_entries = !dialog.editing_savefiles.is_empty()
+                            || !dialog.editing_overrides.is_empty();
+
+                        if ui
+                            .add_enabled(
+                                has_entries,
+                                egui::Button::new(format!(
+                                    "{} {}",
+                                    icons::EXPORT,
+                                    i18n.ufs_export_config()
+                                )),
+                            )
+                            .on_hover_text(i18n.ufs_export_config_tooltip())
+                            .clicked()
+                            && let Some(path) =
+                                pick_bundle_save_path(&format!("ufs_{}.json", dialog.app_id))
+                        {
+                            action = AppInfoDialogAction::ExportConfigs { path, all: false };
+                        }
+
+                        if ui
+                            .button(format!(
+                                "{} {}",
+                                icons::EXPORT,
+                                i18n.ufs_export_all_configs()
+                            ))
+                            .on_hover_text(i18n.ufs_export_all_configs_tooltip())
+                            .clicked()
+                            && let Some(path) = pick_bundle_save_path("ufs_configs.json")
+                        {
+                            action = AppInfoDialogAction::ExportConfigs { path, all: true };
+                        }
+
+                        ui.separator();
+
+                        ui.label(i18n.ufs_import_policy_label());
+                        egui::ComboBox::from_id_salt("ufs_import_policy")
+                            .selected_text(import_policy_label(dialog.import_policy, i18n))
+                            .show_ui(ui, |ui| {
+                                for policy in ImportConflictPolicy::all() {
+                                    ui.selectable_value(
+                                        &mut dialog.import_policy,
+                                        *policy,
+                                        import_policy_label(*policy, i18n),
+                                    );
+                                }
+                            });
+
+                        if ui
+                            .button(format!("{} {}", icons::IMPORT, i18n.ufs_import_config()))
+                            .clicked()
+                            && let Some(path) = rfd::FileDialog::new()
+                                .add_filter("UFS Bundle", &["json", "vdf"])
+                                .pick_file()
+                        {
+                            action = AppInfoDialogAction::ImportConfigs(path);
+                        }
+                    });
+
                     ui.separator();
 
                     // 操作提示区域
@@ -855,6 +1014,10 @@ pub fn draw_appinfo_dialog(
                             .small(),
                     );
                 }
+                2 => {
+                    // Tab 2: 完整 AppInfo（只读）
+                    draw_full_info_tab(ui, dialog, i18n, &mut action);
+                }
                 _ => {}
             }
         });
@@ -865,3 +1028,158 @@ pub fn draw_appinfo_dialog(
 
     action
 }
+
+// 选择分享包保存路径
+fn pick_bundle_save_path(default_name: &str) -> Option<PathBuf> {
+    rfd::FileDialog::new()
+        .add_filter("JSON", &["json"])
+        .add_filter("VDF", &["vdf"])
+        .set_file_name(default_name)
+        .save_file()
+}
+
+fn import_policy_label(policy: ImportConflictPolicy, i18n: &I18n) -> &'static str {
+    match policy {
+        ImportConflictPolicy::Skip => i18n.ufs_import_policy_skip(),
+        ImportConflictPolicy::Overwrite => i18n.ufs_import_policy_overwrite(),
+        ImportConflictPolicy::Merge => i18n.ufs_import_policy_merge(),
+    }
+}
+
+// 绘制配置校验结果
+fn draw_validation_report(ui: &mut egui::Ui, report: &UfsValidationReport, i18n: &I18n) {
+    if report.issues.is_empty() {
+        ui.label(
+            egui::RichText::new(format!("{} {}", icons::CHECK, i18n.ufs_validation_ok()))
+                .color(egui::Color32::GREEN),
+        );
+        return;
+    }
+
+    egui::CollapsingHeader::new(format!(
+        "{} {}: {}",
+        icons::WARNING,
+        i18n.ufs_validation_title(),
+        i18n.ufs_validation_summary(report.error_count(), report.warning_count())
+    ))
+    .id_salt("ufs_validation_report")
+    .default_open(report.has_errors())
+    .show(ui, |ui| {
+        for issue in &report.issues {
+            let (icon, color) = match issue.severity() {
+                UfsIssueSeverity::Error => (icons::ERROR, egui::Color32::RED),
+                UfsIssueSeverity::Warning => (icons::WARNING, egui::Color32::YELLOW),
+            };
+            ui.label(
+                egui::RichText::new(format!("{} {}", icon, issue_message(issue, i18n)))
+                    .color(color),
+            );
+        }
+    });
+}
+
+// 校验问题的本地化描述（序号从 1 开始显示）
+fn issue_message(issue: &UfsIssueKind, i18n: &I18n) -> String {
+    match issue {
+        UfsIssueKind::UnknownRoot { index, root } => i18n.ufs_issue_unknown_root(index + 1, root),
+        UfsIssueKind::EmptyPattern { index } => i18n.ufs_issue_empty_pattern(index + 1),
+        UfsIssueKind::AbsolutePath { index, path } => i18n.ufs_issue_absolute_path(index + 1, path),
+        UfsIssueKind::ParentDirSegment { index, path } => {
+            i18n.ufs_issue_parent_segment(index + 1, path)
+        }
+        UfsIssueKind::UnknownPlatform { index, platform } => {
+            i18n.ufs_issue_unknown_platform(index + 1, platform)
+        }
+        UfsIssueKind::OverlappingSavefiles { first, second } => {
+            i18n.ufs_issue_overlapping_savefiles(first + 1, second + 1)
+        }
+        UfsIssueKind::UnknownOverrideRoot { index, root } => {
+            i18n.ufs_issue_unknown_override_root(index + 1, root)
+        }
+        UfsIssueKind::InvalidNewRoot { index, root } => {
+            i18n.ufs_issue_invalid_new_root(index + 1, root)
+        }
+        UfsIssueKind::UnknownOverrideOs { index, os } => {
+            i18n.ufs_issue_unknown_override_os(index + 1, os)
+        }
+        UfsIssueKind::DuplicateOverrideTarget { first, second } => {
+            i18n.ufs_issue_duplicate_override(first + 1, second + 1)
+        }
+    }
+}
+
+// 绘制完整 AppInfo 检查器
+fn draw_full_info_tab(
+    ui: &mut egui::Ui,
+    dialog: &mut AppInfoDialog,
+    i18n: &I18n,
+    action: &mut AppInfoDialogAction,
+) {
+    let summary = match &dialog.full_info {
+        None => {
+            ui.label(i18n.appinfo_full_loading());
+            *action = AppInfoDialogAction::LoadFullAppInfo;
+            return;
+        }
+        Some(Err(e)) => {
+            ui.colored_label(egui::Color32::RED, i18n.appinfo_full_load_error(e));
+            if ui
+                .button(format!("{} {}", icons::REFRESH, i18n.refresh()))
+                .clicked()
+            {
+                *action = AppInfoDialogAction::RefreshConfig;
+            }
+            return;
+        }
+        Some(Ok(info)) => {
+            i18n.appinfo_full_summary(info.version, info.change_number, info.info_state)
+        }
+    };
+
+    ui.horizontal(|ui| {
+        ui.label(summary);
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            if ui
+                .button(format!("{} {}", icons::REFRESH, i18n.refresh()))
+                .clicked()
+            {
+                *action = AppInfoDialogAction::RefreshConfig;
+            }
+            if ui
+                .button(format!("{} {}", icons::COPY, i18n.appinfo_copy_output()))
+                .clicked()
+            {
+                ui.ctx().copy_text(dialog.full_info_output.clone());
+            }
+        });
+    });
+
+    ui.horizontal(|ui| {
+        ui.label(icons::MAGNIFYING_GLASS);
+        ui.add(
+            egui::TextEdit::singleline(&mut dialog.full_info_query)
+                .hint_text(i18n.appinfo_search_placeholder())
+                .desired_width(300.0),
+        );
+        ui.checkbox(&mut dialog.full_info_as_json, i18n.appinfo_show_as_json());
+    });
+
+    dialog.update_full_info_output();
+
+    ui.separator();
+
+    if dialog.full_info_output.is_empty() {
+        ui.label(i18n.appinfo_full_no_match());
+        return;
+    }
+
+    egui::ScrollArea::both()
+        .id_salt("full_appinfo_scroll")
+        .show(ui, |ui| {
+            ui.add(
+                egui::TextEdit::multiline(&mut dialog.full_info_output.as_str())
+                    .font(egui::TextStyle::Monospace)
+                    .desired_width(f32::INFINITY),
+            );
+        });
+}
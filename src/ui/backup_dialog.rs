@@ -378,6 +378,37 @@ impl DownloadProgressDialog {
 
                     ui.label(i18n.download_result_stats(result.success_count, result.total_files));
 
+                    if !result.sources.is_empty() {
+                        use crate::config::DownloadSource;
+                        ui.label(
+                            RichText::new(i18n.download_source_stats(
+                                result.source_count(DownloadSource::Cdp),
+                                result.source_count(DownloadSource::SteamApi),
+                                result.source_count(DownloadSource::Local),
+                            ))
+                            .size(11.0)
+                            .color(crate::ui::theme::muted_color(ui.ctx())),
+                        );
+
+                        // 本地副本可能比云端旧，单独提示
+                        let local_count = result.source_count(DownloadSource::Local);
+                        if local_count > 0 {
+                            ui.label(
+                                RichText::new(i18n.download_local_copy_warning(local_count))
+                                    .color(crate::ui::theme::warning_color(ui.ctx())),
+                            )
+                            .on_hover_text(
+                                result
+                                    .sources
+                                    .iter()
+                                    .filter(|(_, s)| *s == DownloadSource::Local)
+                                    .map(|(name, _)| name.as_str())
+                                    .collect::<Vec<_>>()
+                                    .join("\n"),
+                            );
+                        }
+                    }
+
                     if !result.failed_files.is_empty() {
                         ui.add_space(8.0);
                         ui.label(
@@ -435,12 +466,40 @@ impl DownloadProgressDialog {
                         self.progress.completed_files, self.progress.total_files
                     ));
 
-                    if !self.progress.current_file.is_empty() {
-                        ui.label(
-                            RichText::new(&self.progress.current_file)
-                                .size(11.0)
-                                .color(crate::ui::theme::muted_color(ui.ctx())),
-                        );
+                    if self.progress.bytes_total > 0 {
+                        ui.label(i18n.download_bytes_progress(
+                            &crate::file_manager::format_size(self.progress.bytes_downloaded),
+                            &crate::file_manager::format_size(self.progress.bytes_total),
+                        ));
+                    }
+
+                    if self.progress.active_files.is_empty() {
+                        if !self.progress.current_file.is_empty() {
+                            ui.label(
+                                RichText::new(&self.progress.current_file)
+                                    .size(11.0)
+                                    .color(crate::ui::theme::muted_color(ui.ctx())),
+                            );
+                        }
+                    } else {
+                        // 并发下载时逐个显示正在下载的文件
+                        for file in &self.progress.active_files {
+                            let text = if file.total > 0 {
+                                format!(
+                                    "{} ({} / {})",
+                                    file.name,
+                                    crate::file_manager::format_size(file.downloaded),
+                                    crate::file_manager::format_size(file.total)
+                                )
+                            } else {
+                                file.name.clone()
+                            };
+                            ui.label(
+                                RichText::new(text)
+                                    .size(11.0)
+                                    .color(crate::ui::theme::muted_color(ui.ctx())),
+                            );
+                        }
                     }
 
                     ui.add_space(12.0);
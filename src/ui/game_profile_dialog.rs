@@ -0,0 +1,289 @@
+use crate::config::{GameProfile, UploadConfig};
+use crate::i18n::I18n;
+use crate::icons;
+use crate::symlink_manager::LinkDirection;
+use egui::RichText;
+use std::path::PathBuf;
+
+// 游戏配置档对话框的操作结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameProfileAction {
+    None,
+    Save(GameProfile),
+    Cancel,
+}
+
+// 单个游戏的配置档编辑
+pub struct GameProfileDialog {
+    pub app_id: u32,
+    game_name: String,
+    last_backup_at: Option<i64>,
+    download_dir: String,
+    upload_virtual_root: String,
+    // 勾选后使用独立的上传过滤规则
+    custom_filter: bool,
+    filter: UploadConfig,
+    include_text: String,
+    exclude_text: String,
+    backup_interval_hours: u32,
+    custom_symlink: bool,
+    symlink_direction: LinkDirection,
+    symlink_remote_subfolder: String,
+}
+
+impl GameProfileDialog {
+    pub fn new(app_id: u32, game_name: String, profile: Option<GameProfile>) -> Self {
+        let profile = profile.unwrap_or_else(|| GameProfile::new(app_id));
+        let filter = profile.upload_config();
+        Self {
+            app_id,
+            game_name,
+            last_backup_at: profile.last_backup_at,
+            download_dir: profile
+                .download_dir
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            upload_virtual_root: profile.upload_virtual_root.unwrap_or_default(),
+            custom_filter: profile.upload_filter.is_some(),
+            include_text: filter.include.join("\n"),
+            exclude_text: filter.exclude.join("\n"),
+            filter,
+            backup_interval_hours: profile.backup_interval_hours,
+            custom_symlink: profile.symlink_direction.is_some()
+                || profile.symlink_remote_subfolder.is_some(),
+            symlink_direction: profile
+                .symlink_direction
+                .as_deref()
+                .map(LinkDirection::from_config_str)
+                .unwrap_or(LinkDirection::RemoteToLocal),
+            symlink_remote_subfolder: profile.symlink_remote_subfolder.unwrap_or_default(),
+        }
+    }
+
+    // 根据编辑状态生成配置档，留空的项不覆盖全局设置
+    fn build_profile(&self) -> GameProfile {
+        let text = |s: &str| Some(s.trim().to_string()).filter(|s| !s.is_empty());
+        let lines = |s: &str| -> Vec<String> {
+            s.lines()
+                .map(|l| l.trim().to_string())
+                .filter(|l| !l.is_empty())
+                .collect()
+        };
+
+        GameProfile {
+            app_id: self.app_id,
+            download_dir: text(&self.download_dir).map(PathBuf::from),
+            upload_virtual_root: text(&self.upload_virtual_root),
+            upload_filter: self.custom_filter.then(|| UploadConfig {
+                include: lines(&self.include_text),
+                exclude: lines(&self.exclude_text),
+                ..self.filter.clone()
+            }),
+            backup_interval_hours: self.backup_interval_hours,
+            last_backup_at: self.last_backup_at,
+            symlink_direction: self
+                .custom_symlink
+                .then(|| self.symlink_direction.as_config_str().to_string()),
+            symlink_remote_subfolder: if self.custom_symlink {
+                text(&self.symlink_remote_subfolder)
+            } else {
+                None
+            },
+        }
+    }
+
+    pub fn draw(&mut self, ctx: &egui::Context, i18n: &I18n) -> GameProfileAction {
+        let mut action = GameProfileAction::None;
+        let mut open = true;
+
+        egui::Window::new(format!(
+            "{} {}",
+            icons::SLIDERS,
+            i18n.game_profile_title(&self.game_name)
+        ))
+        .open(&mut open)
+        .resizable(true)
+        .collapsible(false)
+        .default_width(560.0)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            ui.label(
+                RichText::new(i18n.game_profile_hint())
+                    .size(12.0)
+                    .color(crate::ui::theme::muted_color(ctx)),
+            );
+            ui.add_space(8.0);
+
+            egui::ScrollArea::vertical()
+                .max_height(420.0)
+                .show(ui, |ui| {
+                    self.draw_transfer(ui, i18n);
+                    ui.separator();
+                    self.draw_filter(ui, i18n);
+                    ui.separator();
+                    self.draw_backup(ui, i18n);
+                    ui.separator();
+                    self.draw_symlink(ui, i18n);
+                });
+
+            ui.add_space(8.0);
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui
+                    .button(format!("{} {}", icons::SAVE, i18n.game_profile_save()))
+                    .clicked()
+                {
+                    action = GameProfileAction::Save(self.build_profile());
+                }
+                if ui
+                    .button(i18n.game_profile_reset())
+                    .on_hover_text(i18n.game_profile_reset_hint())
+                    .clicked()
+                {
+                    *self = Self::new(self.app_id, std::mem::take(&mut self.game_name), None);
+                }
+                if ui.button(i18n.cancel()).clicked() {
+                    action = GameProfileAction::Cancel;
+                }
+            });
+        });
+
+        if !open {
+            action = GameProfileAction::Cancel;
+        }
+        action
+    }
+
+    // 下载目录与上传根目录
+    fn draw_transfer(&mut self, ui: &mut egui::Ui, i18n: &I18n) {
+        ui.horizontal(|ui| {
+            ui.label(i18n.game_profile_download_dir());
+            ui.add(
+                egui::TextEdit::singleline(&mut self.download_dir)
+                    .desired_width(300.0)
+                    .hint_text(i18n.game_profile_ask_each_time()),
+            );
+            if ui
+                .button(icons::FOLDER_OPEN)
+                .on_hover_text(i18n.symlink_browse())
+                .clicked()
+                && let Some(path) = rfd::FileDialog::new().pick_folder()
+            {
+                self.download_dir = path.to_string_lossy().to_string();
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label(i18n.game_profile_upload_root());
+            ui.add(
+                egui::TextEdit::singleline(&mut self.upload_virtual_root)
+                    .desired_width(200.0)
+                    .hint_text("saves"),
+            )
+            .on_hover_text(i18n.game_profile_upload_root_hint());
+        });
+    }
+
+    // 上传过滤规则
+    fn draw_filter(&mut self, ui: &mut egui::Ui, i18n: &I18n) {
+        ui.checkbox(&mut self.custom_filter, i18n.game_profile_custom_filter())
+            .on_hover_text(i18n.game_profile_custom_filter_hint());
+        if !self.custom_filter {
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.filter.skip_hidden, i18n.upload_skip_hidden());
+            ui.checkbox(&mut self.filter.skip_temp, i18n.upload_skip_temp());
+        });
+        ui.label(
+            RichText::new(i18n.upload_patterns_hint())
+                .size(11.0)
+                .color(crate::ui::theme::muted_color(ui.ctx())),
+        );
+        ui.columns(2, |columns| {
+            columns[0].label(i18n.upload_include_patterns());
+            columns[0].add(
+                egui::TextEdit::multiline(&mut self.include_text)
+                    .desired_rows(3)
+                    .code_editor(),
+            );
+            columns[1].label(i18n.upload_exclude_patterns());
+            columns[1].add(
+                egui::TextEdit::multiline(&mut self.exclude_text)
+                    .desired_rows(3)
+                    .code_editor(),
+            );
+        });
+    }
+
+    // 自动备份计划
+    fn draw_backup(&mut self, ui: &mut egui::Ui, i18n: &I18n) {
+        ui.horizontal(|ui| {
+            ui.label(i18n.game_profile_backup_interval());
+            ui.add(
+                egui::DragValue::new(&mut self.backup_interval_hours)
+                    .range(0..=24 * 30)
+                    .suffix(" h"),
+            )
+            .on_hover_text(i18n.game_profile_backup_interval_hint());
+        });
+
+        let last = self
+            .last_backup_at
+            .and_then(|t| chrono::DateTime::from_timestamp(t, 0))
+            .map(|t| {
+                t.with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            });
+        if let Some(last) = last {
+            ui.label(
+                RichText::new(i18n.game_profile_last_backup(&last))
+                    .size(12.0)
+                    .color(crate::ui::theme::muted_color(ui.ctx())),
+            );
+        }
+    }
+
+    // 新建软链接的默认值
+    fn draw_symlink(&mut self, ui: &mut egui::Ui, i18n: &I18n) {
+        ui.checkbox(
+            &mut self.custom_symlink,
+            i18n.game_profile_symlink_defaults(),
+        );
+        if !self.custom_symlink {
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            ui.label(i18n.symlink_direction());
+            ui.selectable_value(
+                &mut self.symlink_direction,
+                LinkDirection::RemoteToLocal,
+                format!(
+                    "{} {}",
+                    icons::CLOUD_DOWNLOAD,
+                    LinkDirection::RemoteToLocal.description()
+                ),
+            );
+            ui.selectable_value(
+                &mut self.symlink_direction,
+                LinkDirection::LocalToRemote,
+                format!(
+                    "{} {}",
+                    icons::CLOUD_UPLOAD,
+                    LinkDirection::LocalToRemote.description()
+                ),
+            );
+        });
+        ui.horizontal(|ui| {
+            ui.label(i18n.symlink_remote_subfolder());
+            ui.add(
+                egui::TextEdit::singleline(&mut self.symlink_remote_subfolder)
+                    .desired_width(200.0)
+                    .hint_text("MySaves"),
+            );
+        });
+    }
+}
@@ -5,11 +5,14 @@ pub mod conflict_dialog;
 pub mod controls;
 pub mod file_list;
 pub mod font_loader;
+pub mod game_profile_dialog;
 pub mod guide_dialog;
 pub mod settings;
 pub mod symlink_dialog;
+pub mod symlink_health_panel;
 pub mod theme;
 pub mod upload_dialog;
+pub mod user_compare_dialog;
 pub mod windows;
 
 pub use app_panels::*;
@@ -19,8 +22,11 @@ pub use conflict_dialog::*;
 pub use controls::*;
 pub use file_list::{FileTreeRenderParams, TreeViewState, render_file_tree};
 pub use font_loader::setup_fonts;
+pub use game_profile_dialog::*;
 pub use guide_dialog::*;
 pub use settings::*;
 pub use symlink_dialog::*;
+pub use symlink_health_panel::*;
 pub use upload_dialog::*;
+pub use user_compare_dialog::*;
 pub use windows::*;
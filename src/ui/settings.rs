@@ -78,6 +78,9 @@ pub struct SettingsWindowState {
     pub steam_path_input: String,
     pub steam_path_changed: bool,
     pub show_reset_confirm: bool,
+    pub remote_provider: crate::config::RemoteStorageProvider,
+    pub session_cookie_input: String,
+    pub session_cookie_saved: bool,
     pub log_dir_display: String,
     pub steam_log_dir_display: String,
     pub config_path_display: String,
@@ -105,6 +108,7 @@ impl Default for SettingsWindowState {
         let backup_dir = crate::backup::get_backup_root_dir()
             .map(|p| p.display().to_string())
             .unwrap_or_default();
+        let remote_storage = crate::config::get_config().remote_storage;
 
         Self {
             tab: SettingsTab::Log,
@@ -113,6 +117,9 @@ impl Default for SettingsWindowState {
             steam_path_input: current_path,
             steam_path_changed: false,
             show_reset_confirm: false,
+            remote_provider: remote_storage.provider,
+            session_cookie_input: remote_storage.session_cookie,
+            session_cookie_saved: false,
             log_dir_display: log_dir,
             steam_log_dir_display: steam_log_dir,
             config_path_display: config_path,
@@ -460,6 +467,12 @@ fn draw_advanced_settings(ui: &mut egui::Ui, state: &mut SettingsWindowState, i1
     ui.separator();
     ui.add_space(16.0);
 
+    draw_remote_storage_settings(ui, state, i18n);
+
+    ui.add_space(24.0);
+    ui.separator();
+    ui.add_space(16.0);
+
     // 恢复默认设置
     ui.horizontal(|ui| {
         if ui
@@ -522,6 +535,80 @@ fn draw_advanced_settings(ui: &mut egui::Ui, state: &mut SettingsWindowState, i1
     );
 }
 
+// 网页版云存储读取方式
+fn draw_remote_storage_settings(ui: &mut egui::Ui, state: &mut SettingsWindowState, i18n: &I18n) {
+    use crate::config::RemoteStorageProvider;
+    let text_subtle = ui.style().visuals.text_color().gamma_multiply(0.6);
+
+    ui.heading(i18n.remote_storage_provider_label());
+    ui.add_space(8.0);
+
+    let previous = state.remote_provider;
+    ui.radio_value(
+        &mut state.remote_provider,
+        RemoteStorageProvider::Cdp,
+        i18n.remote_storage_provider_cdp(),
+    );
+    ui.radio_value(
+        &mut state.remote_provider,
+        RemoteStorageProvider::Web,
+        i18n.remote_storage_provider_web(),
+    );
+    if state.remote_provider != previous {
+        let provider = state.remote_provider;
+        if let Err(e) = crate::config::update_config(|c| c.remote_storage.provider = provider) {
+            tracing::error!("保存网页版云存储读取方式失败: {}", e);
+        }
+    }
+
+    if state.remote_provider == RemoteStorageProvider::Web {
+        ui.add_space(8.0);
+        ui.label(
+            egui::RichText::new(i18n.session_cookie_label())
+                .size(11.0)
+                .color(text_subtle),
+        );
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+                if ui.button(i18n.ok()).clicked() {
+                    let cookie = state.session_cookie_input.trim().to_string();
+                    match crate::config::update_config(|c| c.remote_storage.session_cookie = cookie)
+                    {
+                        Ok(_) => state.session_cookie_saved = true,
+                        Err(e) => tracing::error!("保存会话 Cookie 失败: {}", e),
+                    }
+                }
+
+                let w = ui.available_width();
+                let response = ui.add_sized(
+                    [w, 24.0],
+                    egui::TextEdit::singleline(&mut state.session_cookie_input)
+                        .password(true)
+                        .hint_text("steamLoginSecure=..."),
+                );
+                if response.changed() {
+                    state.session_cookie_saved = false;
+                }
+            });
+        });
+
+        if state.session_cookie_saved {
+            ui.label(
+                egui::RichText::new(i18n.session_cookie_saved())
+                    .size(11.0)
+                    .color(crate::ui::theme::success_color(ui.ctx())),
+            );
+        }
+        ui.add_space(4.0);
+        ui.label(
+            egui::RichText::new(i18n.session_cookie_hint())
+                .size(10.0)
+                .color(text_subtle),
+        );
+    }
+}
+
 // 备份设置内容
 fn draw_backup_settings(ui: &mut egui::Ui, state: &mut SettingsWindowState, i18n: &I18n) {
     let text_subtle = ui.style().visuals.text_color().gamma_multiply(0.6);
@@ -1,7 +1,9 @@
 use crate::i18n::I18n;
 use crate::icons;
 use crate::steam_worker::SteamWorkerManager;
-use crate::symlink_manager::{LinkDirection, LinkStatus, SymlinkConfig, SymlinkManager};
+use crate::symlink_manager::{
+    CloudFileState, LinkDirection, LinkStatus, MigrationRecord, SymlinkConfig, SymlinkManager,
+};
 use egui::RichText;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
@@ -9,6 +11,7 @@ use std::sync::{Arc, Mutex};
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SymlinkAction {
     None,
+    OpenHealthCheck,
 }
 
 // 待执行的操作
@@ -22,6 +25,11 @@ struct PendingOperations {
     refresh: bool,
     copy_command: Option<usize>,
     sync_files: Option<usize>, // 同步指定配置的文件到云端
+    migrate: Option<usize>,
+    undo_migration: Option<usize>,
+    discard_backup: Option<usize>,
+    export_to: Option<PathBuf>,
+    import_from: Option<PathBuf>,
 }
 
 // 软链接管理对话框状态
@@ -33,11 +41,18 @@ pub struct SymlinkDialog {
     // 已配置的软链接
     configs: Vec<SymlinkConfig>,
     config_statuses: Vec<LinkStatus>,
+    // 每个配置最近一次可撤销的迁移
+    config_migrations: Vec<Option<MigrationRecord>>,
 
     // 新建软链接表单
     new_direction: LinkDirection,
     new_local_path: String,
     new_remote_subfolder: String,
+    // 游戏配置档中的默认云端子目录，添加后表单恢复为此值
+    default_remote_subfolder: String,
+
+    // 同步时是否删除本地已不存在的云端文件
+    sync_remove_missing: bool,
 
     // 状态消息
     status_message: Option<(String, bool)>, // (message, is_error)
@@ -72,9 +87,12 @@ impl SymlinkDialog {
             game_name,
             configs: Vec::new(),
             config_statuses: Vec::new(),
+            config_migrations: Vec::new(),
             new_direction: LinkDirection::RemoteToLocal,
             new_local_path: String::new(),
             new_remote_subfolder: String::new(),
+            default_remote_subfolder: String::new(),
+            sync_remove_missing: false,
             status_message: None,
             manager,
             steam_manager,
@@ -85,18 +103,38 @@ impl SymlinkDialog {
         dialog
     }
 
+    // 用游戏配置档中的软链接默认值预填新建表单
+    pub fn with_profile(mut self, profile: Option<&crate::config::GameProfile>) -> Self {
+        let Some(profile) = profile else {
+            return self;
+        };
+        if let Some(direction) = &profile.symlink_direction {
+            self.new_direction = LinkDirection::from_config_str(direction);
+        }
+        if let Some(subfolder) = &profile.symlink_remote_subfolder {
+            self.default_remote_subfolder = subfolder.clone();
+            self.new_remote_subfolder = subfolder.clone();
+        }
+        self
+    }
+
     fn refresh_configs(&mut self) {
         if let Some(manager) = &self.manager {
             match manager.get_configs_for_app(self.app_id) {
                 Ok(configs) => {
                     self.config_statuses =
                         configs.iter().map(|c| manager.verify_symlink(c)).collect();
+                    self.config_migrations = configs
+                        .iter()
+                        .map(|c| manager.find_migration(&c.id))
+                        .collect();
                     self.configs = configs;
                 }
                 Err(e) => {
                     tracing::warn!("加载软链接配置失败: {}", e);
                     self.configs = Vec::new();
                     self.config_statuses = Vec::new();
+                    self.config_migrations = Vec::new();
                 }
             }
         }
@@ -142,6 +180,47 @@ impl SymlinkDialog {
             }
         }
 
+        // 迁移现有目录
+        if let Some(i) = ops.migrate
+            && let (Some(manager), Some(config)) = (&self.manager, self.configs.get(i))
+        {
+            match manager.migrate_to_symlink(config) {
+                Ok(record) => {
+                    message = Some((i18n.symlink_migrated(record.moved_files.len()), false));
+                }
+                Err(e) => {
+                    message = Some((format!("{}: {}", i18n.symlink_migrate_failed(), e), true));
+                }
+            }
+            need_refresh = true;
+        }
+
+        // 撤销迁移
+        if let Some(i) = ops.undo_migration
+            && let (Some(manager), Some(Some(record))) =
+                (&self.manager, self.config_migrations.get(i))
+        {
+            match manager.undo_migration(record) {
+                Ok(()) => message = Some((i18n.symlink_migration_undone().to_string(), false)),
+                Err(e) => {
+                    message = Some((format!("{}: {}", i18n.symlink_undo_failed(), e), true));
+                }
+            }
+            need_refresh = true;
+        }
+
+        // 删除迁移备份
+        if let Some(i) = ops.discard_backup
+            && let (Some(manager), Some(Some(record))) =
+                (&self.manager, self.config_migrations.get(i))
+        {
+            match manager.discard_migration_backup(record) {
+                Ok(()) => message = Some((i18n.symlink_backup_discarded().to_string(), false)),
+                Err(e) => message = Some((e.to_string(), true)),
+            }
+            need_refresh = true;
+        }
+
         // 添加配置
         if let Some(config) = ops.add_config
             && let Some(manager) = &self.manager
@@ -150,7 +229,7 @@ impl SymlinkDialog {
                 message = Some((format!("{}: {}", i18n.symlink_add_failed(), e), true));
             } else {
                 self.new_local_path.clear();
-                self.new_remote_subfolder.clear();
+                self.new_remote_subfolder = self.default_remote_subfolder.clone();
                 need_refresh = true;
                 message = Some((i18n.symlink_config_added().to_string(), false));
             }
@@ -174,7 +253,7 @@ impl SymlinkDialog {
                     }
                 }
                 self.new_local_path.clear();
-                self.new_remote_subfolder.clear();
+                self.new_remote_subfolder = self.default_remote_subfolder.clone();
                 need_refresh = true;
             }
         }
@@ -187,6 +266,29 @@ impl SymlinkDialog {
             message = Some(sync_result);
         }
 
+        // 导出配置
+        if let Some(path) = ops.export_to
+            && let Some(manager) = &self.manager
+        {
+            message = Some(
+                match manager.export_configs(self.app_id, Some(self.game_name.clone()), &path) {
+                    Ok(count) => (i18n.symlink_exported(count), false),
+                    Err(e) => (format!("{}: {}", i18n.symlink_export_failed(), e), true),
+                },
+            );
+        }
+
+        // 导入配置
+        if let Some(path) = ops.import_from
+            && let Some(manager) = &self.manager
+        {
+            message = Some(match manager.import_configs(self.app_id, &path) {
+                Ok((added, skipped)) => (i18n.symlink_imported(added, skipped), false),
+                Err(e) => (format!("{}: {}", i18n.symlink_import_failed(), e), true),
+            });
+            need_refresh = true;
+        }
+
         // 刷新
         if ops.refresh {
             need_refresh = true;
@@ -203,7 +305,7 @@ impl SymlinkDialog {
         }
     }
 
-    // 同步指定配置下的所有文件到云端
+    // 对比云端列表后只上传有变化的文件，可选删除本地已不存在的云端文件
     fn sync_files_for_config(&self, config: &SymlinkConfig, i18n: &I18n) -> (String, bool) {
         // 检查必要条件
         let Some(manager) = &self.manager else {
@@ -213,24 +315,52 @@ impl SymlinkDialog {
             return (i18n.symlink_sync_no_steam().to_string(), true);
         };
 
-        // 扫描文件
-        let files = match manager.scan_symlink_files(config) {
-            Ok(f) => f,
+        // 获取云端当前列表，失败时不盲目上传
+        let cloud_files = match steam_mgr.lock() {
+            Ok(mut mgr) => match mgr.get_files() {
+                Ok(files) => files,
+                Err(e) => {
+                    return (format!("{}: {}", i18n.symlink_sync_list_failed(), e), true);
+                }
+            },
+            Err(_) => return (i18n.symlink_sync_no_steam().to_string(), true),
+        };
+        let cloud_files: Vec<CloudFileState> = cloud_files
+            .into_iter()
+            .filter(|f| f.root == 0 && f.exists)
+            .map(|f| CloudFileState {
+                name: f.name,
+                size: f.size,
+                timestamp: f.timestamp,
+                sha: None,
+            })
+            .collect();
+
+        // 扫描并对比
+        let plan = match manager.plan_sync(config, cloud_files) {
+            Ok(plan) => plan,
             Err(e) => {
                 return (format!("{}: {}", i18n.symlink_sync_scan_failed(), e), true);
             }
         };
 
-        if files.is_empty() {
+        if plan.upload.is_empty()
+            && plan.unchanged.is_empty()
+            && plan.cloud_newer.is_empty()
+            && plan.cloud_only.is_empty()
+        {
             return (i18n.symlink_sync_no_files().to_string(), false);
         }
 
-        // 上传文件
+        for name in &plan.cloud_newer {
+            tracing::info!("云端版本较新，跳过上传: {}", name);
+        }
+
+        // 上传有变化的文件
         let mut success_count = 0;
         let mut failed_count = 0;
-        let total = files.len();
 
-        for (cloud_path, local_path, _size) in &files {
+        for (cloud_path, local_path) in &plan.upload {
             // 读取本地文件
             match std::fs::read(local_path) {
                 Ok(data) => {
@@ -261,46 +391,74 @@ impl SymlinkDialog {
             }
         }
 
+        // 删除本地已不存在的云端文件
+        let mut removed_count = 0;
+        if self.sync_remove_missing {
+            for name in &plan.cloud_only {
+                let result = match steam_mgr.lock() {
+                    Ok(mut mgr) => mgr.delete_file(name),
+                    Err(_) => continue,
+                };
+                match result {
+                    Ok(_) => {
+                        tracing::debug!("删除云端文件: {}", name);
+                        removed_count += 1;
+                    }
+                    Err(e) => {
+                        tracing::warn!("删除云端文件失败 {}: {}", name, e);
+                        failed_count += 1;
+                    }
+                }
+            }
+        }
+
         // 触发云同步
-        if success_count > 0
+        if success_count + removed_count > 0
             && let Ok(mut mgr) = steam_mgr.lock()
         {
             let _ = mgr.sync_cloud_files();
         }
 
         tracing::info!(
-            "软链接文件同步完成: {}/{} 成功, {} 失败",
+            "软链接文件同步完成: 上传 {}, 未变化 {}, 云端较新 {}, 删除 {}, 失败 {}",
             success_count,
-            total,
+            plan.unchanged.len(),
+            plan.cloud_newer.len(),
+            removed_count,
             failed_count
         );
 
-        if failed_count == 0 {
+        let mut report = i18n.symlink_sync_report(
+            success_count,
+            plan.unchanged.len(),
+            plan.cloud_newer.len(),
+            removed_count,
+        );
+        if !self.sync_remove_missing && !plan.cloud_only.is_empty() {
+            report = format!(
+                "{}\n{}",
+                report,
+                i18n.symlink_sync_cloud_only_hint(plan.cloud_only.len())
+            );
+        }
+
+        if failed_count > 0 {
+            (format!("{}: {}", i18n.symlink_sync_partial(), report), true)
+        } else if success_count + removed_count == 0 {
             (
-                format!(
-                    "{} ({} {})",
-                    i18n.symlink_sync_success(),
-                    success_count,
-                    i18n.files()
-                ),
+                format!("{} ({})", i18n.symlink_sync_up_to_date(), report),
                 false,
             )
         } else {
             (
-                format!(
-                    "{}: {}/{} {}",
-                    i18n.symlink_sync_partial(),
-                    success_count,
-                    total,
-                    i18n.files()
-                ),
-                true,
+                format!("{}: {}", i18n.symlink_sync_success(), report),
+                false,
             )
         }
     }
 
     pub fn draw(&mut self, ctx: &egui::Context, i18n: &I18n) -> SymlinkAction {
-        let action = SymlinkAction::None;
+        let mut action = SymlinkAction::None;
 
         if !self.show {
             return action;
@@ -373,7 +531,17 @@ impl SymlinkDialog {
                 ui.add_space(8.0);
 
                 // 已配置的软链接列表
-                ui.label(RichText::new(i18n.symlink_configured_links()).strong());
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(i18n.symlink_configured_links()).strong());
+                    if self.steam_manager.is_some() {
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            ui.checkbox(
+                                &mut self.sync_remove_missing,
+                                i18n.symlink_sync_remove_missing(),
+                            );
+                        });
+                    }
+                });
                 ui.add_space(4.0);
 
                 if self.configs.is_empty() {
@@ -410,6 +578,13 @@ impl SymlinkDialog {
                                         RichText::new(config.local_path.to_string_lossy())
                                             .size(11.0),
                                     );
+                                    if config.portable {
+                                        ui.label(
+                                            RichText::new(icons::GLOBE)
+                                                .color(crate::ui::theme::muted_color(ctx)),
+                                        )
+                                        .on_hover_text(i18n.symlink_portable_hint());
+                                    }
 
                                     ui.with_layout(
                                         egui::Layout::right_to_left(egui::Align::Center),
@@ -457,6 +632,13 @@ impl SymlinkDialog {
                                                     }
                                                 }
                                                 LinkStatus::Conflict => {
+                                                    if ui
+                                                        .small_button(icons::ARROW_RIGHT)
+                                                        .on_hover_text(i18n.symlink_migrate())
+                                                        .clicked()
+                                                    {
+                                                        pending.migrate = Some(i);
+                                                    }
                                                     ui.label(
                                                         RichText::new(
                                                             i18n.symlink_conflict_label(),
@@ -467,6 +649,24 @@ impl SymlinkDialog {
                                                 }
                                             }
 
+                                            // 可撤销的迁移
+                                            if self.config_migrations[i].is_some() {
+                                                if ui
+                                                    .small_button(icons::CHECK)
+                                                    .on_hover_text(i18n.symlink_discard_backup())
+                                                    .clicked()
+                                                {
+                                                    pending.discard_backup = Some(i);
+                                                }
+                                                if ui
+                                                    .small_button(icons::ARROW_LEFT)
+                                                    .on_hover_text(i18n.symlink_undo_migration())
+                                                    .clicked()
+                                                {
+                                                    pending.undo_migration = Some(i);
+                                                }
+                                            }
+
                                             // 复制命令按钮
                                             if ui
                                                 .small_button(icons::COPY)
@@ -603,6 +803,43 @@ impl SymlinkDialog {
                     {
                         pending.refresh = true;
                     }
+                    if ui
+                        .button(format!(
+                            "{} {}",
+                            icons::WRENCH,
+                            i18n.symlink_health_check_all()
+                        ))
+                        .clicked()
+                    {
+                        action = SymlinkAction::OpenHealthCheck;
+                    }
+                    if ui
+                        .add_enabled(
+                            !self.configs.is_empty(),
+                            egui::Button::new(format!(
+                                "{} {}",
+                                icons::EXPORT,
+                                i18n.symlink_export()
+                            )),
+                        )
+                        .on_hover_text(i18n.symlink_export_hint())
+                        .clicked()
+                        && let Some(path) = rfd::FileDialog::new()
+                            .add_filter("JSON", &["json"])
+                            .set_file_name(format!("symlinks_{}.json", self.app_id))
+                            .save_file()
+                    {
+                        pending.export_to = Some(path);
+                    }
+                    if ui
+                        .button(format!("{} {}", icons::IMPORT, i18n.symlink_import()))
+                        .clicked()
+                        && let Some(path) = rfd::FileDialog::new()
+                            .add_filter("JSON", &["json"])
+                            .pick_file()
+                    {
+                        pending.import_from = Some(path);
+                    }
                 });
             });
 
@@ -0,0 +1,230 @@
+use crate::i18n::I18n;
+use crate::icons;
+use crate::symlink_manager::{LinkHealth, LinkStatus, RepairAction, SymlinkManager};
+use egui::RichText;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+// 所有游戏的软链接健康检查面板
+pub struct SymlinkHealthPanel {
+    pub show: bool,
+    manager: SymlinkManager,
+    results: Vec<LinkHealth>,
+    game_names: HashMap<u32, String>,
+    show_healthy: bool,
+    status_message: Option<(String, bool)>,
+}
+
+impl SymlinkHealthPanel {
+    pub fn new(
+        steam_path: PathBuf,
+        user_id: String,
+        game_names: HashMap<u32, String>,
+    ) -> anyhow::Result<Self> {
+        let manager = SymlinkManager::new(steam_path, user_id)?;
+        let mut panel = Self {
+            show: true,
+            manager,
+            results: Vec::new(),
+            game_names,
+            show_healthy: false,
+            status_message: None,
+        };
+        panel.refresh();
+        Ok(panel)
+    }
+
+    pub fn refresh(&mut self) {
+        self.results = self.manager.check_health();
+        let issues = self.issue_count();
+        if issues > 0 {
+            tracing::warn!(
+                "软链接健康检查: {}/{} 个需要处理",
+                issues,
+                self.results.len()
+            );
+        } else {
+            tracing::debug!("软链接健康检查: {} 个全部正常", self.results.len());
+        }
+    }
+
+    pub fn issue_count(&self) -> usize {
+        self.results.iter().filter(|h| !h.is_healthy()).count()
+    }
+
+    fn repair_label(action: RepairAction, i18n: &I18n) -> String {
+        match action {
+            RepairAction::CreateLink => format!("{} {}", icons::LINK, i18n.symlink_repair_create()),
+            RepairAction::RecreateLink => {
+                format!("{} {}", icons::REFRESH, i18n.symlink_repair_recreate())
+            }
+            RepairAction::MigrateFolder => {
+                format!("{} {}", icons::ARROW_RIGHT, i18n.symlink_repair_migrate())
+            }
+            RepairAction::AdoptPlatform => {
+                format!("{} {}", icons::CHECK, i18n.symlink_repair_adopt())
+            }
+            RepairAction::RemoveConfig => {
+                format!("{} {}", icons::TRASH, i18n.symlink_repair_remove())
+            }
+        }
+    }
+
+    pub fn draw(&mut self, ctx: &egui::Context, i18n: &I18n) {
+        if !self.show {
+            return;
+        }
+
+        let mut repair: Option<(usize, RepairAction)> = None;
+        let mut refresh = false;
+        let mut show = self.show;
+
+        egui::Window::new(i18n.symlink_health_title())
+            .open(&mut show)
+            .resizable(true)
+            .collapsible(false)
+            .default_size([640.0, 420.0])
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                let issues = self.issue_count();
+                ui.horizontal(|ui| {
+                    if self.results.is_empty() {
+                        ui.label(i18n.symlink_health_no_configs());
+                    } else if issues == 0 {
+                        ui.colored_label(
+                            crate::ui::theme::success_color(ctx),
+                            format!(
+                                "{} {}",
+                                icons::CHECK,
+                                i18n.symlink_health_all_ok(self.results.len())
+                            ),
+                        );
+                    } else {
+                        ui.colored_label(
+                            crate::ui::theme::warning_color(ctx),
+                            format!(
+                                "{} {}",
+                                icons::WARNING,
+                                i18n.symlink_health_summary(issues, self.results.len())
+                            ),
+                        );
+                    }
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui
+                            .button(icons::REFRESH)
+                            .on_hover_text(i18n.symlink_refresh())
+                            .clicked()
+                        {
+                            refresh = true;
+                        }
+                        ui.checkbox(&mut self.show_healthy, i18n.symlink_health_show_all());
+                    });
+                });
+
+                ui.separator();
+
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    for (i, health) in self.results.iter().enumerate() {
+                        if health.is_healthy() && !self.show_healthy {
+                            continue;
+                        }
+                        let config = &health.config;
+                        let game_name = self
+                            .game_names
+                            .get(&config.app_id)
+                            .cloned()
+                            .unwrap_or_else(|| format!("App {}", config.app_id));
+
+                        ui.group(|ui| {
+                            ui.horizontal(|ui| {
+                                let color = match health.status {
+                                    LinkStatus::Valid => crate::ui::theme::success_color(ctx),
+                                    LinkStatus::NotExists => crate::ui::theme::muted_color(ctx),
+                                    LinkStatus::Broken => crate::ui::theme::warning_color(ctx),
+                                    LinkStatus::Conflict => crate::ui::theme::error_color(ctx),
+                                };
+                                ui.colored_label(color, health.status.icon())
+                                    .on_hover_text(health.status.description());
+                                ui.strong(&game_name);
+                                ui.label(
+                                    RichText::new(format!("({})", config.app_id))
+                                        .color(crate::ui::theme::muted_color(ctx)),
+                                );
+                                ui.label(health.status.description());
+                                if health.platform_mismatch {
+                                    ui.colored_label(
+                                        crate::ui::theme::warning_color(ctx),
+                                        i18n.symlink_platform_mismatch(&config.platform),
+                                    );
+                                }
+                            });
+                            ui.label(
+                                RichText::new(format!(
+                                    "{} ↔ {}",
+                                    config.remote_subfolder,
+                                    config.local_path.display()
+                                ))
+                                .size(11.0)
+                                .color(crate::ui::theme::muted_color(ctx)),
+                            )
+                            .on_hover_text(config.direction.description());
+
+                            let actions = health.repair_actions();
+                            if !actions.is_empty() {
+                                ui.horizontal(|ui| {
+                                    for action in actions {
+                                        if ui
+                                            .small_button(Self::repair_label(action, i18n))
+                                            .clicked()
+                                        {
+                                            repair = Some((i, action));
+                                        }
+                                    }
+                                });
+                            }
+                        });
+                        ui.add_space(4.0);
+                    }
+                });
+
+                if let Some((msg, is_error)) = &self.status_message {
+                    ui.add_space(6.0);
+                    let color = if *is_error {
+                        crate::ui::theme::error_color(ctx)
+                    } else {
+                        crate::ui::theme::success_color(ctx)
+                    };
+                    ui.label(RichText::new(msg).color(color));
+                }
+            });
+
+        self.show = show;
+
+        if let Some((i, action)) = repair
+            && let Some(health) = self.results.get(i)
+        {
+            let config = health.config.clone();
+            match self.manager.repair(&config, action) {
+                Ok(()) => {
+                    tracing::info!(
+                        "修复软链接 {} ({:?}): {:?}",
+                        config.id,
+                        action,
+                        config.local_path
+                    );
+                    self.status_message = Some((i18n.symlink_repair_done().to_string(), false));
+                }
+                Err(e) => {
+                    tracing::warn!("修复软链接失败 {}: {}", config.id, e);
+                    self.status_message =
+                        Some((format!("{}: {}", i18n.symlink_repair_failed(), e), true));
+                }
+            }
+            refresh = true;
+        }
+
+        if refresh {
+            self.refresh();
+        }
+    }
+}
@@ -1,6 +1,10 @@
-use crate::file_manager::{UploadQueue, format_size};
+use crate::config::{GameProfile, PathRewriteRule, UploadConfig};
+use crate::file_manager::{
+    QuotaIssue, TaskStatus, UploadLimits, UploadProjection, UploadQueue, format_size,
+};
 use crate::i18n::I18n;
 use crate::icons;
+use crate::upload_session::UploadSession;
 use egui::RichText;
 
 // 上传对话框的操作结果
@@ -18,6 +22,15 @@ pub struct UploadPreviewDialog {
     // 编辑状态：(任务索引, 编辑中的路径)
     editing_index: Option<usize>,
     editing_path: String,
+    // 云端现有文件 (名称, 大小)，用于计算覆盖和配额预测
+    existing_files: Vec<(String, u64)>,
+    pub limits: UploadLimits,
+    // 过滤与路径改写规则编辑状态
+    filter_config: UploadConfig,
+    include_text: String,
+    exclude_text: String,
+    // 游戏配置档有独立过滤规则时，规则修改保存到配置档
+    pub profile: Option<GameProfile>,
 }
 
 impl UploadPreviewDialog {
@@ -27,7 +40,50 @@ impl UploadPreviewDialog {
             show: true,
             editing_index: None,
             editing_path: String::new(),
+            existing_files: Vec::new(),
+            limits: UploadLimits::default(),
+            filter_config: UploadConfig::default(),
+            include_text: String::new(),
+            exclude_text: String::new(),
+            profile: None,
         }
+        .with_filter_config(crate::config::get_config().upload)
+    }
+
+    fn with_filter_config(mut self, config: UploadConfig) -> Self {
+        self.include_text = config.include.join("\n");
+        self.exclude_text = config.exclude.join("\n");
+        self.filter_config = config;
+        self
+    }
+
+    pub fn with_profile(self, profile: Option<&GameProfile>) -> Self {
+        match profile.filter(|p| p.upload_filter.is_some()) {
+            Some(profile) => {
+                // 写入后校验仍是全局设置
+                let mut config = profile.upload_config();
+                config.verify_after_write = self.filter_config.verify_after_write;
+                let mut dialog = self.with_filter_config(config);
+                dialog.profile = Some(profile.clone());
+                dialog
+            }
+            None => self,
+        }
+    }
+
+    pub fn with_quota(mut self, existing_files: Vec<(String, u64)>, limits: UploadLimits) -> Self {
+        self.existing_files = existing_files;
+        self.limits = limits;
+        self
+    }
+
+    pub fn projection(&self) -> UploadProjection {
+        self.queue.project(
+            self.existing_files
+                .iter()
+                .map(|(name, size)| (name.as_str(), *size)),
+            &self.limits,
+        )
     }
 
     pub fn draw(&mut self, ctx: &egui::Context, i18n: &I18n) -> UploadAction {
@@ -53,6 +109,12 @@ impl UploadPreviewDialog {
                     ui.label(i18n.total_size_label(&format_size(total_size)));
                 });
 
+                let projection = self.projection();
+                if !self.queue.tasks.is_empty() {
+                    ui.add_space(4.0);
+                    self.draw_projection(ui, &projection, i18n);
+                }
+
                 ui.add_space(8.0);
 
                 // 操作按钮 - 添加文件/文件夹
@@ -83,11 +145,15 @@ impl UploadPreviewDialog {
                                 )
                                 .clicked()
                         {
-                            self.queue.tasks.clear();
+                            self.queue.clear();
                         }
                     });
                 });
 
+                ui.add_space(4.0);
+                self.draw_filter_settings(ui, i18n);
+                self.draw_skipped(ui, i18n);
+
                 ui.add_space(8.0);
                 ui.separator();
                 ui.add_space(8.0);
@@ -125,8 +191,25 @@ impl UploadPreviewDialog {
 
                 // 操作按钮 - 取消/确认
                 ui.horizontal(|ui| {
+                    // 写入后校验（保存到配置，下次上传沿用）
+                    if ui
+                        .checkbox(
+                            &mut self.filter_config.verify_after_write,
+                            i18n.upload_verify_after_write(),
+                        )
+                        .on_hover_text(i18n.upload_verify_after_write_hint())
+                        .changed()
+                    {
+                        let verify = self.filter_config.verify_after_write;
+                        if let Err(e) =
+                            crate::config::update_config(|c| c.upload.verify_after_write = verify)
+                        {
+                            tracing::warn!("保存上传校验设置失败: {}", e);
+                        }
+                    }
+
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        let can_upload = !self.queue.tasks.is_empty();
+                        let can_upload = !self.queue.tasks.is_empty() && !projection.is_blocked();
 
                         if ui
                             .add_enabled(can_upload, egui::Button::new(i18n.confirm_upload()))
@@ -270,7 +353,7 @@ impl UploadPreviewDialog {
 
         // 处理删除
         if let Some(index) = to_remove {
-            self.queue.tasks.remove(index);
+            self.queue.remove_task(index);
             // 如果删除的是正在编辑的项，取消编辑状态
             if self.editing_index == Some(index) {
                 self.editing_index = None;
@@ -289,12 +372,252 @@ impl UploadPreviewDialog {
         }
     }
 
+    // 过滤与云端路径改写规则
+    fn draw_filter_settings(&mut self, ui: &mut egui::Ui, i18n: &I18n) {
+        egui::CollapsingHeader::new(i18n.upload_filters_header())
+            .id_salt("upload_filters")
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.checkbox(
+                        &mut self.filter_config.skip_hidden,
+                        i18n.upload_skip_hidden(),
+                    );
+                    ui.checkbox(&mut self.filter_config.skip_temp, i18n.upload_skip_temp());
+                });
+
+                ui.label(
+                    RichText::new(i18n.upload_patterns_hint())
+                        .size(12.0)
+                        .color(crate::ui::theme::muted_color(ui.ctx())),
+                );
+                ui.columns(2, |columns| {
+                    columns[0].label(i18n.upload_include_patterns());
+                    columns[0].add(
+                        egui::TextEdit::multiline(&mut self.include_text)
+                            .desired_rows(3)
+                            .hint_text("*.sav"),
+                    );
+                    columns[1].label(i18n.upload_exclude_patterns());
+                    columns[1].add(
+                        egui::TextEdit::multiline(&mut self.exclude_text)
+                            .desired_rows(3)
+                            .hint_text("*.log"),
+                    );
+                });
+
+                ui.add_space(4.0);
+                ui.label(i18n.upload_path_rules());
+                let mut to_remove: Option<usize> = None;
+                for (index, rule) in self.filter_config.path_rules.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        if ui
+                            .button(
+                                RichText::new("✕").color(crate::ui::theme::error_color(ui.ctx())),
+                            )
+                            .clicked()
+                        {
+                            to_remove = Some(index);
+                        }
+                        match rule {
+                            PathRewriteRule::StripPrefix { prefix } => {
+                                ui.label(i18n.upload_rule_strip_prefix());
+                                ui.add(egui::TextEdit::singleline(prefix).desired_width(200.0));
+                            }
+                            PathRewriteRule::Lowercase => {
+                                ui.label(i18n.upload_rule_lowercase());
+                            }
+                            PathRewriteRule::ReplaceSegment { from, to } => {
+                                ui.label(i18n.upload_rule_replace_segment());
+                                ui.add(egui::TextEdit::singleline(from).desired_width(120.0));
+                                ui.label("→");
+                                ui.add(egui::TextEdit::singleline(to).desired_width(120.0));
+                            }
+                        }
+                    });
+                }
+                if let Some(index) = to_remove {
+                    self.filter_config.path_rules.remove(index);
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label(i18n.upload_add_rule());
+                    if ui.small_button(i18n.upload_rule_strip_prefix()).clicked() {
+                        self.filter_config
+                            .path_rules
+                            .push(PathRewriteRule::StripPrefix {
+                                prefix: String::new(),
+                            });
+                    }
+                    if ui.small_button(i18n.upload_rule_lowercase()).clicked() {
+                        self.filter_config
+                            .path_rules
+                            .push(PathRewriteRule::Lowercase);
+                    }
+                    if ui
+                        .small_button(i18n.upload_rule_replace_segment())
+                        .clicked()
+                    {
+                        self.filter_config
+                            .path_rules
+                            .push(PathRewriteRule::ReplaceSegment {
+                                from: String::new(),
+                                to: String::new(),
+                            });
+                    }
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button(i18n.upload_apply_filters()).clicked() {
+                            self.apply_filter_config();
+                        }
+                    });
+                });
+            });
+    }
+
+    // 保存规则并重建队列，列表即为改写后的预览
+    fn apply_filter_config(&mut self) {
+        let lines = |text: &str| -> Vec<String> {
+            text.lines()
+                .map(|l| l.trim().to_string())
+                .filter(|l| !l.is_empty())
+                .collect()
+        };
+        self.filter_config.include = lines(&self.include_text);
+        self.filter_config.exclude = lines(&self.exclude_text);
+
+        let config = self.filter_config.clone();
+        let result = match &mut self.profile {
+            Some(profile) => {
+                profile.upload_filter = Some(config);
+                crate::config::save_game_profile(profile)
+            }
+            None => crate::config::update_config(|c| c.upload = config.clone()),
+        };
+        if let Err(e) = result {
+            tracing::warn!("保存上传过滤规则失败: {}", e);
+        }
+
+        self.editing_index = None;
+        self.queue
+            .set_filter(crate::upload_filter::UploadFilter::new(&self.filter_config));
+    }
+
+    // 被过滤规则跳过的文件
+    fn draw_skipped(&self, ui: &mut egui::Ui, i18n: &I18n) {
+        if self.queue.skipped.is_empty() {
+            return;
+        }
+        egui::CollapsingHeader::new(i18n.upload_skipped_files(self.queue.skipped.len()))
+            .id_salt("upload_skipped")
+            .show(ui, |ui| {
+                egui::ScrollArea::vertical()
+                    .max_height(120.0)
+                    .show(ui, |ui| {
+                        for (path, reason) in &self.queue.skipped {
+                            let reason = match reason {
+                                crate::upload_filter::SkipReason::Hidden => {
+                                    i18n.upload_skip_reason_hidden()
+                                }
+                                crate::upload_filter::SkipReason::Temp => {
+                                    i18n.upload_skip_reason_temp()
+                                }
+                                crate::upload_filter::SkipReason::Excluded => {
+                                    i18n.upload_skip_reason_excluded()
+                                }
+                                crate::upload_filter::SkipReason::NotIncluded => {
+                                    i18n.upload_skip_reason_not_included()
+                                }
+                            };
+                            ui.label(
+                                RichText::new(format!("{} ({})", path.display(), reason))
+                                    .size(12.0)
+                                    .color(crate::ui::theme::muted_color(ui.ctx())),
+                            );
+                        }
+                    });
+            });
+    }
+
+    // 上传后的配额预测
+    fn draw_projection(&self, ui: &mut egui::Ui, projection: &UploadProjection, i18n: &I18n) {
+        let muted = crate::ui::theme::muted_color(ui.ctx());
+        let limit_text =
+            |limit: Option<String>| limit.unwrap_or_else(|| i18n.upload_limit_none().to_string());
+
+        ui.label(
+            RichText::new(i18n.upload_projection_bytes(
+                &format_size(projection.current_bytes),
+                &format_size(projection.projected_bytes),
+                &limit_text(self.limits.quota.map(format_size)),
+            ))
+            .color(muted),
+        );
+        ui.label(
+            RichText::new(i18n.upload_projection_files(
+                projection.current_files,
+                projection.projected_files,
+                &limit_text(self.limits.max_files.map(|n| n.to_string())),
+            ))
+            .color(muted),
+        );
+        if let Some(available) = self.limits.available {
+            ui.label(
+                RichText::new(i18n.upload_projection_available(
+                    &format_size(projection.growth_bytes()),
+                    &format_size(available),
+                ))
+                .color(muted),
+            );
+        }
+        if projection.overwritten_files > 0 {
+            ui.label(
+                RichText::new(i18n.upload_projection_overwrites(
+                    projection.overwritten_files,
+                    &format_size(projection.overwritten_bytes),
+                ))
+                .color(muted),
+            );
+        }
+
+        for issue in &projection.issues {
+            let color = if issue.is_blocking() {
+                crate::ui::theme::error_color(ui.ctx())
+            } else {
+                crate::ui::theme::warning_color(ui.ctx())
+            };
+            ui.colored_label(color, quota_issue_message(issue, i18n));
+        }
+        if projection.is_blocked() {
+            ui.colored_label(
+                crate::ui::theme::error_color(ui.ctx()),
+                i18n.upload_blocked_by_quota(),
+            );
+        }
+    }
+
     fn has_warnings(&self) -> bool {
         // 有文件待上传时显示覆盖警告
         !self.queue.tasks.is_empty()
     }
 }
 
+fn quota_issue_message(issue: &QuotaIssue, i18n: &I18n) -> String {
+    match issue {
+        QuotaIssue::QuotaExceeded { projected, limit } => {
+            i18n.quota_issue_exceeded(&format_size(*projected), &format_size(*limit))
+        }
+        QuotaIssue::MaxFilesExceeded { projected, limit } => {
+            i18n.quota_issue_max_files(*projected, *limit)
+        }
+        QuotaIssue::AvailableExceeded { needed, available } => {
+            i18n.quota_issue_available(&format_size(*needed), &format_size(*available))
+        }
+        QuotaIssue::NearQuota { projected, limit } => {
+            i18n.quota_issue_near(&format_size(*projected), &format_size(*limit))
+        }
+    }
+}
+
 // 上传进度对话框
 pub struct UploadProgressDialog {
     pub show: bool,
@@ -380,27 +703,33 @@ impl UploadProgressDialog {
 pub struct UploadCompleteDialog {
     pub show: bool,
     pub success_count: usize,
+    pub skipped_count: usize,
     pub failed_count: usize,
     pub total_size: u64,
     pub elapsed_secs: u64,
     pub failed_files: Vec<(String, String)>,
+    pub quota_warning: Option<String>, // 上传前未能读取云端文件列表的错误
 }
 
 impl UploadCompleteDialog {
     pub fn new(
         success_count: usize,
+        skipped_count: usize,
         failed_count: usize,
         total_size: u64,
         elapsed_secs: u64,
         failed_files: Vec<(String, String)>,
+        quota_warning: Option<String>,
     ) -> Self {
         Self {
             show: true,
             success_count,
+            skipped_count,
             failed_count,
             total_size,
             elapsed_secs,
             failed_files,
+            quota_warning,
         }
     }
 
@@ -435,6 +764,15 @@ impl UploadCompleteDialog {
 
                     ui.add_space(10.0);
 
+                    if self.skipped_count > 0 {
+                        ui.label(i18n.upload_skipped_unchanged(self.skipped_count));
+                    }
+                    if let Some(ref err) = self.quota_warning {
+                        ui.label(
+                            RichText::new(i18n.upload_quota_list_failed(err))
+                                .color(crate::ui::theme::warning_color(ctx)),
+                        );
+                    }
                     ui.label(i18n.total_size_label(&format_size(self.total_size)));
                     ui.label(i18n.elapsed_time(self.elapsed_secs));
 
@@ -486,3 +824,94 @@ impl UploadCompleteDialog {
         should_close
     }
 }
+
+// 续传对话框的操作结果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UploadResumeAction {
+    None,
+    Resume,  // 继续上传
+    Discard, // 放弃并删除会话日志
+    Later,   // 稍后处理
+}
+
+// 未完成上传会话提示
+pub struct UploadResumeDialog {
+    pub session: UploadSession,
+}
+
+impl UploadResumeDialog {
+    pub fn new(session: UploadSession) -> Self {
+        Self { session }
+    }
+
+    pub fn draw(&mut self, ctx: &egui::Context, i18n: &I18n) -> UploadResumeAction {
+        let mut action = UploadResumeAction::None;
+        let pending = self.session.pending_count();
+        let total = self.session.tasks.len();
+        let updated = chrono::DateTime::from_timestamp(self.session.updated_at, 0)
+            .map(|t| {
+                t.with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            })
+            .unwrap_or_default();
+
+        egui::Window::new(i18n.upload_resume_title())
+            .resizable(false)
+            .collapsible(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(
+                    RichText::new(i18n.upload_resume_message(self.session.app_id, pending, total))
+                        .strong(),
+                );
+                ui.label(
+                    RichText::new(i18n.upload_resume_updated(&updated))
+                        .color(crate::ui::theme::muted_color(ctx)),
+                );
+                ui.label(
+                    RichText::new(i18n.upload_resume_hint())
+                        .size(12.0)
+                        .color(crate::ui::theme::muted_color(ctx)),
+                );
+
+                ui.add_space(8.0);
+                egui::ScrollArea::vertical()
+                    .max_height(150.0)
+                    .show(ui, |ui| {
+                        for task in self
+                            .session
+                            .tasks
+                            .iter()
+                            .filter(|t| t.status != TaskStatus::Success)
+                        {
+                            ui.label(format!("{} {}", icons::FILE, task.cloud_path));
+                        }
+                    });
+
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .button(format!("{} {}", icons::UPLOAD, i18n.upload_resume_button()))
+                        .clicked()
+                    {
+                        action = UploadResumeAction::Resume;
+                    }
+                    if ui.button(i18n.upload_resume_later()).clicked() {
+                        action = UploadResumeAction::Later;
+                    }
+                    if ui
+                        .button(
+                            RichText::new(i18n.upload_resume_discard())
+                                .color(crate::ui::theme::error_color(ctx)),
+                        )
+                        .clicked()
+                    {
+                        action = UploadResumeAction::Discard;
+                    }
+                });
+            });
+
+        action
+    }
+}
@@ -0,0 +1,345 @@
+use crate::i18n::I18n;
+use crate::icons;
+use crate::user_compare::{CopyTarget, CrossUserEntry, CrossUserStatus, UserSaveFile};
+use crate::vdf_parser::UserInfo;
+use egui::RichText;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UserCompareAction {
+    None,
+    Compare,
+    Copy,
+}
+
+// 跨账号存档对比对话框
+pub struct UserCompareDialog {
+    pub show: bool,
+    pub app_id_input: String,
+    pub users: Vec<UserInfo>,
+    pub source_user: String,
+    pub target_user: String,
+    pub entries: Vec<CrossUserEntry>,
+    pub selected: HashSet<usize>,
+    pub copy_target: CopyTarget,
+    // Steam 工作进程当前登录的账号和连接的 app
+    pub worker_session: Option<(String, u32)>,
+    pub compared: bool,
+}
+
+impl UserCompareDialog {
+    pub fn new(
+        users: Vec<UserInfo>,
+        app_id_input: String,
+        worker_session: Option<(String, u32)>,
+    ) -> Self {
+        let source_user = users
+            .iter()
+            .find(|u| u.is_current)
+            .or(users.first())
+            .map(|u| u.user_id.clone())
+            .unwrap_or_default();
+        let target_user = users
+            .iter()
+            .find(|u| u.user_id != source_user)
+            .map(|u| u.user_id.clone())
+            .unwrap_or_default();
+        Self {
+            show: true,
+            app_id_input,
+            users,
+            source_user,
+            target_user,
+            entries: Vec::new(),
+            selected: HashSet::new(),
+            copy_target: CopyTarget::LocalFolder,
+            worker_session,
+            compared: false,
+        }
+    }
+
+    pub fn app_id(&self) -> Option<u32> {
+        self.app_id_input.trim().parse().ok()
+    }
+
+    // 目标账号已登录且工作进程连接的是同一个游戏时才能通过 Steam 上传，
+    // 且选中的文件都在 root 0
+    pub fn can_use_steam_api(&self) -> bool {
+        let session_matches = match (&self.worker_session, self.app_id()) {
+            (Some((user_id, app_id)), Some(current)) => {
+                *user_id == self.target_user && *app_id == current
+            }
+            _ => false,
+        };
+        session_matches
+            && self
+                .selected
+                .iter()
+                .filter_map(|&i| self.entries.get(i))
+                .all(|e| e.can_copy(CopyTarget::SteamApi))
+    }
+
+    pub fn set_entries(&mut self, entries: Vec<CrossUserEntry>) {
+        self.entries = entries;
+        self.selected.clear();
+        self.compared = true;
+        if !self.can_use_steam_api() {
+            self.copy_target = CopyTarget::LocalFolder;
+        }
+    }
+
+    pub fn selected_entries(&self) -> Vec<CrossUserEntry> {
+        let mut indices: Vec<usize> = self.selected.iter().copied().collect();
+        indices.sort();
+        indices
+            .into_iter()
+            .filter_map(|i| self.entries.get(i).cloned())
+            .collect()
+    }
+
+    fn user_label(&self, user_id: &str) -> String {
+        match self
+            .users
+            .iter()
+            .find(|u| u.user_id == user_id)
+            .and_then(|u| u.persona_name.as_ref())
+        {
+            Some(name) => format!("{} ({})", name, user_id),
+            None => user_id.to_string(),
+        }
+    }
+
+    pub fn draw(&mut self, ctx: &egui::Context, i18n: &I18n) -> UserCompareAction {
+        let mut action = UserCompareAction::None;
+        let mut show = self.show;
+
+        egui::Window::new(i18n.user_compare_title())
+            .open(&mut show)
+            .resizable(true)
+            .default_size([720.0, 480.0])
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("App ID:");
+                    ui.add(egui::TextEdit::singleline(&mut self.app_id_input).desired_width(80.0));
+                    self.draw_user_combo(
+                        ui,
+                        "compare_source_user",
+                        i18n.user_compare_source(),
+                        true,
+                    );
+                    if ui
+                        .button(format!(
+                            "{} {}",
+                            icons::ARROW_SYNC,
+                            i18n.user_compare_swap()
+                        ))
+                        .clicked()
+                    {
+                        std::mem::swap(&mut self.source_user, &mut self.target_user);
+                        action = UserCompareAction::Compare;
+                    }
+                    self.draw_user_combo(
+                        ui,
+                        "compare_target_user",
+                        i18n.user_compare_target(),
+                        false,
+                    );
+
+                    let ready = self.app_id().is_some() && self.source_user != self.target_user;
+                    if ui
+                        .add_enabled(
+                            ready,
+                            egui::Button::new(format!(
+                                "{} {}",
+                                icons::MAGNIFYING_GLASS,
+                                i18n.user_compare_run()
+                            )),
+                        )
+                        .clicked()
+                    {
+                        action = UserCompareAction::Compare;
+                    }
+                });
+
+                if self.source_user == self.target_user {
+                    ui.colored_label(
+                        crate::ui::theme::warning_color(ctx),
+                        i18n.user_compare_same_user(),
+                    );
+                }
+
+                ui.separator();
+
+                if self.compared && self.entries.is_empty() {
+                    ui.label(i18n.user_compare_empty());
+                } else if !self.entries.is_empty() {
+                    self.draw_entries(ui, i18n);
+                    ui.separator();
+                    if self.draw_copy_controls(ui, i18n) {
+                        action = UserCompareAction::Copy;
+                    }
+                }
+            });
+
+        self.show = show;
+        action
+    }
+
+    fn draw_user_combo(&mut self, ui: &mut egui::Ui, id: &str, label: &str, is_source: bool) {
+        ui.label(label);
+        let current = if is_source {
+            self.source_user.clone()
+        } else {
+            self.target_user.clone()
+        };
+        let mut chosen = current.clone();
+        egui::ComboBox::from_id_salt(id)
+            .selected_text(self.user_label(&current))
+            .show_ui(ui, |ui| {
+                for user in &self.users {
+                    ui.selectable_value(
+                        &mut chosen,
+                        user.user_id.clone(),
+                        self.user_label(&user.user_id),
+                    );
+                }
+            });
+        if chosen != current {
+            if is_source {
+                self.source_user = chosen;
+            } else {
+                self.target_user = chosen;
+            }
+            // 账号变化后旧的对比结果不再有效
+            self.entries.clear();
+            self.selected.clear();
+            self.compared = false;
+        }
+    }
+
+    fn draw_entries(&mut self, ui: &mut egui::Ui, i18n: &I18n) {
+        ui.horizontal(|ui| {
+            if ui.button(i18n.user_compare_select_different()).clicked() {
+                self.selected = self
+                    .entries
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, e)| e.can_copy(self.copy_target))
+                    .map(|(i, _)| i)
+                    .collect();
+            }
+        });
+        ui.add_space(4.0);
+
+        egui::ScrollArea::vertical()
+            .max_height(300.0)
+            .show(ui, |ui| {
+                egui::Grid::new("user_compare_grid")
+                    .striped(true)
+                    .num_columns(5)
+                    .show(ui, |ui| {
+                        ui.label("");
+                        ui.strong(i18n.file_name());
+                        ui.strong(i18n.user_compare_col_source());
+                        ui.strong(i18n.user_compare_col_target());
+                        ui.strong(i18n.user_compare_col_status());
+                        ui.end_row();
+
+                        for (i, entry) in self.entries.iter().enumerate() {
+                            let mut checked = self.selected.contains(&i);
+                            if ui
+                                .add_enabled(
+                                    entry.can_copy(self.copy_target),
+                                    egui::Checkbox::without_text(&mut checked),
+                                )
+                                .changed()
+                            {
+                                if checked {
+                                    self.selected.insert(i);
+                                } else {
+                                    self.selected.remove(&i);
+                                }
+                            }
+                            ui.label(format!("{} {}", icons::FILE, entry.name));
+                            ui.label(describe_file(entry.source.as_ref()));
+                            ui.label(describe_file(entry.target.as_ref()));
+                            let (text, color) = match entry.status {
+                                CrossUserStatus::OnlyInSource => (
+                                    i18n.cross_status_only_source(),
+                                    crate::ui::theme::info_color(ui.ctx()),
+                                ),
+                                CrossUserStatus::OnlyInTarget => (
+                                    i18n.cross_status_only_target(),
+                                    crate::ui::theme::muted_color(ui.ctx()),
+                                ),
+                                CrossUserStatus::Identical => (
+                                    i18n.cross_status_identical(),
+                                    crate::ui::theme::success_color(ui.ctx()),
+                                ),
+                                CrossUserStatus::Different => (
+                                    i18n.cross_status_different(),
+                                    crate::ui::theme::warning_color(ui.ctx()),
+                                ),
+                            };
+                            ui.colored_label(color, text);
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+
+    // 返回是否点击了复制
+    fn draw_copy_controls(&mut self, ui: &mut egui::Ui, i18n: &I18n) -> bool {
+        ui.radio_value(
+            &mut self.copy_target,
+            CopyTarget::LocalFolder,
+            i18n.copy_target_local(),
+        );
+        let api_available = self.can_use_steam_api();
+        if !api_available {
+            self.copy_target = CopyTarget::LocalFolder;
+        }
+        ui.add_enabled_ui(api_available, |ui| {
+            ui.radio_value(
+                &mut self.copy_target,
+                CopyTarget::SteamApi,
+                i18n.copy_target_api(),
+            );
+        });
+        if !api_available {
+            ui.label(
+                RichText::new(i18n.copy_target_api_hint())
+                    .size(12.0)
+                    .color(crate::ui::theme::muted_color(ui.ctx())),
+            );
+        }
+
+        ui.add_space(6.0);
+        ui.add_enabled(
+            !self.selected.is_empty(),
+            egui::Button::new(format!(
+                "{} {}",
+                icons::COPY,
+                i18n.user_compare_copy(self.selected.len())
+            )),
+        )
+        .clicked()
+    }
+}
+
+fn describe_file(file: Option<&UserSaveFile>) -> String {
+    match file {
+        Some(file) => {
+            let time = chrono::DateTime::from_timestamp(file.timestamp, 0)
+                .map(|t| {
+                    t.with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string()
+                })
+                .unwrap_or_default();
+            format!("{}  {}", crate::file_manager::format_size(file.size), time)
+        }
+        None => "-".to_string(),
+    }
+}
@@ -1,13 +1,17 @@
-use crate::game_scanner::CloudGameInfo;
+use crate::game_scanner::{CloudGameInfo, CollectionFilter};
 use crate::i18n::I18n;
 use crate::icons;
 use crate::vdf_parser::UserInfo;
-use egui;
+use egui::{self, RichText};
+use std::collections::HashMap;
 
+#[allow(clippy::too_many_arguments)]
 pub fn draw_game_selector_window(
     ctx: &egui::Context,
     show: &mut bool,
     games: &[CloudGameInfo],
+    filter: &mut CollectionFilter,
+    dynamic_collections: &[String],
     is_scanning: bool,
     vdf_count: usize,
     cdp_count: usize,
@@ -69,15 +73,21 @@ pub fn draw_game_selector_window(
                         }
                     });
                 });
+                draw_collection_filter(ui, games, filter, dynamic_collections, i18n);
                 ui.add_space(10.0);
 
+                let mut shown = 0;
                 egui::ScrollArea::vertical().show(ui, |ui| {
-                    for game in games {
+                    for game in games.iter().filter(|g| filter.matches(g)) {
+                        shown += 1;
                         if let Some(app_id) = draw_game_item(ui, game, i18n) {
                             selected_app_id = Some(app_id);
                         }
                         ui.add_space(5.0);
                     }
+                    if shown == 0 {
+                        ui.label(i18n.no_games_in_collection());
+                    }
                 });
             }
         });
@@ -85,6 +95,63 @@ pub fn draw_game_selector_window(
     (selected_app_id, refresh_clicked)
 }
 
+// 收藏夹筛选下拉框
+fn draw_collection_filter(
+    ui: &mut egui::Ui,
+    games: &[CloudGameInfo],
+    filter: &mut CollectionFilter,
+    dynamic_collections: &[String],
+    i18n: &I18n,
+) {
+    let names = crate::game_scanner::collection_names(games);
+    // 收藏夹被删除后回到全部
+    if let CollectionFilter::Collection(name) = filter
+        && !names.contains(name)
+    {
+        *filter = CollectionFilter::All;
+    }
+
+    // 动态收藏夹只能按手动加入的游戏筛选，名称后注明规则不支持
+    let filter_text = |f: &CollectionFilter| match f {
+        CollectionFilter::All => i18n.collection_all().to_string(),
+        CollectionFilter::Favorites => i18n.collection_favorites().to_string(),
+        CollectionFilter::Hidden => i18n.collection_hidden().to_string(),
+        CollectionFilter::Collection(name) if dynamic_collections.contains(name) => {
+            i18n.collection_dynamic_unsupported(name)
+        }
+        CollectionFilter::Collection(name) => name.clone(),
+    };
+
+    ui.horizontal(|ui| {
+        ui.label(i18n.collection_filter_label());
+        egui::ComboBox::from_id_salt("collection_filter")
+            .selected_text(filter_text(filter))
+            .show_ui(ui, |ui| {
+                let mut options = vec![
+                    CollectionFilter::All,
+                    CollectionFilter::Favorites,
+                    CollectionFilter::Hidden,
+                ];
+                for name in dynamic_collections {
+                    if !names.contains(name) {
+                        ui.add_enabled(
+                            false,
+                            egui::Button::selectable(
+                                false,
+                                i18n.collection_dynamic_unsupported(name),
+                            ),
+                        );
+                    }
+                }
+                options.extend(names.into_iter().map(CollectionFilter::Collection));
+                for option in options {
+                    let text = filter_text(&option);
+                    ui.selectable_value(filter, option, text);
+                }
+            });
+    });
+}
+
 // 绘制单个游戏项
 fn draw_game_item(ui: &mut egui::Ui, game: &CloudGameInfo, i18n: &I18n) -> Option<u32> {
     let mut clicked = false;
@@ -170,9 +237,11 @@ pub fn draw_user_selector_window(
     ctx: &egui::Context,
     show: &mut bool,
     users: &[UserInfo],
+    avatar_textures: &mut HashMap<u64, Option<egui::TextureHandle>>,
     i18n: &I18n,
-) -> Option<String> {
+) -> (Option<String>, bool) {
     let mut selected_user_id = None;
+    let mut compare_clicked = false;
 
     egui::Window::new(i18n.select_user())
         .open(show)
@@ -180,12 +249,28 @@ pub fn draw_user_selector_window(
         .default_size([400.0, 300.0])
         .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
         .show(ctx, |ui| {
-            ui.heading(i18n.steam_users(users.len()));
+            ui.horizontal(|ui| {
+                ui.heading(i18n.steam_users(users.len()));
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui
+                        .add_enabled(
+                            users.len() > 1,
+                            egui::Button::new(i18n.compare_users_button()),
+                        )
+                        .clicked()
+                    {
+                        compare_clicked = true;
+                    }
+                });
+            });
             ui.add_space(10.0);
 
             egui::ScrollArea::vertical().show(ui, |ui| {
                 for user in users {
-                    if let Some(user_id) = draw_user_item(ui, user, i18n) {
+                    let avatar = avatar_textures
+                        .entry(user.steam_id64)
+                        .or_insert_with(|| load_avatar_texture(ui.ctx(), user));
+                    if let Some(user_id) = draw_user_item(ui, user, avatar.as_ref(), i18n) {
                         selected_user_id = Some(user_id);
                     }
                     ui.add_space(5.0);
@@ -193,15 +278,63 @@ pub fn draw_user_selector_window(
             });
         });
 
-    selected_user_id
+    (selected_user_id, compare_clicked)
+}
+
+// 加载 Steam 缓存的头像
+fn load_avatar_texture(ctx: &egui::Context, user: &UserInfo) -> Option<egui::TextureHandle> {
+    let path = user.avatar_path.as_ref()?;
+    let img = match image::open(path) {
+        Ok(img) => img,
+        Err(e) => {
+            tracing::debug!("读取头像失败 {}: {}", path.display(), e);
+            return None;
+        }
+    };
+    let img = img.resize_exact(
+        AVATAR_SIZE as u32,
+        AVATAR_SIZE as u32,
+        image::imageops::FilterType::Lanczos3,
+    );
+    let rgba = img.to_rgba8();
+    let color_image = egui::ColorImage::from_rgba_unmultiplied(
+        [AVATAR_SIZE, AVATAR_SIZE],
+        rgba.as_flat_samples().as_slice(),
+    );
+    Some(ctx.load_texture(
+        format!("avatar_{}", user.steam_id64),
+        color_image,
+        Default::default(),
+    ))
 }
 
+const AVATAR_SIZE: usize = 48;
+
 // 绘制单个用户项
-fn draw_user_item(ui: &mut egui::Ui, user: &UserInfo, i18n: &I18n) -> Option<String> {
+fn draw_user_item(
+    ui: &mut egui::Ui,
+    user: &UserInfo,
+    avatar: Option<&egui::TextureHandle>,
+    i18n: &I18n,
+) -> Option<String> {
     let mut clicked = false;
+    let muted = crate::ui::theme::muted_color(ui.ctx());
 
     ui.group(|ui| {
         ui.horizontal(|ui| {
+            let avatar_size = egui::vec2(AVATAR_SIZE as f32, AVATAR_SIZE as f32);
+            match avatar {
+                Some(texture) => {
+                    ui.add(egui::Image::new(texture).fit_to_exact_size(avatar_size));
+                }
+                None => {
+                    ui.add_sized(
+                        avatar_size,
+                        egui::Label::new(RichText::new(icons::USER).size(28.0)),
+                    );
+                }
+            }
+
             ui.vertical(|ui| {
                 if let Some(name) = &user.persona_name {
                     ui.strong(name);
@@ -209,6 +342,36 @@ fn draw_user_item(ui: &mut egui::Ui, user: &UserInfo, i18n: &I18n) -> Option<Str
                 } else {
                     ui.strong(format!("{}: {}", i18n.user_id(), user.user_id));
                 }
+                if let Some(account) = &user.account_name {
+                    ui.label(i18n.account_name_label(account));
+                }
+                ui.label(
+                    RichText::new(format!("SteamID64: {}", user.steam_id64))
+                        .size(12.0)
+                        .color(muted),
+                );
+                if let Some(last_login) = user.last_login
+                    && let Some(time) = chrono::DateTime::from_timestamp(last_login, 0)
+                {
+                    let time = time
+                        .with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string();
+                    ui.label(RichText::new(i18n.last_login_label(&time)).color(muted));
+                }
+
+                let flags: Vec<&str> = [
+                    (user.most_recent, i18n.user_most_recent()),
+                    (user.remember_password, i18n.user_remember_password()),
+                    (user.allow_auto_login, i18n.user_auto_login()),
+                ]
+                .into_iter()
+                .filter(|(on, _)| *on)
+                .map(|(_, text)| text)
+                .collect();
+                if !flags.is_empty() {
+                    ui.label(RichText::new(flags.join(" · ")).size(12.0).color(muted));
+                }
             });
 
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
@@ -0,0 +1,255 @@
+// 上传过滤与云端路径改写
+// 包含/排除规则使用 gitignore 风格：* ? ** 通配，/ 开头锚定根目录，/ 结尾仅匹配目录，! 取反
+
+use crate::config::{PathRewriteRule, UploadConfig};
+use regex::Regex;
+
+// 常见临时文件名
+const TEMP_FILE_NAMES: &[&str] = &[".DS_Store", "Thumbs.db", "desktop.ini"];
+// 常见临时文件扩展名
+const TEMP_FILE_EXTENSIONS: &[&str] = &["tmp", "temp", "swp", "part", "crdownload"];
+
+// 文件被跳过的原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    Hidden,
+    Temp,
+    Excluded,
+    NotIncluded,
+}
+
+// 单条 gitignore 风格规则
+#[derive(Debug, Clone)]
+struct GlobPattern {
+    regex: Regex,
+    negated: bool,
+    dir_only: bool,
+}
+
+impl GlobPattern {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        let (negated, line) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let (dir_only, line) = match line.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        // 含 / 的规则相对根目录匹配，否则匹配任意层级
+        let anchored = line.contains('/');
+        let line = line.trim_start_matches('/');
+        if line.is_empty() {
+            return None;
+        }
+
+        let mut regex = String::from(if anchored { "^" } else { "^(?:.*/)?" });
+        let chars: Vec<char> = line.chars().collect();
+        let mut i = 0;
+        while i < chars.len() {
+            match chars[i] {
+                '*' if chars.get(i + 1) == Some(&'*') => {
+                    if chars.get(i + 2) == Some(&'/') {
+                        regex.push_str("(?:.*/)?");
+                        i += 3;
+                    } else {
+                        regex.push_str(".*");
+                        i += 2;
+                    }
+                    continue;
+                }
+                '*' => regex.push_str("[^/]*"),
+                '?' => regex.push_str("[^/]"),
+                c => regex.push_str(&regex::escape(&c.to_string())),
+            }
+            i += 1;
+        }
+        regex.push('$');
+
+        Regex::new(&regex).ok().map(|regex| Self {
+            regex,
+            negated,
+            dir_only,
+        })
+    }
+
+    // 匹配路径本身或其任一上级目录
+    fn matches(&self, relative: &str) -> bool {
+        if !self.dir_only && self.regex.is_match(relative) {
+            return true;
+        }
+        relative
+            .match_indices('/')
+            .any(|(pos, _)| self.regex.is_match(&relative[..pos]))
+    }
+}
+
+// 最后一条匹配的规则决定结果：Some(true) 命中，Some(false) 被 ! 取反
+fn last_match(patterns: &[GlobPattern], relative: &str) -> Option<bool> {
+    patterns
+        .iter()
+        .rev()
+        .find(|p| p.matches(relative))
+        .map(|p| !p.negated)
+}
+
+fn parse_patterns(lines: &[String]) -> Vec<GlobPattern> {
+    lines.iter().filter_map(|l| GlobPattern::parse(l)).collect()
+}
+
+// 上传过滤器
+#[derive(Debug, Clone, Default)]
+pub struct UploadFilter {
+    include: Vec<GlobPattern>,
+    exclude: Vec<GlobPattern>,
+    skip_hidden: bool,
+    skip_temp: bool,
+    rules: Vec<PathRewriteRule>,
+}
+
+impl UploadFilter {
+    pub fn new(config: &UploadConfig) -> Self {
+        Self {
+            include: parse_patterns(&config.include),
+            exclude: parse_patterns(&config.exclude),
+            skip_hidden: config.skip_hidden,
+            skip_temp: config.skip_temp,
+            rules: config.path_rules.clone(),
+        }
+    }
+
+    // 检查文件夹内的相对路径（/ 分隔），返回 None 表示接受
+    pub fn check(&self, relative: &str) -> Option<SkipReason> {
+        let file_name = relative.rsplit('/').next().unwrap_or(relative);
+
+        if self.skip_hidden && relative.split('/').any(|seg| seg.starts_with('.')) {
+            return Some(SkipReason::Hidden);
+        }
+        if self.skip_temp && is_temp_file(file_name) {
+            return Some(SkipReason::Temp);
+        }
+        if !self.include.is_empty() && last_match(&self.include, relative) != Some(true) {
+            return Some(SkipReason::NotIncluded);
+        }
+        if last_match(&self.exclude, relative) == Some(true) {
+            return Some(SkipReason::Excluded);
+        }
+        None
+    }
+
+    // 应用云端路径改写规则
+    pub fn rewrite(&self, cloud_path: &str) -> String {
+        rewrite_cloud_path(cloud_path, &self.rules)
+    }
+}
+
+fn is_temp_file(file_name: &str) -> bool {
+    if file_name.ends_with('~') || TEMP_FILE_NAMES.contains(&file_name) {
+        return true;
+    }
+    match file_name.rsplit_once('.') {
+        Some((_, ext)) => TEMP_FILE_EXTENSIONS.contains(&ext.to_lowercase().as_str()),
+        None => false,
+    }
+}
+
+// 按顺序应用改写规则，结果为空时保留原路径
+pub fn rewrite_cloud_path(cloud_path: &str, rules: &[PathRewriteRule]) -> String {
+    let mut path = cloud_path.to_string();
+    for rule in rules {
+        let rewritten = match rule {
+            PathRewriteRule::StripPrefix { prefix } => {
+                let prefix = prefix.trim_end_matches('/');
+                match path.strip_prefix(prefix) {
+                    Some(rest) if prefix.is_empty() || rest.is_empty() || rest.starts_with('/') => {
+                        rest.trim_start_matches('/').to_string()
+                    }
+                    _ => path.clone(),
+                }
+            }
+            PathRewriteRule::Lowercase => path.to_lowercase(),
+            PathRewriteRule::ReplaceSegment { from, to } => path
+                .split('/')
+                .filter_map(|seg| {
+                    if seg == from {
+                        (!to.is_empty()).then_some(to.as_str())
+                    } else {
+                        Some(seg)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("/"),
+        };
+        if !rewritten.is_empty() {
+            path = rewritten;
+        }
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(include: &[&str], exclude: &[&str]) -> UploadFilter {
+        UploadFilter::new(&UploadConfig {
+            include: include.iter().map(|s| s.to_string()).collect(),
+            exclude: exclude.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn gitignore_style_patterns() {
+        let f = filter(&[], &["*.log", "cache/", "/build", "!keep.log"]);
+        assert_eq!(f.check("a/b/debug.log"), Some(SkipReason::Excluded));
+        assert_eq!(f.check("a/keep.log"), None);
+        assert_eq!(f.check("x/cache/data.bin"), Some(SkipReason::Excluded));
+        assert_eq!(f.check("cache"), None);
+        assert_eq!(f.check("build/out.sav"), Some(SkipReason::Excluded));
+        assert_eq!(f.check("sub/build/out.sav"), None);
+
+        let f = filter(&["saves/**/*.sav"], &[]);
+        assert_eq!(f.check("saves/slot1.sav"), None);
+        assert_eq!(f.check("saves/a/b/slot2.sav"), None);
+        assert_eq!(f.check("other/slot.sav"), Some(SkipReason::NotIncluded));
+    }
+
+    #[test]
+    fn skips_hidden_and_temp_files() {
+        let f = filter(&[], &[]);
+        assert_eq!(f.check(".git/config"), Some(SkipReason::Hidden));
+        assert_eq!(f.check("save.sav~"), Some(SkipReason::Temp));
+        assert_eq!(f.check("dir/Thumbs.db"), Some(SkipReason::Temp));
+        assert_eq!(f.check("dir/slot.TMP"), Some(SkipReason::Temp));
+        assert_eq!(f.check("dir/slot.sav"), None);
+    }
+
+    #[test]
+    fn rewrite_rules_apply_in_order() {
+        let rules = vec![
+            PathRewriteRule::StripPrefix {
+                prefix: "MyGame/".to_string(),
+            },
+            PathRewriteRule::ReplaceSegment {
+                from: "Saves".to_string(),
+                to: "save".to_string(),
+            },
+            PathRewriteRule::Lowercase,
+        ];
+        assert_eq!(
+            rewrite_cloud_path("MyGame/Saves/Slot1.SAV", &rules),
+            "save/slot1.sav"
+        );
+        // 前缀必须匹配完整路径段
+        assert_eq!(
+            rewrite_cloud_path("MyGameX/Slot1.sav", &rules[..1]),
+            "MyGameX/Slot1.sav"
+        );
+    }
+}
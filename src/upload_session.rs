@@ -0,0 +1,221 @@
+// 上传会话日志
+// 上传过程中把队列和每个任务的状态写入磁盘，程序中途退出后可在下次启动时继续上传
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::file_manager::{TaskStatus, UploadQueue, UploadTask};
+
+// 会话中的单个任务
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionTask {
+    pub local_path: PathBuf,
+    pub cloud_path: String,
+    pub size: u64,
+    pub status: TaskStatus,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+// 上传会话（每个 app 最多一个未完成会话）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadSession {
+    pub app_id: u32,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub tasks: Vec<SessionTask>,
+}
+
+impl UploadSession {
+    pub fn from_queue(app_id: u32, created_at: i64, queue: &UploadQueue) -> Self {
+        Self {
+            app_id,
+            created_at,
+            updated_at: chrono::Utc::now().timestamp(),
+            tasks: queue
+                .tasks
+                .iter()
+                .map(|t| SessionTask {
+                    local_path: t.local_path.clone(),
+                    cloud_path: t.cloud_path.clone(),
+                    size: t.size,
+                    status: t.status,
+                    error: t.error.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    // 未成功的任务数（等待中、重试中或失败）
+    pub fn pending_count(&self) -> usize {
+        self.tasks
+            .iter()
+            .filter(|t| t.status != TaskStatus::Success)
+            .count()
+    }
+
+    // 用未完成的任务重建上传队列，本地文件已不存在的任务会被丢弃
+    pub fn to_queue(&self) -> UploadQueue {
+        let mut queue = UploadQueue::new();
+        for task in self
+            .tasks
+            .iter()
+            .filter(|t| t.status != TaskStatus::Success)
+        {
+            let size = match std::fs::metadata(&task.local_path) {
+                Ok(metadata) => metadata.len(),
+                Err(e) => {
+                    tracing::warn!(
+                        "续传跳过不存在的本地文件 {}: {}",
+                        task.local_path.display(),
+                        e
+                    );
+                    continue;
+                }
+            };
+            queue.tasks.push(UploadTask {
+                local_path: task.local_path.clone(),
+                cloud_path: task.cloud_path.clone(),
+                size,
+                status: TaskStatus::Pending,
+                retry_count: 0,
+                error: None,
+            });
+        }
+        queue
+    }
+}
+
+// 会话日志目录
+fn sessions_dir() -> Result<PathBuf> {
+    Ok(crate::config::get_config_dir()?.join("upload_sessions"))
+}
+
+fn session_path(app_id: u32) -> Result<PathBuf> {
+    Ok(sessions_dir()?.join(format!("{}.json", app_id)))
+}
+
+// 读取指定 app 的未完成会话
+pub fn load_session(app_id: u32) -> Option<UploadSession> {
+    let path = session_path(app_id).ok()?;
+    let content = std::fs::read_to_string(&path).ok()?;
+    match serde_json::from_str::<UploadSession>(&content) {
+        Ok(session) if session.pending_count() > 0 => Some(session),
+        Ok(_) => None,
+        Err(e) => {
+            tracing::warn!("上传会话日志损坏 {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+// 列出所有未完成会话，最近更新的在前
+pub fn list_sessions() -> Vec<UploadSession> {
+    let dir = match sessions_dir() {
+        Ok(dir) => dir,
+        Err(_) => return Vec::new(),
+    };
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut sessions: Vec<UploadSession> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let app_id = entry
+                .path()
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .and_then(|s| s.parse::<u32>().ok())?;
+            load_session(app_id)
+        })
+        .collect();
+    sessions.sort_by_key(|s| std::cmp::Reverse(s.updated_at));
+    sessions
+}
+
+// 删除会话日志
+pub fn remove_session(app_id: u32) -> Result<()> {
+    let path = session_path(app_id)?;
+    if path.exists() {
+        std::fs::remove_file(&path).map_err(|e| anyhow!("删除上传会话日志失败: {}", e))?;
+    }
+    Ok(())
+}
+
+// 上传日志写入器，由 UploadExecutor 在每个任务结束后调用
+pub struct UploadJournal {
+    app_id: u32,
+    created_at: i64,
+}
+
+impl UploadJournal {
+    pub fn new(app_id: u32) -> Self {
+        Self {
+            app_id,
+            created_at: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    // 写入当前队列状态（先写临时文件再重命名，避免中途退出留下半个文件）
+    pub fn save(&self, queue: &UploadQueue) -> Result<()> {
+        let dir = sessions_dir()?;
+        std::fs::create_dir_all(&dir)?;
+
+        let session = UploadSession::from_queue(self.app_id, self.created_at, queue);
+        let content = serde_json::to_string_pretty(&session)?;
+        let path = session_path(self.app_id)?;
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, content)?;
+        std::fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+
+    // 全部任务完成后删除日志
+    pub fn finish(&self) -> Result<()> {
+        remove_session(self.app_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resumed_queue_keeps_unfinished_existing_files() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path();
+        let local = dir.join("slot1.sav");
+        std::fs::write(&local, b"12345").unwrap();
+
+        let task = |path: PathBuf, status| SessionTask {
+            local_path: path,
+            cloud_path: "save/slot.sav".to_string(),
+            size: 1,
+            status,
+            error: None,
+        };
+        let session = UploadSession {
+            app_id: 480,
+            created_at: 0,
+            updated_at: 0,
+            tasks: vec![
+                task(local.clone(), TaskStatus::Success),
+                task(local.clone(), TaskStatus::Failed),
+                task(dir.join("missing.sav"), TaskStatus::Pending),
+            ],
+        };
+        assert_eq!(session.pending_count(), 2);
+
+        let queue = session.to_queue();
+        assert_eq!(queue.tasks.len(), 1);
+        assert_eq!(queue.tasks[0].size, 5);
+        assert_eq!(queue.tasks[0].status, TaskStatus::Pending);
+
+        let json = serde_json::to_string(&UploadSession::from_queue(480, 1, &queue)).unwrap();
+        let restored: UploadSession = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.tasks[0].local_path, local);
+    }
+}
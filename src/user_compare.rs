@@ -0,0 +1,318 @@
+// 跨账号云存档对比与复制
+// 同一台机器上的多个 Steam 账号各有一份 userdata/<uid>/<appid>/remotecache.vdf 和 remote/，
+// 这里把两个账号的存档集合按 (root, 文件名) 对齐比较，并支持把选中的文件复制到另一个账号
+
+use crate::vdf_parser::VdfParser;
+use anyhow::{Result, anyhow};
+use sha1::{Digest, Sha1};
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+// 单个账号中的一个存档文件
+#[derive(Debug, Clone)]
+pub struct UserSaveFile {
+    pub name: String,
+    pub root: u32,
+    pub size: u64,
+    pub timestamp: i64,
+    // remotecache.vdf 中的 sha，不在 remotecache 中的文件使用本地计算的 sha1
+    pub sha: String,
+    // 本地文件路径，文件不存在时为 None
+    pub local_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossUserStatus {
+    OnlyInSource,
+    OnlyInTarget,
+    Identical,
+    Different,
+}
+
+#[derive(Debug, Clone)]
+pub struct CrossUserEntry {
+    pub name: String,
+    pub root: u32,
+    pub source: Option<UserSaveFile>,
+    pub target: Option<UserSaveFile>,
+    pub status: CrossUserStatus,
+}
+
+impl CrossUserEntry {
+    // 只有源账号本地存在的文件才能复制；Steam API 只能写入 root 0（remote/），
+    // rootoverride 目录中的文件上传后会变成游戏不会读取的 remote 文件
+    pub fn can_copy(&self, target: CopyTarget) -> bool {
+        self.status != CrossUserStatus::Identical
+            && self.source.as_ref().is_some_and(|f| f.local_path.is_some())
+            && (target == CopyTarget::LocalFolder || self.root == 0)
+    }
+}
+
+// 复制方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyTarget {
+    // 写入目标账号的本地存档目录，目标账号下次登录时由 Steam 同步
+    LocalFolder,
+    // 通过已连接的 Steam 工作进程写入云端，要求目标账号当前已登录
+    SteamApi,
+}
+
+#[derive(Debug, Default)]
+pub struct CopyReport {
+    pub copied: Vec<String>,
+    pub failed: Vec<(String, String)>,
+}
+
+fn remote_dir(steam_path: &Path, user_id: &str, app_id: u32) -> PathBuf {
+    steam_path
+        .join("userdata")
+        .join(user_id)
+        .join(app_id.to_string())
+        .join("remote")
+}
+
+fn file_sha1(path: &Path) -> Option<String> {
+    let data = std::fs::read(path).ok()?;
+    Some(
+        Sha1::digest(&data)
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect(),
+    )
+}
+
+// 读取账号的存档集合：remotecache.vdf 中的记录加上 remote/ 下未被记录的文件
+pub fn load_user_saves(steam_path: &Path, user_id: &str, app_id: u32) -> Vec<UserSaveFile> {
+    let parser = VdfParser::with_user_id(steam_path.to_path_buf(), user_id.to_string());
+    let mut files: Vec<UserSaveFile> = parser
+        .parse_remotecache(app_id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|entry| {
+            let local_path = crate::path_resolver::resolve_cloud_file_path(
+                entry.root,
+                &entry.filename,
+                steam_path,
+                user_id,
+                app_id,
+            )
+            .ok()
+            .filter(|p| p.is_file());
+            UserSaveFile {
+                name: entry.filename,
+                root: entry.root,
+                size: entry.size,
+                timestamp: entry.timestamp,
+                sha: entry.sha.to_lowercase(),
+                local_path,
+            }
+        })
+        .collect();
+
+    let known: HashSet<(u32, String)> = files.iter().map(|f| (f.root, f.name.clone())).collect();
+    let remote = remote_dir(steam_path, user_id, app_id);
+    let mut stack = vec![remote.clone()];
+    while let Some(dir) = stack.pop() {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+                continue;
+            }
+            let name = match path.strip_prefix(&remote) {
+                Ok(rel) => rel.to_string_lossy().replace('\\', "/"),
+                Err(_) => continue,
+            };
+            if known.contains(&(0, name.clone())) {
+                continue;
+            }
+            let metadata = entry.metadata().ok();
+            files.push(UserSaveFile {
+                name,
+                root: 0,
+                size: metadata.as_ref().map(|m| m.len()).unwrap_or(0),
+                timestamp: crate::game_index::file_mtime(&path)
+                    .map(|ms| (ms / 1000) as i64)
+                    .unwrap_or(0),
+                sha: file_sha1(&path).unwrap_or_default(),
+                local_path: Some(path),
+            });
+        }
+    }
+
+    files
+}
+
+// 按 (root, 文件名) 对齐两个账号的存档
+pub fn compare_saves(source: &[UserSaveFile], target: &[UserSaveFile]) -> Vec<CrossUserEntry> {
+    let mut merged: BTreeMap<(String, u32), (Option<UserSaveFile>, Option<UserSaveFile>)> =
+        BTreeMap::new();
+    for file in source {
+        merged.entry((file.name.clone(), file.root)).or_default().0 = Some(file.clone());
+    }
+    for file in target {
+        merged.entry((file.name.clone(), file.root)).or_default().1 = Some(file.clone());
+    }
+
+    merged
+        .into_iter()
+        .map(|((name, root), (source, target))| {
+            let status = match (&source, &target) {
+                (Some(_), None) => CrossUserStatus::OnlyInSource,
+                (None, _) => CrossUserStatus::OnlyInTarget,
+                (Some(a), Some(b)) => {
+                    let same = if !a.sha.is_empty() && !b.sha.is_empty() {
+                        a.sha == b.sha
+                    } else {
+                        a.size == b.size && a.timestamp == b.timestamp
+                    };
+                    if same {
+                        CrossUserStatus::Identical
+                    } else {
+                        CrossUserStatus::Different
+                    }
+                }
+            };
+            CrossUserEntry {
+                name,
+                root,
+                source,
+                target,
+                status,
+            }
+        })
+        .collect()
+}
+
+// 读取源文件内容
+pub fn read_source(entry: &CrossUserEntry) -> Result<Vec<u8>> {
+    let path = entry
+        .source
+        .as_ref()
+        .and_then(|f| f.local_path.as_ref())
+        .ok_or_else(|| anyhow!("源账号本地没有该文件"))?;
+    std::fs::read(path).map_err(|e| anyhow!("读取源文件失败: {}", e))
+}
+
+// 复制到目标账号的本地存档目录，返回写入的路径
+pub fn copy_to_user_folder(
+    steam_path: &Path,
+    app_id: u32,
+    target_user: &str,
+    entry: &CrossUserEntry,
+) -> Result<PathBuf> {
+    let data = read_source(entry)?;
+    let target_path = crate::path_resolver::resolve_cloud_file_path(
+        entry.root,
+        &entry.name,
+        steam_path,
+        target_user,
+        app_id,
+    )?;
+
+    // 非 remote/ 的 root（如文档目录）通常两个账号共用同一路径
+    if entry
+        .source
+        .as_ref()
+        .and_then(|f| f.local_path.as_ref())
+        .is_some_and(|p| *p == target_path)
+    {
+        return Err(anyhow!("两个账号使用同一本地路径，无需复制"));
+    }
+
+    if let Some(parent) = target_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| anyhow!("创建目录失败: {}", e))?;
+    }
+    let tmp_path = target_path.with_extension("scfm_tmp");
+    std::fs::write(&tmp_path, &data).map_err(|e| anyhow!("写入文件失败: {}", e))?;
+    std::fs::rename(&tmp_path, &target_path).map_err(|e| anyhow!("写入文件失败: {}", e))?;
+    Ok(target_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn save(name: &str, size: u64, sha: &str) -> UserSaveFile {
+        UserSaveFile {
+            name: name.to_string(),
+            root: 0,
+            size,
+            timestamp: 100,
+            sha: sha.to_string(),
+            local_path: Some(PathBuf::from(name)),
+        }
+    }
+
+    #[test]
+    fn compares_save_sets_across_users() {
+        let source = vec![
+            save("a.sav", 10, "aa"),
+            save("b.sav", 10, "bb"),
+            save("c.sav", 10, ""),
+        ];
+        let target = vec![
+            save("b.sav", 10, "b0"),
+            save("c.sav", 10, ""),
+            save("d.sav", 1, "dd"),
+        ];
+
+        let entries = compare_saves(&source, &target);
+        let statuses: Vec<(&str, CrossUserStatus)> = entries
+            .iter()
+            .map(|e| (e.name.as_str(), e.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("a.sav", CrossUserStatus::OnlyInSource),
+                ("b.sav", CrossUserStatus::Different),
+                ("c.sav", CrossUserStatus::Identical),
+                ("d.sav", CrossUserStatus::OnlyInTarget),
+            ]
+        );
+        assert!(entries[0].can_copy(CopyTarget::LocalFolder));
+        assert!(entries[0].can_copy(CopyTarget::SteamApi));
+        assert!(!entries[2].can_copy(CopyTarget::LocalFolder));
+        assert!(!entries[3].can_copy(CopyTarget::LocalFolder));
+
+        // 非 root 0 的文件只能复制到本地目录
+        let documents = UserSaveFile {
+            root: 2,
+            ..save("e.sav", 10, "ee")
+        };
+        let entries = compare_saves(&[documents], &[]);
+        assert!(entries[0].can_copy(CopyTarget::LocalFolder));
+        assert!(!entries[0].can_copy(CopyTarget::SteamApi));
+    }
+
+    #[test]
+    fn copies_remote_files_between_users() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let steam = temp.path();
+        let source_remote = remote_dir(steam, "1", 480);
+        std::fs::create_dir_all(source_remote.join("slot")).unwrap();
+        std::fs::write(source_remote.join("slot").join("save.dat"), b"hello").unwrap();
+
+        let source = load_user_saves(steam, "1", 480);
+        assert_eq!(source.len(), 1);
+        assert_eq!(source[0].name, "slot/save.dat");
+        let target = load_user_saves(steam, "2", 480);
+        assert!(target.is_empty());
+
+        let entries = compare_saves(&source, &target);
+        let path = copy_to_user_folder(steam, 480, "2", &entries[0]).unwrap();
+        assert_eq!(
+            path,
+            remote_dir(steam, "2", 480).join("slot").join("save.dat")
+        );
+        assert_eq!(std::fs::read(&path).unwrap(), b"hello");
+
+        let entries = compare_saves(&source, &load_user_saves(steam, "2", 480));
+        assert_eq!(entries[0].status, CrossUserStatus::Identical);
+    }
+}
@@ -2,64 +2,125 @@ use crate::vdf_parser::UserInfo;
 use anyhow::{Result, anyhow};
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-// 从 loginusers.vdf 查找用户 ID
-pub fn find_user_id_from_loginusers(steam_path: &Path) -> Option<(String, Option<String>)> {
-    let p = steam_path.join("config").join("loginusers.vdf");
-    let s = fs::read_to_string(&p).ok()?;
-    let mut current_id64: Option<u64> = None;
-    let mut most_recent_id64: Option<u64> = None;
-    let mut most_recent_name: Option<String> = None;
-    let mut most_recent_timestamp: i64 = 0;
+// SteamID64 = 账号 ID + 个人账号基数
+pub const STEAM_ID64_BASE: u64 = 76561197960265728;
 
-    for line in s.lines() {
-        let trimmed = line.trim();
+pub fn account_id_to_steam_id64(account_id: u32) -> u64 {
+    STEAM_ID64_BASE + account_id as u64
+}
 
-        if trimmed.starts_with('"')
-            && trimmed.ends_with('"')
-            && let Ok(id64) = trimmed.trim_matches('"').parse::<u64>()
-        {
-            current_id64 = Some(id64);
-        }
+pub fn steam_id64_to_account_id(steam_id64: u64) -> Option<u32> {
+    steam_id64
+        .checked_sub(STEAM_ID64_BASE)
+        .filter(|id| *id > 0)
+        .and_then(|id| u32::try_from(id).ok())
+}
 
-        if let Some(id64) = current_id64 {
-            if trimmed.contains("\"PersonaName\"")
-                && let Some(name) = trimmed.split('"').nth(3)
-                && (trimmed.contains("\"MostRecent\"") || trimmed.contains("\"Timestamp\""))
-                && let Some(ts_str) = trimmed.split('"').nth(3)
-                && let Ok(ts) = ts_str.parse::<i64>()
-                && ts > most_recent_timestamp
-            {
-                most_recent_timestamp = ts;
-                most_recent_id64 = Some(id64);
-                most_recent_name = Some(name.to_string());
-            }
+// loginusers.vdf 中的一个账号
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LoginUser {
+    pub steam_id64: u64,
+    pub account_name: Option<String>,
+    pub persona_name: Option<String>,
+    pub remember_password: bool,
+    pub allow_auto_login: bool,
+    pub most_recent: bool,
+    pub timestamp: i64,
+}
 
-            if trimmed.contains("\"PersonaName\"")
-                && let Some(name) = trimmed.split('"').nth(3)
-            {
-                most_recent_name = Some(name.to_string());
+impl LoginUser {
+    pub fn account_id(&self) -> Option<u32> {
+        steam_id64_to_account_id(self.steam_id64)
+    }
+}
+
+// 解析 loginusers.vdf 内容
+pub fn parse_loginusers(content: &str) -> Vec<LoginUser> {
+    let mut users = Vec::new();
+    let mut current: Option<LoginUser> = None;
+    let mut depth = 0;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        match trimmed {
+            "{" => {
+                depth += 1;
+                continue;
+            }
+            "}" => {
+                // 账号块结束
+                if depth == 2
+                    && let Some(user) = current.take()
+                {
+                    users.push(user);
+                }
+                depth -= 1;
+                continue;
             }
+            _ => {}
+        }
 
-            if trimmed.contains("\"Timestamp\"")
-                && let Some(ts_str) = trimmed.split('"').nth(3)
-                && let Ok(ts) = ts_str.parse::<i64>()
-                && ts > most_recent_timestamp
-            {
-                most_recent_timestamp = ts;
-                most_recent_id64 = Some(id64);
+        let parts: Vec<&str> = trimmed.split('"').collect();
+        // 只有键的行: "76561198000000000"
+        if depth == 1 && parts.len() == 3 {
+            current = parts[1].parse::<u64>().ok().map(|steam_id64| LoginUser {
+                steam_id64,
+                ..Default::default()
+            });
+            continue;
+        }
+
+        if depth == 2
+            && parts.len() >= 5
+            && let Some(user) = current.as_mut()
+        {
+            let value = parts[3];
+            match parts[1].to_ascii_lowercase().as_str() {
+                "accountname" => user.account_name = Some(value.to_string()),
+                "personaname" => user.persona_name = Some(value.to_string()),
+                "rememberpassword" => user.remember_password = value == "1",
+                "allowautologin" => user.allow_auto_login = value == "1",
+                "mostrecent" => user.most_recent = value == "1",
+                "timestamp" => user.timestamp = value.parse().unwrap_or(0),
+                _ => {}
             }
         }
     }
 
-    let id64 = most_recent_id64.or(current_id64)?;
-    let base: u64 = 76561197960265728;
-    if id64 > base {
-        Some(((id64 - base).to_string(), most_recent_name))
-    } else {
-        None
-    }
+    users
+}
+
+pub fn read_loginusers(steam_path: &Path) -> Vec<LoginUser> {
+    let p = steam_path.join("config").join("loginusers.vdf");
+    fs::read_to_string(&p)
+        .map(|s| parse_loginusers(&s))
+        .unwrap_or_default()
+}
+
+// 最近登录的账号：优先 MostRecent 标记，其次 Timestamp 最大的账号
+pub fn most_recent_login(users: &[LoginUser]) -> Option<&LoginUser> {
+    users
+        .iter()
+        .find(|u| u.most_recent)
+        .or_else(|| users.iter().max_by_key(|u| u.timestamp))
+}
+
+// 从 loginusers.vdf 查找用户 ID
+pub fn find_user_id_from_loginusers(steam_path: &Path) -> Option<(String, Option<String>)> {
+    let users = read_loginusers(steam_path);
+    let user = most_recent_login(&users)?;
+    Some((user.account_id()?.to_string(), user.persona_name.clone()))
+}
+
+// Steam 缓存的本地头像
+pub fn avatar_path(steam_path: &Path, steam_id64: u64) -> Option<PathBuf> {
+    let path = steam_path
+        .join("config")
+        .join("avatarcache")
+        .join(format!("{}.png", steam_id64));
+    path.is_file().then_some(path)
 }
 
 // 查找用户 ID
@@ -85,64 +146,129 @@ pub fn get_all_users_info(steam_path: &Path, current_user_id: &str) -> Result<Ve
     let userdata_path = steam_path.join("userdata");
     let mut users = Vec::new();
 
-    // 读取 loginusers.vdf 获取所有用户的昵称信息
-    let mut login_users = HashMap::new();
-    let loginusers_path = steam_path.join("config").join("loginusers.vdf");
-    if let Ok(content) = fs::read_to_string(&loginusers_path) {
-        let mut current_id64: Option<u64> = None;
-
-        for line in content.lines() {
-            let trimmed = line.trim();
-
-            if trimmed.starts_with('"')
-                && trimmed.ends_with('"')
-                && let Ok(id64) = trimmed.trim_matches('"').parse::<u64>()
-            {
-                current_id64 = Some(id64);
-            }
-
-            if let Some(id64) = current_id64
-                && trimmed.contains("\"PersonaName\"")
-                && let Some(name) = trimmed.split('"').nth(3)
-            {
-                let base: u64 = 76561197960265728;
-                if id64 > base {
-                    let user_id = (id64 - base).to_string();
-                    login_users.insert(user_id, name.to_string());
-                }
-            }
-        }
-    }
+    // 读取 loginusers.vdf 获取所有用户的账号信息
+    let login_users: HashMap<String, LoginUser> = read_loginusers(steam_path)
+        .into_iter()
+        .filter_map(|u| Some((u.account_id()?.to_string(), u)))
+        .collect();
 
     // 扫描 userdata 目录
     if let Ok(entries) = fs::read_dir(&userdata_path) {
         for entry in entries.flatten() {
             if let Some(name) = entry.file_name().to_str()
-                && name.parse::<u64>().is_ok()
+                && let Ok(account_id) = name.parse::<u32>()
             {
                 let user_id = name.to_string();
-                let persona_name = login_users.get(&user_id).cloned();
+                let steam_id64 = account_id_to_steam_id64(account_id);
+                let login = login_users.get(&user_id);
                 let is_current = user_id == current_user_id;
 
                 users.push(UserInfo {
                     user_id,
-                    persona_name,
+                    steam_id64,
+                    persona_name: login.and_then(|u| u.persona_name.clone()),
+                    account_name: login.and_then(|u| u.account_name.clone()),
                     is_current,
+                    most_recent: login.is_some_and(|u| u.most_recent),
+                    remember_password: login.is_some_and(|u| u.remember_password),
+                    allow_auto_login: login.is_some_and(|u| u.allow_auto_login),
+                    last_login: login.map(|u| u.timestamp).filter(|ts| *ts > 0),
+                    avatar_path: avatar_path(steam_path, steam_id64),
                 });
             }
         }
     }
 
-    // 按当前用户优先排序
+    sort_users(&mut users);
+    Ok(users)
+}
+
+// 当前用户优先，其余按最近登录时间排序
+pub fn sort_users(users: &mut [UserInfo]) {
     users.sort_by(|a, b| {
-        if a.is_current {
-            std::cmp::Ordering::Less
-        } else if b.is_current {
-            std::cmp::Ordering::Greater
-        } else {
-            a.user_id.cmp(&b.user_id)
-        }
+        b.is_current
+            .cmp(&a.is_current)
+            .then_with(|| b.last_login.unwrap_or(0).cmp(&a.last_login.unwrap_or(0)))
+            .then_with(|| a.user_id.cmp(&b.user_id))
     });
+}
 
-    Ok(users)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOGINUSERS: &str = r#""users"
+{
+	"76561198000000001"
+	{
+		"AccountName"		"alice_acc"
+		"PersonaName"		"Alice"
+		"RememberPassword"		"1"
+		"WantsOfflineMode"		"0"
+		"SkipOfflineModeWarning"		"0"
+		"AllowAutoLogin"		"1"
+		"MostRecent"		"0"
+		"Timestamp"		"1700000000"
+	}
+	"76561198000000002"
+	{
+		"AccountName"		"bob_acc"
+		"PersonaName"		"Bob"
+		"RememberPassword"		"0"
+		"AllowAutoLogin"		"0"
+		"MostRecent"		"1"
+		"Timestamp"		"1600000000"
+	}
+}
+"#;
+
+    #[test]
+    fn parses_loginusers_accounts() {
+        let users = parse_loginusers(LOGINUSERS);
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0].account_name.as_deref(), Some("alice_acc"));
+        assert!(users[0].remember_password && users[0].allow_auto_login);
+        assert_eq!(users[0].timestamp, 1700000000);
+        assert_eq!(users[0].account_id(), Some(39734273));
+        assert_eq!(account_id_to_steam_id64(39734273), 76561198000000001);
+
+        // MostRecent 优先于时间戳
+        assert_eq!(
+            most_recent_login(&users).unwrap().steam_id64,
+            76561198000000002
+        );
+        let mut without_flag = users.clone();
+        without_flag[1].most_recent = false;
+        assert_eq!(
+            most_recent_login(&without_flag).unwrap().steam_id64,
+            76561198000000001
+        );
+
+        assert_eq!(steam_id64_to_account_id(STEAM_ID64_BASE), None);
+    }
+
+    #[test]
+    fn sorts_users_by_last_login() {
+        let user = |id: &str, last_login: Option<i64>, is_current: bool| UserInfo {
+            user_id: id.to_string(),
+            steam_id64: 0,
+            persona_name: None,
+            account_name: None,
+            is_current,
+            most_recent: false,
+            remember_password: false,
+            allow_auto_login: false,
+            last_login,
+            avatar_path: None,
+        };
+        let mut users = vec![
+            user("1", Some(100), false),
+            user("2", None, false),
+            user("3", Some(300), false),
+            user("4", Some(50), true),
+        ];
+        sort_users(&mut users);
+        let ids: Vec<&str> = users.iter().map(|u| u.user_id.as_str()).collect();
+        assert_eq!(ids, vec!["4", "3", "1", "2"]);
+    }
 }
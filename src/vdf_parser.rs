@@ -1,3 +1,4 @@
+use crate::appinfo_format::{self, AppInfoFormat, UnsupportedAppInfoVersion};
 use anyhow::{Result, anyhow};
 use byteorder::{LittleEndian, ReadBytesExt};
 use serde::{Deserialize, Serialize};
@@ -44,8 +45,17 @@ pub struct UfsConfig {
 #[derive(Debug, Clone)]
 pub struct UserInfo {
     pub user_id: String,
+    pub steam_id64: u64,
     pub persona_name: Option<String>,
+    pub account_name: Option<String>,
     pub is_current: bool,
+    // loginusers.vdf 中的标记，未登录过的 userdata 目录均为默认值
+    pub most_recent: bool,
+    pub remember_password: bool,
+    pub allow_auto_login: bool,
+    pub last_login: Option<i64>,
+    // config/avatarcache/<SteamID64>.png
+    pub avatar_path: Option<PathBuf>,
 }
 
 impl VdfParser {
@@ -346,40 +356,27 @@ impl VdfParser {
         let mut cursor = Cursor::new(&data);
         let mut apps = HashMap::new();
 
-        // 读取 Magic 确定版本
-        let magic = match cursor.read_u32::<LittleEndian>() {
-            Ok(m) => m,
-            Err(_) => {
-                tracing::warn!("appinfo.vdf 格式无效");
+        // 读取文件头确定版本
+        let header = match appinfo_format::read_header(&mut cursor) {
+            Ok(h) => h,
+            Err(e) => {
+                // 未知版本直接报错，避免静默返回空游戏列表
+                if UnsupportedAppInfoVersion::from_error(&e).is_some() {
+                    tracing::warn!("{}", e);
+                    return Err(e);
+                }
+                tracing::warn!("appinfo.vdf 格式无效: {}", e);
                 return Ok(HashMap::new());
             }
         };
+        let format = header.format;
 
-        let version = match magic {
-            0x07564427 => 27,
-            0x07564428 => 28,
-            0x07564429 => 29,
-            _ => {
-                tracing::warn!("appinfo.vdf 版本不支持: 0x{:X}", magic);
-                return Ok(HashMap::new());
+        // 解析字符串表
+        let string_table = match header.string_table_offset {
+            Some(offset) if offset > 0 => {
+                Self::parse_string_table(&data, offset as usize).unwrap_or_default()
             }
-        };
-
-        // 读取 Universe
-        let _ = cursor.read_u32::<LittleEndian>();
-
-        // V29+ 需要读取 String Table Offset (8 bytes)
-        let string_table_offset = if version >= 29 {
-            cursor.read_u64::<LittleEndian>().unwrap_or(0)
-        } else {
-            0
-        };
-
-        // 解析字符串表 (V29+)
-        let string_table = if version >= 29 && string_table_offset > 0 {
-            Self::parse_string_table(&data, string_table_offset as usize).unwrap_or_default()
-        } else {
-            Vec::new()
+            _ => Vec::new(),
         };
 
         // 找到 "name" 和 "common" 在字符串表中的索引
@@ -404,29 +401,13 @@ impl VdfParser {
                 Err(_) => break,
             };
 
-            // 头部字段大小
-            let header_size: usize = 4 + 4 + 8 + 20 + 4 + if version >= 28 { 20 } else { 0 };
-            let vdf_size = (size as usize).saturating_sub(header_size);
+            let vdf_size = (size as usize).saturating_sub(format.entry_header_size());
 
             // 跳过头部元数据
-            let _ = cursor.read_u32::<LittleEndian>(); // infostate
-            let _ = cursor.read_u32::<LittleEndian>(); // last_updated
-            let _ = cursor.read_u64::<LittleEndian>(); // access_token
-
-            let mut sha = [0u8; 20];
-            if cursor.read_exact(&mut sha).is_err() {
+            if appinfo_format::read_entry_header(&mut cursor, format).is_err() {
                 break;
             }
 
-            let _ = cursor.read_u32::<LittleEndian>(); // change_number
-
-            if version >= 28 {
-                let mut binary_sha = [0u8; 20];
-                if cursor.read_exact(&mut binary_sha).is_err() {
-                    break;
-                }
-            }
-
             // 读取 VDF 数据
             if vdf_size > 0 && vdf_size < 10_000_000 {
                 let mut vdf_data = vec![0u8; vdf_size];
@@ -435,7 +416,7 @@ impl VdfParser {
                     if let Some(name) = Self::extract_name_from_vdf(
                         &vdf_data,
                         &string_table,
-                        version,
+                        format,
                         name_idx,
                         common_idx,
                     ) && !name.is_empty()
@@ -459,13 +440,17 @@ impl VdfParser {
             count += 1;
         }
         // V29 名称提取仍需改进，但名称可从 manifest/CDP 获取
-        if apps.is_empty() && version >= 29 {
+        if apps.is_empty() && format.string_table {
             tracing::debug!(
                 "appinfo.vdf V{} 名称提取尚未完善，使用 manifest/CDP 获取名称",
-                version
+                format.version
             );
         } else {
-            tracing::debug!("appinfo.vdf V{} 解析到 {} 个游戏名称", version, apps.len());
+            tracing::debug!(
+                "appinfo.vdf V{} 解析到 {} 个游戏名称",
+                format.version,
+                apps.len()
+            );
         }
         Ok(apps)
     }
@@ -474,11 +459,11 @@ impl VdfParser {
     fn extract_name_from_vdf(
         data: &[u8],
         string_table: &[String],
-        version: u32,
+        format: &AppInfoFormat,
         name_idx: Option<usize>,
         _common_idx: Option<usize>,
     ) -> Option<String> {
-        if version >= 29 && !string_table.is_empty() {
+        if format.string_table && !string_table.is_empty() {
             if let Some(name_i) = name_idx {
                 // V29 格式 1: 0x01 (string type) + key_idx (4 bytes) + inline string value
                 // 格式: 0x01 + [name_idx LE] + "Game Name\0"
@@ -574,28 +559,13 @@ impl VdfParser {
     fn parse_app_ufs_config(data: &[u8], target_app_id: u32) -> Result<UfsConfig> {
         let mut cursor = Cursor::new(data);
 
-        let magic = cursor.read_u32::<LittleEndian>()?;
-        let version = match magic {
-            0x07564427 => 27,
-            0x07564428 => 28,
-            0x07564429 => 29,
-            _ => return Err(anyhow!("不支持的 appinfo.vdf 版本: 0x{:X}", magic)),
-        };
+        let header = appinfo_format::read_header(&mut cursor)?;
+        let format = header.format;
 
-        cursor.read_u32::<LittleEndian>()?; // universe
-
-        // 版本 29+ 有字符串表
-        let string_table_offset = if version >= 29 {
-            cursor.read_u64::<LittleEndian>()?
-        } else {
-            0
-        };
-
-        // 解析字符串表 (版本 29+)
-        let string_table = if version >= 29 && string_table_offset > 0 {
-            Self::parse_string_table(data, string_table_offset as usize)?
-        } else {
-            Vec::new()
+        // 解析字符串表
+        let string_table = match header.string_table_offset {
+            Some(offset) if offset > 0 => Self::parse_string_table(data, offset as usize)?,
+            _ => Vec::new(),
         };
 
         // 查找 ufs 在字符串表中的索引
@@ -612,32 +582,18 @@ impl VdfParser {
 
             let size = cursor.read_u32::<LittleEndian>()?;
 
-            // size 是 size 字段之后所有数据的大小
-            // 头部字段: infostate(4) + last_updated(4) + access_token(8) + sha(20) + change_number(4) + binary_sha(20 for v28+)
-            let header_size: usize = 4 + 4 + 8 + 20 + 4 + if version >= 28 { 20 } else { 0 };
-            let vdf_size = (size as usize).saturating_sub(header_size);
+            // size 是 size 字段之后所有数据的大小，包含条目头
+            let vdf_size = (size as usize).saturating_sub(format.entry_header_size());
 
             if app_id == target_app_id {
                 // 跳过头部字段
-                cursor.read_u32::<LittleEndian>()?; // infostate
-                cursor.read_u32::<LittleEndian>()?; // last_updated
-                cursor.read_u64::<LittleEndian>()?; // access_token
-
-                let mut sha = vec![0u8; 20];
-                cursor.read_exact(&mut sha)?;
-
-                cursor.read_u32::<LittleEndian>()?; // change_number
-
-                if version >= 28 {
-                    let mut binary_sha = vec![0u8; 20];
-                    cursor.read_exact(&mut binary_sha)?;
-                }
+                appinfo_format::read_entry_header(&mut cursor, format)?;
 
                 let mut vdf_data = vec![0u8; vdf_size];
                 cursor.read_exact(&mut vdf_data)?;
 
                 // 使用简化的解析方法
-                return Self::extract_ufs_from_binary_vdf(&vdf_data, &string_table, version);
+                return Self::extract_ufs_from_binary_vdf(&vdf_data, &string_table, format);
             } else {
                 // 跳过整个条目: 已读取 app_id(4) + size(4)，剩余 size 字节
                 cursor.set_position(entry_start + 8 + size as u64);
@@ -649,7 +605,7 @@ impl VdfParser {
 
     // 解析字符串表 (版本 29+)
     // V29 格式：4 字节字符串数量 + null-terminated strings
-    fn parse_string_table(data: &[u8], offset: usize) -> Result<Vec<String>> {
+    pub fn parse_string_table(data: &[u8], offset: usize) -> Result<Vec<String>> {
         if offset + 4 >= data.len() {
             return Ok(Vec::new());
         }
@@ -696,11 +652,11 @@ impl VdfParser {
     fn extract_ufs_from_binary_vdf(
         data: &[u8],
         string_table: &[String],
-        version: u32,
+        format: &AppInfoFormat,
     ) -> Result<UfsConfig> {
         let mut config = UfsConfig::default();
 
-        if version >= 29 && !string_table.is_empty() {
+        if format.string_table && !string_table.is_empty() {
             // 找到 "ufs" 在字符串表中的索引
             let ufs_idx = match string_table.iter().position(|s| s == "ufs") {
                 Some(idx) => idx,
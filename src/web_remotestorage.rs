@@ -0,0 +1,183 @@
+// 不经过 CEF 调试端口读取网页版云存储
+// 用用户提供的会话 Cookie 直接请求 remotestorage 页面，解析结果与 CDP 方式一致
+
+use crate::config::{RemoteStorageConfig, RemoteStorageProvider};
+use crate::game_scanner::CloudGameInfo;
+use crate::remotestorage_html::{
+    FILES_PER_PAGE, GAME_LIST_URL, file_page_url, has_account_table, has_index_link,
+    parse_file_rows, parse_game_rows,
+};
+use crate::steam_api::CloudFile;
+use anyhow::{Result, anyhow};
+use std::time::Duration;
+
+const USER_AGENT: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+// 翻页上限，防止页面结构变化导致死循环
+const MAX_PAGES: usize = 200;
+
+pub struct WebRemoteStorageClient {
+    agent: ureq::Agent,
+    cookie: String,
+}
+
+impl WebRemoteStorageClient {
+    pub fn new(session_cookie: &str) -> Result<Self> {
+        let cookie =
+            normalize_cookie(session_cookie).ok_or_else(|| anyhow!("未设置网页版会话 Cookie"))?;
+        let agent = ureq::config::Config::builder()
+            .timeout_connect(Some(Duration::from_secs(15)))
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .new_agent();
+        Ok(Self { agent, cookie })
+    }
+
+    pub fn from_config() -> Result<Self> {
+        Self::new(&crate::config::get_config().remote_storage.session_cookie)
+    }
+
+    fn fetch_page(&self, url: &str) -> Result<String> {
+        tracing::debug!("请求网页版云存储: {}", url);
+        let resp = self
+            .agent
+            .get(url)
+            .header("Cookie", &self.cookie)
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|e| anyhow!("请求网页版云存储失败: {}", e))?;
+        let html = resp
+            .into_body()
+            .read_to_string()
+            .map_err(|e| anyhow!("读取网页内容失败: {}", e))?;
+
+        // Cookie 失效时会被重定向到登录页，页面中没有数据表格
+        if !has_account_table(&html) {
+            return Err(anyhow!("页面中没有云存储数据，会话 Cookie 可能已失效"));
+        }
+        Ok(html)
+    }
+
+    pub fn fetch_game_list(&self) -> Result<Vec<CloudGameInfo>> {
+        tracing::info!("正在通过网页读取 Steam 云存储游戏列表...");
+        let html = self.fetch_page(GAME_LIST_URL)?;
+        let games: Vec<CloudGameInfo> = parse_game_rows(&html)
+            .iter()
+            .filter_map(|row| row.to_game_info())
+            .collect();
+        tracing::info!("网页解析完成，获取到 {} 个游戏", games.len());
+        Ok(games)
+    }
+
+    pub fn fetch_game_files(&self, app_id: u32) -> Result<Vec<CloudFile>> {
+        let mut all_files = Vec::new();
+        let mut index = 0;
+
+        for _ in 0..MAX_PAGES {
+            let html = self.fetch_page(&file_page_url(app_id, index))?;
+            all_files.extend(parse_file_rows(&html).iter().map(|row| row.to_cloud_file()));
+
+            index += FILES_PER_PAGE;
+            if !has_index_link(&html, index) {
+                break;
+            }
+        }
+
+        tracing::info!(count = all_files.len(), "网页版云存储返回文件");
+        Ok(all_files)
+    }
+}
+
+// 接受完整的 "steamLoginSecure=..." 或只有值的形式
+fn normalize_cookie(input: &str) -> Option<String> {
+    let input = input.trim().trim_start_matches("Cookie:").trim();
+    if input.is_empty() {
+        None
+    } else if input.contains('=') {
+        Some(input.to_string())
+    } else {
+        Some(format!("steamLoginSecure={}", input))
+    }
+}
+
+// 网页方式下载 store.steampowered.com 的文件链接时需要带上会话 Cookie，
+// 否则 Steam 返回 200 的登录页
+pub fn download_cookie(config: &RemoteStorageConfig, url: &str) -> Option<String> {
+    if config.provider != RemoteStorageProvider::Web {
+        return None;
+    }
+    let host = url
+        .split_once("://")
+        .map_or(url, |(_, rest)| rest)
+        .split(['/', '?', '#'])
+        .next()
+        .unwrap_or("");
+    if !host.eq_ignore_ascii_case("store.steampowered.com") {
+        return None;
+    }
+    normalize_cookie(&config.session_cookie)
+}
+
+// 按设置选择的方式检查网页版云存储是否可用
+pub fn is_available() -> bool {
+    let config = crate::config::get_config().remote_storage;
+    match config.provider {
+        RemoteStorageProvider::Cdp => crate::cdp_client::CdpClient::is_cdp_running(),
+        RemoteStorageProvider::Web => normalize_cookie(&config.session_cookie).is_some(),
+    }
+}
+
+// 按设置选择的方式读取游戏列表
+pub fn fetch_game_list() -> Result<Vec<CloudGameInfo>> {
+    match crate::config::get_config().remote_storage.provider {
+        RemoteStorageProvider::Cdp => {
+            crate::cdp_client::CdpClient::connect_for(crate::cdp_client::CdpTarget::GameList)?
+                .fetch_game_list()
+        }
+        RemoteStorageProvider::Web => WebRemoteStorageClient::from_config()?.fetch_game_list(),
+    }
+}
+
+// 按设置选择的方式读取文件列表
+pub fn fetch_game_files(app_id: u32) -> Result<Vec<CloudFile>> {
+    match crate::config::get_config().remote_storage.provider {
+        RemoteStorageProvider::Cdp => {
+            crate::cdp_client::CdpClient::connect_for(crate::cdp_client::CdpTarget::FileList)?
+                .fetch_game_files(app_id)
+        }
+        RemoteStorageProvider::Web => {
+            WebRemoteStorageClient::from_config()?.fetch_game_files(app_id)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sends_cookie_only_for_store_downloads_in_web_mode() {
+        let mut config = RemoteStorageConfig {
+            provider: RemoteStorageProvider::Web,
+            session_cookie: "abc".to_string(),
+        };
+        let store = "https://store.steampowered.com/account/filedownload/480?ugcid=1";
+        assert_eq!(
+            download_cookie(&config, store).as_deref(),
+            Some("steamLoginSecure=abc")
+        );
+        assert_eq!(
+            download_cookie(
+                &config,
+                "https://cloud-3.steamusercontent.com/ugc/1/slot1.sav"
+            ),
+            None
+        );
+        assert_eq!(
+            download_cookie(&config, "https://store.steampowered.com.example.com/x"),
+            None
+        );
+
+        config.provider = RemoteStorageProvider::Cdp;
+        assert_eq!(download_cookie(&config, store), None);
+    }
+}